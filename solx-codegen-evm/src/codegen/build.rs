@@ -5,6 +5,8 @@
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
+use crate::codegen::remark::Remark;
+use crate::codegen::source_map::SourceMap;
 use crate::codegen::warning::Warning;
 
 ///
@@ -16,12 +18,20 @@ pub struct Build {
     pub bytecode: Option<Vec<u8>>,
     /// Text assembly.
     pub assembly: Option<String>,
+    /// Unoptimized LLVM IR.
+    pub llvm_ir: Option<String>,
+    /// Optimized LLVM IR.
+    pub llvm_ir_optimized: Option<String>,
     /// Mapping with immutables.
     pub immutables: Option<BTreeMap<String, BTreeSet<u64>>>,
+    /// Source map, resolved against the bytecode after linking.
+    pub source_map: Option<SourceMap>,
     /// Whether the size fallback has been activated.
     pub is_size_fallback: bool,
     /// Warnings produced during compilation.
     pub warnings: Vec<Warning>,
+    /// Optimization remarks.
+    pub remarks: Vec<Remark>,
 }
 
 impl Build {
@@ -31,16 +41,24 @@ impl Build {
     pub fn new(
         bytecode: Option<Vec<u8>>,
         assembly: Option<String>,
+        llvm_ir: Option<String>,
+        llvm_ir_optimized: Option<String>,
         immutables: Option<BTreeMap<String, BTreeSet<u64>>>,
+        source_map: Option<SourceMap>,
         is_size_fallback: bool,
         warnings: Vec<Warning>,
+        remarks: Vec<Remark>,
     ) -> Self {
         Self {
             bytecode,
             assembly,
+            llvm_ir,
+            llvm_ir_optimized,
             immutables,
+            source_map,
             is_size_fallback,
             warnings,
+            remarks,
         }
     }
 }
@@ -0,0 +1,148 @@
+//!
+//! The LLVM IR generator debug information.
+//!
+
+use std::collections::HashMap;
+
+use inkwell::debug_info::AsDIScope;
+use inkwell::debug_info::DIFlagsConstants;
+
+use crate::codegen::source_map::location::Location as SourceLocation;
+
+///
+/// The LLVM IR generator debug information.
+///
+/// Source locations are attached to the LLVM IR as line-only debug locations, where the line
+/// number is a 1-based index into the table of interned source locations. It allows carrying
+/// byte ranges through the optimizer and code generator, and reading them back from the
+/// `.loc` directives of the emitted assembly.
+///
+pub struct DebugInfo<'ctx> {
+    /// The debug information builder.
+    builder: inkwell::debug_info::DebugInfoBuilder<'ctx>,
+    /// The compile unit.
+    compile_unit: inkwell::debug_info::DICompileUnit<'ctx>,
+    /// The interned source locations.
+    locations: Vec<SourceLocation>,
+    /// The source location indexes.
+    indexes: HashMap<SourceLocation, u32>,
+}
+
+impl<'ctx> DebugInfo<'ctx> {
+    /// The debug information version expected by LLVM.
+    const DEBUG_INFO_VERSION: u64 = 3;
+
+    ///
+    /// Initializes the debug information for `module`.
+    ///
+    pub fn new(
+        llvm: &'ctx inkwell::context::Context,
+        module: &inkwell::module::Module<'ctx>,
+    ) -> Self {
+        module.add_basic_value_flag(
+            "Debug Info Version",
+            inkwell::module::FlagBehavior::Warning,
+            llvm.i32_type().const_int(Self::DEBUG_INFO_VERSION, false),
+        );
+
+        let file_name = module.get_name().to_str().expect("Always valid");
+        let (builder, compile_unit) = module.create_debug_info_builder(
+            true,
+            inkwell::debug_info::DWARFSourceLanguage::C,
+            file_name,
+            "",
+            crate::r#const::DEBUG_INFO_PRODUCER,
+            true,
+            "",
+            0,
+            "",
+            inkwell::debug_info::DWARFEmissionKind::LineTablesOnly,
+            0,
+            false,
+            false,
+            "",
+            "",
+        );
+
+        Self {
+            builder,
+            compile_unit,
+            locations: Vec::new(),
+            indexes: HashMap::new(),
+        }
+    }
+
+    ///
+    /// Attaches a subprogram to `function`.
+    ///
+    pub fn declare_function(&self, function: inkwell::values::FunctionValue<'ctx>) {
+        let file = self.compile_unit.get_file();
+        let r#type = self.builder.create_subroutine_type(
+            file,
+            None,
+            &[],
+            inkwell::debug_info::DIFlags::PUBLIC,
+        );
+        let subprogram = self.builder.create_function(
+            self.compile_unit.as_debug_info_scope(),
+            function.get_name().to_str().expect("Always valid"),
+            None,
+            file,
+            0,
+            r#type,
+            false,
+            true,
+            0,
+            inkwell::debug_info::DIFlags::PUBLIC,
+            true,
+        );
+        function.set_subprogram(subprogram);
+    }
+
+    ///
+    /// Returns the debug location of `location` in the scope of `function`.
+    ///
+    /// Returns the line `0` location if `location` is not set.
+    ///
+    pub fn location(
+        &mut self,
+        llvm: &'ctx inkwell::context::Context,
+        function: inkwell::values::FunctionValue<'ctx>,
+        location: Option<SourceLocation>,
+    ) -> Option<inkwell::debug_info::DILocation<'ctx>> {
+        let subprogram = function.get_subprogram()?;
+        let line = match location {
+            Some(location) => match self.indexes.get(&location) {
+                Some(index) => *index,
+                None => {
+                    self.locations.push(location);
+                    let index = self.locations.len() as u32;
+                    self.indexes.insert(location, index);
+                    index
+                }
+            },
+            None => 0,
+        };
+        Some(self.builder.create_debug_location(
+            llvm,
+            line,
+            0,
+            subprogram.as_debug_info_scope(),
+            None,
+        ))
+    }
+
+    ///
+    /// Returns the interned source locations.
+    ///
+    pub fn locations(&self) -> &[SourceLocation] {
+        self.locations.as_slice()
+    }
+
+    ///
+    /// Finalizes the debug information.
+    ///
+    pub fn finalize(&self) {
+        self.builder.finalize();
+    }
+}
@@ -26,6 +26,8 @@ pub struct Intrinsics<'ctx> {
     pub mulmod: FunctionDeclaration<'ctx>,
     /// The corresponding intrinsic function name.
     pub byte: FunctionDeclaration<'ctx>,
+    /// The corresponding intrinsic function name.
+    pub ctlz: FunctionDeclaration<'ctx>,
 
     /// The corresponding intrinsic function name.
     pub mstore8: FunctionDeclaration<'ctx>,
@@ -99,10 +101,14 @@ pub struct Intrinsics<'ctx> {
     /// The corresponding intrinsic function name.
     pub blockhash: FunctionDeclaration<'ctx>,
     /// The corresponding intrinsic function name.
+    pub blobhash: FunctionDeclaration<'ctx>,
+    /// The corresponding intrinsic function name.
     pub coinbase: FunctionDeclaration<'ctx>,
     /// The corresponding intrinsic function name.
     pub basefee: FunctionDeclaration<'ctx>,
     /// The corresponding intrinsic function name.
+    pub blobbasefee: FunctionDeclaration<'ctx>,
+    /// The corresponding intrinsic function name.
     pub timestamp: FunctionDeclaration<'ctx>,
     /// The corresponding intrinsic function name.
     pub number: FunctionDeclaration<'ctx>,
@@ -154,6 +160,9 @@ impl<'ctx> Intrinsics<'ctx> {
     /// The corresponding intrinsic function name.
     pub const FUNCTION_BYTE: &'static str = "llvm.evm.byte";
 
+    /// The corresponding intrinsic function name.
+    pub const FUNCTION_CTLZ: &'static str = "llvm.ctlz.i256";
+
     /// The corresponding intrinsic function name.
     pub const FUNCTION_MSTORE8: &'static str = "llvm.evm.mstore8";
 
@@ -253,12 +262,18 @@ impl<'ctx> Intrinsics<'ctx> {
     /// The corresponding intrinsic function name.
     pub const FUNCTION_BLOCKHASH: &'static str = "llvm.evm.blockhash";
 
+    /// The corresponding intrinsic function name.
+    pub const FUNCTION_BLOBHASH: &'static str = "llvm.evm.blobhash";
+
     /// The corresponding intrinsic function name.
     pub const FUNCTION_COINBASE: &'static str = "llvm.evm.coinbase";
 
     /// The corresponding intrinsic function name.
     pub const FUNCTION_BASEFEE: &'static str = "llvm.evm.basefee";
 
+    /// The corresponding intrinsic function name.
+    pub const FUNCTION_BLOBBASEFEE: &'static str = "llvm.evm.blobbasefee";
+
     /// The corresponding intrinsic function name.
     pub const FUNCTION_TIMESTAMP: &'static str = "llvm.evm.timestamp";
 
@@ -391,6 +406,18 @@ impl<'ctx> Intrinsics<'ctx> {
                 false,
             ),
         );
+        let ctlz = Self::declare(
+            llvm,
+            module,
+            Self::FUNCTION_CTLZ,
+            field_type.fn_type(
+                &[
+                    field_type.as_basic_type_enum().into(),
+                    bool_type.as_basic_type_enum().into(),
+                ],
+                false,
+            ),
+        );
 
         let mstore8 = Self::declare(
             llvm,
@@ -701,6 +728,12 @@ impl<'ctx> Intrinsics<'ctx> {
             Self::FUNCTION_BLOCKHASH,
             field_type.fn_type(&[field_type.as_basic_type_enum().into()], false),
         );
+        let blobhash = Self::declare(
+            llvm,
+            module,
+            Self::FUNCTION_BLOBHASH,
+            field_type.fn_type(&[field_type.as_basic_type_enum().into()], false),
+        );
         let coinbase = Self::declare(
             llvm,
             module,
@@ -713,6 +746,12 @@ impl<'ctx> Intrinsics<'ctx> {
             Self::FUNCTION_BASEFEE,
             field_type.fn_type(&[], false),
         );
+        let blobbasefee = Self::declare(
+            llvm,
+            module,
+            Self::FUNCTION_BLOBBASEFEE,
+            field_type.fn_type(&[], false),
+        );
         let timestamp = Self::declare(
             llvm,
             module,
@@ -852,6 +891,7 @@ impl<'ctx> Intrinsics<'ctx> {
             addmod,
             mulmod,
             byte,
+            ctlz,
 
             mstore8,
             msize,
@@ -890,8 +930,10 @@ impl<'ctx> Intrinsics<'ctx> {
             gasprice,
             gaslimit,
             blockhash,
+            blobhash,
             coinbase,
             basefee,
+            blobbasefee,
             timestamp,
             number,
             chainid,
@@ -940,6 +982,7 @@ impl<'ctx> Intrinsics<'ctx> {
         let field_type = llvm.custom_width_int_type(solx_utils::BIT_LENGTH_FIELD as u32);
 
         match name {
+            name if name == Self::FUNCTION_CTLZ => vec![field_type.as_basic_type_enum()],
             name if name == Self::FUNCTION_MEMORY_MOVE_HEAP => vec![
                 llvm.ptr_type(AddressSpace::Heap.into())
                     .as_basic_type_enum(),
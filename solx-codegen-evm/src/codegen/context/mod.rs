@@ -3,12 +3,14 @@
 //!
 
 pub mod address_space;
+pub mod debug_info;
 pub mod evmla_data;
 pub mod function;
 pub mod solidity_data;
 pub mod yul_data;
 
 use std::cell::RefCell;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::rc::Rc;
 
@@ -16,6 +18,10 @@ use inkwell::types::BasicType;
 
 use crate::codegen::build::Build as EVMBuild;
 use crate::codegen::profiler::Profiler;
+use crate::codegen::remark::Remark;
+use crate::codegen::source_map::function_debug_data::FunctionDebugData;
+use crate::codegen::source_map::location::Location as SourceLocation;
+use crate::codegen::source_map::SourceMap;
 use crate::codegen::warning::Warning;
 use crate::context::attribute::Attribute;
 use crate::context::function::declaration::Declaration as FunctionDeclaration;
@@ -28,6 +34,7 @@ use crate::optimizer::Optimizer;
 use crate::target_machine::TargetMachine;
 
 use self::address_space::AddressSpace;
+use self::debug_info::DebugInfo;
 use self::evmla_data::EVMLAData;
 use self::function::intrinsics::Intrinsics;
 use self::function::Function;
@@ -53,6 +60,10 @@ pub struct Context<'ctx> {
     llvm_options: Vec<String>,
     /// The current contract code type, which can be deploy or runtime.
     code_segment: solx_utils::CodeSegment,
+    /// The bytecode size limits.
+    code_size_limits: solx_utils::CodeSizeLimits,
+    /// The target EVM version.
+    evm_version: solx_utils::EVMVersion,
     /// The LLVM intrinsic functions, defined on the LLVM side.
     intrinsics: Intrinsics<'ctx>,
     /// The declared functions.
@@ -64,6 +75,16 @@ pub struct Context<'ctx> {
 
     /// The debug configuration telling whether to dump the needed IRs.
     debug_config: Option<DebugConfig>,
+    /// The debug information, which is only set if the source map is requested.
+    debug_info: Option<DebugInfo<'ctx>>,
+    /// Whether the function debug data is requested.
+    is_function_debug_data_enabled: bool,
+    /// Whether the unoptimized LLVM IR is requested.
+    is_llvm_ir_enabled: bool,
+    /// Whether the optimized LLVM IR is requested.
+    is_llvm_ir_optimized_enabled: bool,
+    /// The optimization remarks, which are only collected if requested.
+    remarks: Option<Vec<Remark>>,
 
     /// The Solidity data.
     solidity_data: Option<SolidityData>,
@@ -101,12 +122,19 @@ impl<'ctx> Context<'ctx> {
             optimizer,
             module,
             code_segment,
+            code_size_limits: solx_utils::CodeSizeLimits::default(),
+            evm_version: solx_utils::EVMVersion::default(),
             intrinsics,
             functions: HashMap::with_capacity(Self::FUNCTIONS_HASHMAP_INITIAL_CAPACITY),
             current_function: None,
             loop_stack: Vec::with_capacity(Self::LOOP_STACK_INITIAL_CAPACITY),
 
             debug_config,
+            debug_info: None,
+            is_function_debug_data_enabled: false,
+            is_llvm_ir_enabled: false,
+            is_llvm_ir_optimized_enabled: false,
+            remarks: None,
 
             solidity_data: None,
             yul_data: None,
@@ -132,16 +160,37 @@ impl<'ctx> Context<'ctx> {
             "InitVerify",
             self.optimizer.settings(),
         );
-        let target_machine =
-            TargetMachine::new(self.optimizer.settings(), self.llvm_options.as_slice())?;
+        let mut llvm_options = self.llvm_options.clone();
+        if self.remarks.is_some() {
+            llvm_options.extend(Remark::LLVM_OPTIONS.map(|option| option.to_owned()));
+        }
+        let target_machine = TargetMachine::new(
+            self.optimizer.settings(),
+            self.evm_version,
+            llvm_options.as_slice(),
+        )?;
         target_machine.set_target_data(self.module());
         target_machine.set_asm_verbosity(true);
 
+        if let (Some(debug_info), false) = (self.debug_info.as_ref(), is_size_fallback) {
+            debug_info.finalize();
+        }
+
         let spill_area = self
             .optimizer
             .settings()
             .spill_area_size()
             .map(|spill_area_size| (crate::r#const::SOLC_USER_MEMORY_OFFSET, spill_area_size));
+        if let (Some(remarks), Some((offset, size)), false) =
+            (self.remarks.as_mut(), spill_area, is_size_fallback)
+        {
+            remarks.push(Remark::new(
+                contract_path.to_owned(),
+                self.code_segment,
+                "InitVerify".to_owned(),
+                format!("A spill area of {size}B has been allocated at offset {offset} to resolve stack-too-deep errors"),
+            ));
+        }
 
         if let Some(ref debug_config) = self.debug_config {
             debug_config.dump_llvm_ir_unoptimized(
@@ -157,6 +206,9 @@ impl<'ctx> Context<'ctx> {
                 self.code_segment,
             )
         })?;
+        let llvm_ir = self
+            .is_llvm_ir_enabled
+            .then(|| self.module().print_to_string().to_string());
         run_init_verify.borrow_mut().finish();
 
         let module_size_fallback = self.module.clone();
@@ -183,9 +235,23 @@ impl<'ctx> Context<'ctx> {
                 self.code_segment,
             )
         })?;
+        let llvm_ir_optimized = self
+            .is_llvm_ir_optimized_enabled
+            .then(|| self.module().print_to_string().to_string());
         run_optimize_verify.borrow_mut().finish();
+        if let Some(remarks) = self.remarks.as_mut() {
+            remarks.extend(Remark::take_llvm_remarks(
+                contract_path,
+                self.code_segment,
+                "OptimizeVerify",
+            )?);
+        }
 
-        let assembly_buffer = if output_assembly || self.debug_config.is_some() {
+        let is_assembly_required = output_assembly
+            || self.debug_config.is_some()
+            || self.debug_info.is_some()
+            || self.is_function_debug_data_enabled;
+        let assembly_buffer = if is_assembly_required {
             let run_emit_llvm_assembly = profiler.start_evm_translation_unit(
                 contract_path,
                 self.code_segment,
@@ -211,12 +277,41 @@ impl<'ctx> Context<'ctx> {
             }
 
             run_emit_llvm_assembly.borrow_mut().finish();
+            if let Some(remarks) = self.remarks.as_mut() {
+                remarks.extend(Remark::take_llvm_remarks(
+                    contract_path,
+                    self.code_segment,
+                    "EmitLLVMAssembly",
+                )?);
+            }
             Some(assembly_buffer)
         } else {
             None
         };
         let assembly = assembly_buffer
             .map(|assembly_buffer| String::from_utf8_lossy(assembly_buffer.as_slice()).to_string());
+        let source_map = match assembly.as_deref() {
+            Some(assembly) if self.debug_info.is_some() || self.is_function_debug_data_enabled => {
+                let locations = self
+                    .debug_info
+                    .as_ref()
+                    .map(|debug_info| debug_info.locations())
+                    .unwrap_or_default();
+                let mut source_map =
+                    SourceMap::try_from_assembly(assembly, locations).map_err(|error| {
+                        anyhow::anyhow!("{} code source map: {error}", self.code_segment)
+                    })?;
+                if self.is_function_debug_data_enabled {
+                    source_map.functions = Some(self.function_debug_data());
+                }
+                if self.debug_info.is_some() {
+                    self.module.strip_debug_info();
+                }
+                Some(source_map)
+            }
+            _ => None,
+        };
+        let assembly = assembly.filter(|_| output_assembly || self.debug_config.is_some());
 
         if output_bytecode {
             let run_emit_bytecode = profiler.start_evm_translation_unit(
@@ -231,16 +326,20 @@ impl<'ctx> Context<'ctx> {
                     anyhow::anyhow!("{} bytecode emitting: {error}", self.code_segment)
                 })?;
             run_emit_bytecode.borrow_mut().finish();
+            if let Some(remarks) = self.remarks.as_mut() {
+                remarks.extend(Remark::take_llvm_remarks(
+                    contract_path,
+                    self.code_segment,
+                    "EmitBytecode",
+                )?);
+            }
 
             let immutables = match self.code_segment {
                 solx_utils::CodeSegment::Deploy => None,
                 solx_utils::CodeSegment::Runtime => Some(bytecode_buffer.get_immutables_evm()),
             };
 
-            let bytecode_size_limit = match self.code_segment {
-                solx_utils::CodeSegment::Deploy => crate::r#const::DEPLOY_CODE_SIZE_LIMIT,
-                solx_utils::CodeSegment::Runtime => crate::r#const::RUNTIME_CODE_SIZE_LIMIT,
-            };
+            let bytecode_size_limit = self.code_size_limits.get(self.code_segment);
 
             let mut warnings = Vec::with_capacity(1);
             let bytecode_size = bytecode_buffer.as_slice().len();
@@ -248,45 +347,64 @@ impl<'ctx> Context<'ctx> {
                 if self.optimizer.settings() == &OptimizerSettings::cycles()
                     && self.optimizer.settings().is_fallback_to_size_enabled()
                 {
-                    crate::codegen::IS_SIZE_FALLBACK
-                        .compare_exchange(
-                            false,
-                            true,
-                            std::sync::atomic::Ordering::Relaxed,
-                            std::sync::atomic::Ordering::Relaxed,
-                        )
-                        .expect("Failed to set the global size fallback flag");
+                    assert!(
+                        !crate::codegen::IS_SIZE_FALLBACK.replace(true),
+                        "Failed to set the thread-local size fallback flag"
+                    );
+                    if let Some(remarks) = self.remarks.as_mut() {
+                        remarks.push(Remark::new(
+                            contract_path.to_owned(),
+                            self.code_segment,
+                            "EmitBytecode".to_owned(),
+                            format!("The bytecode size of {bytecode_size}B exceeds the limit of {bytecode_size_limit}B, so the module is recompiled with size optimizations"),
+                        ));
+                    }
+                    drop(target_machine);
                     self.optimizer = Optimizer::new(OptimizerSettings::size());
                     self.module = module_size_fallback;
                     for function in self.module.get_functions() {
                         Function::set_size_attributes(self.llvm, function);
                     }
                     return self.build(output_assembly, output_bytecode, true, profiler);
-                } else {
-                    warnings.push(match self.code_segment {
-                        solx_utils::CodeSegment::Deploy => Warning::DeployCodeSize {
-                            found: bytecode_size,
-                        },
-                        solx_utils::CodeSegment::Runtime => Warning::RuntimeCodeSize {
-                            found: bytecode_size,
-                        },
-                    })
+                }
+
+                let warning = match self.code_segment {
+                    solx_utils::CodeSegment::Deploy => Warning::DeployCodeSize {
+                        found: bytecode_size,
+                        limit: bytecode_size_limit,
+                    },
+                    solx_utils::CodeSegment::Runtime => Warning::RuntimeCodeSize {
+                        found: bytecode_size,
+                        limit: bytecode_size_limit,
+                    },
                 };
+                if self.code_size_limits.is_overrun_error {
+                    anyhow::bail!("{warning}");
+                }
+                warnings.push(warning);
             }
             Ok(EVMBuild::new(
                 Some(bytecode_buffer.as_slice().to_vec()),
                 assembly,
+                llvm_ir,
+                llvm_ir_optimized,
                 immutables,
+                source_map,
                 is_size_fallback,
                 warnings,
+                self.remarks.take().unwrap_or_default(),
             ))
         } else {
             Ok(EVMBuild::new(
                 None,
                 assembly,
+                llvm_ir,
+                llvm_ir_optimized,
                 None,
+                source_map,
                 is_size_fallback,
                 vec![],
+                self.remarks.take().unwrap_or_default(),
             ))
         }
     }
@@ -300,6 +418,146 @@ impl<'ctx> Context<'ctx> {
             .map_err(|error| anyhow::anyhow!(error.to_string()))
     }
 
+    ///
+    /// Enables the source map generation.
+    ///
+    /// Must be called before any code is generated, so all functions get their debug information.
+    ///
+    pub fn enable_source_map(&mut self) {
+        let debug_info = DebugInfo::new(self.llvm, &self.module);
+        for function in self.functions.values() {
+            debug_info.declare_function(function.borrow().declaration().value);
+        }
+        self.debug_info = Some(debug_info);
+    }
+
+    ///
+    /// Sets the bytecode size limits.
+    ///
+    pub fn set_code_size_limits(&mut self, code_size_limits: solx_utils::CodeSizeLimits) {
+        self.code_size_limits = code_size_limits;
+    }
+
+    ///
+    /// Sets the target EVM version.
+    ///
+    pub fn set_evm_version(&mut self, evm_version: solx_utils::EVMVersion) {
+        self.evm_version = evm_version;
+    }
+
+    ///
+    /// Returns the target EVM version.
+    ///
+    pub fn evm_version(&self) -> solx_utils::EVMVersion {
+        self.evm_version
+    }
+
+    ///
+    /// Checks whether the `opcode` is available on the target EVM version.
+    ///
+    pub fn check_opcode(&self, opcode: u8) -> anyhow::Result<()> {
+        let opcode = solx_utils::Opcode(opcode);
+        let required_evm_version = opcode.evm_version();
+        if self.evm_version < required_evm_version {
+            anyhow::bail!(
+                "The `{opcode}` instruction is not supported by EVM version `{}`. It is only available since `{required_evm_version}`.",
+                self.evm_version,
+            );
+        }
+        Ok(())
+    }
+
+    ///
+    /// Enables the function debug data generation.
+    ///
+    pub fn enable_function_debug_data(&mut self) {
+        self.is_function_debug_data_enabled = true;
+    }
+
+    ///
+    /// Enables the unoptimized LLVM IR output.
+    ///
+    pub fn enable_llvm_ir(&mut self) {
+        self.is_llvm_ir_enabled = true;
+    }
+
+    ///
+    /// Enables the optimized LLVM IR output.
+    ///
+    pub fn enable_llvm_ir_optimized(&mut self) {
+        self.is_llvm_ir_optimized_enabled = true;
+    }
+
+    ///
+    /// Enables the optimization remarks collection.
+    ///
+    /// Installs a diagnostic handler collecting the LLVM remarks emitted for this context.
+    ///
+    pub fn enable_remarks(&mut self) {
+        unsafe {
+            self.llvm.set_diagnostic_handler(
+                crate::codegen::remark::diagnostic_handler,
+                std::ptr::null_mut(),
+            );
+        }
+        self.remarks = Some(Vec::new());
+    }
+
+    ///
+    /// Returns the debug data of the functions generated from the source code.
+    ///
+    /// The entry function is omitted, as it always starts at the beginning of the bytecode.
+    ///
+    fn function_debug_data(&self) -> BTreeMap<String, FunctionDebugData> {
+        self.functions
+            .iter()
+            .filter(|(name, _function)| name.as_str() != crate::r#const::ENTRY_FUNCTION_NAME)
+            .map(|(name, function)| {
+                let function = function.borrow();
+                let id = self
+                    .yul_data
+                    .as_ref()
+                    .and_then(|data| data.function_id(name.as_str()));
+                let parameter_slots = function.declaration().r#type.count_param_types() as usize;
+                let return_slots = function.return_data_size() / solx_utils::BYTE_LENGTH_FIELD;
+                (
+                    name.to_owned(),
+                    FunctionDebugData::new(id, parameter_slots, return_slots),
+                )
+            })
+            .collect()
+    }
+
+    ///
+    /// Sets the source location of the subsequently generated instructions.
+    ///
+    /// Does nothing if the source map is not requested.
+    ///
+    pub fn set_source_location(&mut self, location: Option<SourceLocation>) {
+        let function = match self.current_function.as_ref() {
+            Some(function) => function.borrow().declaration().value,
+            None => return,
+        };
+        self.set_debug_location(function, location);
+    }
+
+    ///
+    /// Sets the debug location of the subsequently generated instructions in the scope of `function`.
+    ///
+    fn set_debug_location(
+        &mut self,
+        function: inkwell::values::FunctionValue<'ctx>,
+        location: Option<SourceLocation>,
+    ) {
+        if let Some(debug_location) = self
+            .debug_info
+            .as_mut()
+            .and_then(|debug_info| debug_info.location(self.llvm, function, location))
+        {
+            self.builder.set_current_debug_location(debug_location);
+        }
+    }
+
     ///
     /// Returns the LLVM intrinsics collection reference.
     ///
@@ -532,6 +790,10 @@ impl<'ctx> IContext<'ctx> for Context<'ctx> {
         linkage: Option<inkwell::module::Linkage>,
     ) -> anyhow::Result<Rc<RefCell<Self::Function>>> {
         let value = self.module().add_function(name, r#type, linkage);
+        if let Some(debug_info) = self.debug_info.as_ref() {
+            debug_info.declare_function(value);
+        }
+        self.set_debug_location(value, None);
 
         let entry_block = self.llvm.append_basic_block(value, "entry");
         let return_block = self.llvm.append_basic_block(value, "return");
@@ -585,6 +847,7 @@ impl<'ctx> IContext<'ctx> for Context<'ctx> {
             self.functions.get(name).cloned().ok_or_else(|| {
                 anyhow::anyhow!("Failed to activate an undeclared function `{name}`")
             })?;
+        self.set_debug_location(function.borrow().declaration().value, None);
         self.current_function = Some(function);
         Ok(())
     }
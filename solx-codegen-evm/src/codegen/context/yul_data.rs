@@ -4,6 +4,7 @@
 
 use std::collections::BTreeMap;
 
+use crate::codegen::source_map::location::Location as SourceLocation;
 use crate::context::traits::yul_data::IYulData;
 
 ///
@@ -15,14 +16,45 @@ use crate::context::traits::yul_data::IYulData;
 pub struct YulData {
     /// Mapping from Yul object identifiers to full contract paths.
     identifier_paths: BTreeMap<String, String>,
+    /// Mapping from Yul source code positions to the `@src` annotations found there.
+    source_locations: BTreeMap<(usize, usize), SourceLocation>,
+    /// Mapping from Yul function names to the AST IDs of their Solidity definitions.
+    function_ids: BTreeMap<String, usize>,
 }
 
 impl YulData {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(identifier_paths: BTreeMap<String, String>) -> Self {
-        Self { identifier_paths }
+    pub fn new(
+        identifier_paths: BTreeMap<String, String>,
+        source_locations: BTreeMap<(usize, usize), SourceLocation>,
+        function_ids: BTreeMap<String, usize>,
+    ) -> Self {
+        Self {
+            identifier_paths,
+            source_locations,
+            function_ids,
+        }
+    }
+
+    ///
+    /// Returns the source location of the Yul code at `line` and `column`.
+    ///
+    /// It is the closest `@src` annotation that precedes the position.
+    ///
+    pub fn source_location(&self, line: usize, column: usize) -> Option<SourceLocation> {
+        self.source_locations
+            .range(..(line, column))
+            .next_back()
+            .map(|(_position, location)| *location)
+    }
+
+    ///
+    /// Returns the AST ID of the Solidity definition the Yul function `name` has been generated from.
+    ///
+    pub fn function_id(&self, name: &str) -> Option<usize> {
+        self.function_ids.get(name).copied()
     }
 }
 
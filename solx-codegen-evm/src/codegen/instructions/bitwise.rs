@@ -226,3 +226,24 @@ pub fn byte<'ctx>(
         )?
         .expect("Always exists"))
 }
+
+///
+/// Translates the `clz` instruction.
+///
+/// The zero operand is not poison, so the result is the word size (256 bits) for it.
+///
+pub fn count_leading_zeros<'ctx>(
+    context: &mut Context<'ctx>,
+    operand: inkwell::values::IntValue<'ctx>,
+) -> anyhow::Result<inkwell::values::BasicValueEnum<'ctx>> {
+    Ok(context
+        .build_call(
+            context.intrinsics().ctlz,
+            &[
+                operand.as_basic_value_enum(),
+                context.bool_const(false).as_basic_value_enum(),
+            ],
+            "clz",
+        )?
+        .expect("Always exists"))
+}
@@ -89,6 +89,22 @@ pub fn block_hash<'ctx>(
         .expect("Always exists"))
 }
 
+///
+/// Translates the `blob_hash` instruction.
+///
+pub fn blob_hash<'ctx>(
+    context: &mut Context<'ctx>,
+    index: inkwell::values::IntValue<'ctx>,
+) -> anyhow::Result<inkwell::values::BasicValueEnum<'ctx>> {
+    Ok(context
+        .build_call(
+            context.intrinsics().blobhash,
+            &[index.as_basic_value_enum()],
+            "blobhash",
+        )?
+        .expect("Always exists"))
+}
+
 ///
 /// Translates the `difficulty` instruction.
 ///
@@ -122,6 +138,17 @@ pub fn basefee<'ctx>(
         .expect("Always exists"))
 }
 
+///
+/// Translates the `blobbasefee` instruction.
+///
+pub fn blob_basefee<'ctx>(
+    context: &mut Context<'ctx>,
+) -> anyhow::Result<inkwell::values::BasicValueEnum<'ctx>> {
+    Ok(context
+        .build_call(context.intrinsics().blobbasefee, &[], "blobbasefee")?
+        .expect("Always exists"))
+}
+
 ///
 /// Translates the `msize` instruction.
 ///
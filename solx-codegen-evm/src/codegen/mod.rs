@@ -7,10 +7,12 @@ pub mod build;
 pub mod context;
 pub mod instructions;
 pub mod profiler;
+pub mod remark;
+pub mod source_map;
 pub mod warning;
 
+use std::cell::Cell;
 use std::collections::BTreeMap;
-use std::sync::atomic::AtomicBool;
 
 use self::context::Context;
 
@@ -37,9 +39,11 @@ pub fn append_metadata(
         .map_err(|error| anyhow::anyhow!("bytecode metadata appending error: {error}"))
 }
 
-/// Whether the size fallback is activated during the compilation.
-/// Only set once, as we're only compiling one traslation unit in a process.
-pub static IS_SIZE_FALLBACK: AtomicBool = AtomicBool::new(false);
+thread_local! {
+    /// Whether the size fallback is activated during the compilation.
+    /// Only set once, as we're only compiling one translation unit in a worker thread.
+    pub static IS_SIZE_FALLBACK: Cell<bool> = const { Cell::new(false) };
+}
 
 ///
 /// Assembles the main buffer and its dependencies from `bytecode_buffers`.
@@ -3,6 +3,7 @@
 //!
 
 pub mod run;
+pub mod trace_event;
 
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -12,6 +13,7 @@ use indexmap::IndexMap;
 use crate::optimizer::settings::Settings as OptimizerSettings;
 
 use self::run::Run;
+use self::trace_event::TraceEvent;
 
 ///
 /// Compiler pipeline profiler.
@@ -23,6 +25,9 @@ pub struct Profiler {
 }
 
 impl Profiler {
+    /// The trace event category of the generic pipeline elements.
+    pub const PIPELINE_CATEGORY: &'static str = "pipeline";
+
     ///
     /// Starts a new run for a generic part of the pipeline.
     ///
@@ -33,7 +38,7 @@ impl Profiler {
             "Translation unit run `{run_name}` already exists"
         );
 
-        self.start_run(run_name)
+        self.start_run(run_name, Self::PIPELINE_CATEGORY.to_owned())
     }
 
     ///
@@ -50,15 +55,15 @@ impl Profiler {
             "SpillArea({})",
             optimizer_settings.spill_area_size().unwrap_or_default()
         );
-        let run_name = format!(
-            "{full_path}:{code_segment}/{description}/{optimizer_settings}/{spill_area_description}",
-        );
+        let category = format!("{full_path}:{code_segment}");
+        let run_name =
+            format!("{category}/{description}/{optimizer_settings}/{spill_area_description}");
         assert!(
             !self.timings.contains_key(run_name.as_str()),
             "Translation unit run `{run_name}` already exists"
         );
 
-        self.start_run(run_name)
+        self.start_run(run_name, category)
     }
 
     ///
@@ -78,15 +83,38 @@ impl Profiler {
     }
 
     ///
-    /// Starts a new run with the given name.
+    /// Returns the trace events of the profiler runs.
+    ///
+    /// The translation unit runs are named without the category prefix, which is already
+    /// shown by the trace viewers.
+    ///
+    pub fn to_trace_events(&self) -> Vec<TraceEvent> {
+        self.timings
+            .iter()
+            .map(|(name, run)| {
+                let run = run.borrow();
+                let name = name
+                    .strip_prefix(format!("{}/", run.category).as_str())
+                    .unwrap_or(name.as_str())
+                    .to_owned();
+                run.to_trace_event(name)
+            })
+            .collect()
+    }
+
+    ///
+    /// Starts a new run with the given name and trace event category.
     ///
-    fn start_run(&mut self, name: String) -> Rc<RefCell<Run>> {
+    fn start_run(&mut self, name: String, category: String) -> Rc<RefCell<Run>> {
         assert!(
             !self.timings.contains_key(name.as_str()),
             "Run `{name}` already exists"
         );
 
-        let run = Rc::new(RefCell::new(Run::default()));
+        let run = Rc::new(RefCell::new(Run {
+            category,
+            ..Run::default()
+        }));
         self.timings.insert(name, run.clone());
         run
     }
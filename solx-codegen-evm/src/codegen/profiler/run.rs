@@ -4,14 +4,26 @@
 
 use std::time::Duration;
 use std::time::Instant;
+use std::time::SystemTime;
+
+use crate::codegen::profiler::trace_event::TraceEvent;
 
 ///
 /// Compiler pipeline profiler run.
 ///
 #[derive(Debug)]
 pub struct Run {
+    /// The trace event category, e.g. the translation unit.
+    pub category: String,
     /// Start time.
     pub start_time: Instant,
+    /// Start time in microseconds since the Unix epoch.
+    /// Unlike `start_time`, it can be compared across processes.
+    pub start_timestamp: u64,
+    /// The ID of the process the run was started in.
+    pub process_id: u32,
+    /// The ID of the thread the run was started in.
+    pub thread_id: u64,
     /// Recorded duration.
     pub duration: Option<Duration>,
 }
@@ -19,7 +31,14 @@ pub struct Run {
 impl Default for Run {
     fn default() -> Self {
         Run {
+            category: String::new(),
             start_time: Instant::now(),
+            start_timestamp: SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .map(|duration| duration.as_micros() as u64)
+                .unwrap_or_default(),
+            process_id: std::process::id(),
+            thread_id: TraceEvent::current_thread_id(),
             duration: None,
         }
     }
@@ -37,6 +56,20 @@ impl Run {
 
         self.duration = Some(self.start_time.elapsed());
     }
+
+    ///
+    /// Converts the finished run into a trace event named `name`.
+    ///
+    pub fn to_trace_event(&self, name: String) -> TraceEvent {
+        TraceEvent::new(
+            name,
+            self.category.clone(),
+            self.start_timestamp,
+            self.duration.expect("Always exists").as_micros() as u64,
+            self.process_id,
+            self.thread_id,
+        )
+    }
 }
 
 impl std::fmt::Display for Run {
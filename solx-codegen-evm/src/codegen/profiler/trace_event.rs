@@ -0,0 +1,78 @@
+//!
+//! Compiler pipeline profiler trace event.
+//!
+
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+/// The counter the thread IDs are allocated from.
+static THREAD_ID_COUNTER: AtomicU64 = AtomicU64::new(1);
+
+thread_local! {
+    /// The ID of the current thread, allocated on first use.
+    static THREAD_ID: u64 = THREAD_ID_COUNTER.fetch_add(1, Ordering::Relaxed);
+}
+
+///
+/// Compiler pipeline profiler trace event.
+///
+/// Serialized as a complete event of the Chrome trace format, which can be opened with
+/// `chrome://tracing` or Perfetto. The events of a thread are nested by their time ranges.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TraceEvent {
+    /// The pipeline element name.
+    pub name: String,
+    /// The event category, e.g. the translation unit.
+    #[serde(rename = "cat")]
+    pub category: String,
+    /// The event phase, which is always `X` for complete events.
+    #[serde(rename = "ph")]
+    pub phase: String,
+    /// Start time in microseconds since the Unix epoch.
+    #[serde(rename = "ts")]
+    pub timestamp: u64,
+    /// Duration in microseconds.
+    #[serde(rename = "dur")]
+    pub duration: u64,
+    /// The process ID.
+    pub pid: u32,
+    /// The thread ID.
+    pub tid: u64,
+}
+
+impl TraceEvent {
+    /// The complete event phase.
+    pub const PHASE_COMPLETE: &'static str = "X";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        name: String,
+        category: String,
+        timestamp: u64,
+        duration: u64,
+        pid: u32,
+        tid: u64,
+    ) -> Self {
+        Self {
+            name,
+            category,
+            phase: Self::PHASE_COMPLETE.to_owned(),
+            timestamp,
+            duration,
+            pid,
+            tid,
+        }
+    }
+
+    ///
+    /// Returns the ID of the current thread.
+    ///
+    /// The IDs are small sequential numbers unique within the process, as required by the trace format.
+    ///
+    pub fn current_thread_id() -> u64 {
+        THREAD_ID.with(|thread_id| *thread_id)
+    }
+}
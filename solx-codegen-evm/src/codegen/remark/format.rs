@@ -0,0 +1,38 @@
+//!
+//! The optimization remarks output format.
+//!
+
+use std::str::FromStr;
+
+///
+/// The optimization remarks output format.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// The YAML document stream with a `!Remark` document per remark.
+    #[default]
+    YAML,
+    /// The JSON array.
+    JSON,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "yaml" => Ok(Self::YAML),
+            "json" => Ok(Self::JSON),
+            string => anyhow::bail!("unknown optimization remarks format: `{string}`"),
+        }
+    }
+}
+
+impl std::fmt::Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::YAML => write!(f, "yaml"),
+            Self::JSON => write!(f, "json"),
+        }
+    }
+}
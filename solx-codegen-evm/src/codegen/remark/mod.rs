@@ -0,0 +1,177 @@
+//!
+//! The optimization remark.
+//!
+
+pub mod format;
+
+use std::cell::RefCell;
+use std::ffi::c_void;
+use std::ffi::CStr;
+use std::io::Write;
+
+use inkwell::llvm_sys::core::LLVMDisposeMessage;
+use inkwell::llvm_sys::core::LLVMGetDiagInfoDescription;
+use inkwell::llvm_sys::core::LLVMGetDiagInfoSeverity;
+use inkwell::llvm_sys::prelude::LLVMDiagnosticInfoRef;
+use inkwell::llvm_sys::LLVMDiagnosticSeverity;
+
+thread_local! {
+    /// The messages of the LLVM remarks emitted on the current thread since they were last taken.
+    static LLVM_REMARKS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    /// The messages of the LLVM errors reported on the current thread since they were last taken.
+    static LLVM_ERRORS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+///
+/// The optimization remark.
+///
+/// Explains a decision made either by LLVM, e.g. by the inliner or a loop transform,
+/// or by the compilation pipeline, e.g. a spill area allocation or the size fallback.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Remark {
+    /// The contract path the translation unit is profiled with.
+    pub contract_path: String,
+    /// The code segment.
+    pub code_segment: solx_utils::CodeSegment,
+    /// The pipeline element the remark was emitted in, e.g. `OptimizeVerify`.
+    pub pipeline_element: String,
+    /// The remark message.
+    pub message: String,
+}
+
+impl Remark {
+    ///
+    /// The LLVM options enabling the applied and missed transformation remarks of all passes.
+    ///
+    /// LLVM does not allow resetting the remark filters once they are set, so the remarks
+    /// are emitted for all translation units compiled afterwards in the same process.
+    /// They are only collected if the diagnostic handler is installed, though.
+    ///
+    pub const LLVM_OPTIONS: [&'static str; 2] = ["-pass-remarks=.*", "-pass-remarks-missed=.*"];
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        contract_path: String,
+        code_segment: solx_utils::CodeSegment,
+        pipeline_element: String,
+        message: String,
+    ) -> Self {
+        Self {
+            contract_path,
+            code_segment,
+            pipeline_element,
+            message,
+        }
+    }
+
+    ///
+    /// Takes the LLVM remarks emitted on the current thread, and attributes them to
+    /// `contract_path`, `code_segment`, and `pipeline_element`.
+    ///
+    /// Returns an error if LLVM has reported errors to the diagnostic handler in the meantime.
+    ///
+    pub fn take_llvm_remarks(
+        contract_path: &str,
+        code_segment: solx_utils::CodeSegment,
+        pipeline_element: &str,
+    ) -> anyhow::Result<Vec<Self>> {
+        let errors = LLVM_ERRORS.take();
+        if !errors.is_empty() {
+            LLVM_REMARKS.take();
+            anyhow::bail!(
+                "{code_segment} code {pipeline_element}: {}",
+                errors.join("\n")
+            );
+        }
+
+        Ok(LLVM_REMARKS
+            .take()
+            .into_iter()
+            .map(|message| {
+                Self::new(
+                    contract_path.to_owned(),
+                    code_segment,
+                    pipeline_element.to_owned(),
+                    message,
+                )
+            })
+            .collect())
+    }
+
+    ///
+    /// Converts the remark into a YAML document tagged with `!Remark`.
+    ///
+    /// The LLVM C API only exposes the remark descriptions, so the document contains the same
+    /// fields as the JSON output rather than the pass, name, and arguments of the remark.
+    ///
+    pub fn to_yaml(&self) -> String {
+        format!(
+            "--- !Remark\nContract: {}\nCodeSegment: {}\nPipelineElement: {}\nMessage: {}\n...\n",
+            Self::yaml_string(self.contract_path.as_str()),
+            self.code_segment,
+            Self::yaml_string(self.pipeline_element.as_str()),
+            Self::yaml_string(self.message.as_str()),
+        )
+    }
+
+    ///
+    /// Returns `value` as a double-quoted YAML scalar.
+    ///
+    fn yaml_string(value: &str) -> String {
+        let mut result = String::with_capacity(value.len() + 2);
+        result.push('"');
+        for character in value.chars() {
+            match character {
+                '"' => result.push_str("\\\""),
+                '\\' => result.push_str("\\\\"),
+                '\n' => result.push_str("\\n"),
+                '\r' => result.push_str("\\r"),
+                '\t' => result.push_str("\\t"),
+                character if character.is_control() => {
+                    result.push_str(format!("\\u{:04x}", character as u32).as_str())
+                }
+                character => result.push(character),
+            }
+        }
+        result.push('"');
+        result
+    }
+}
+
+///
+/// Handles the LLVM diagnostics while the optimization remarks are enabled.
+///
+/// The remarks are collected on the current thread, and so are the errors, which are returned
+/// by the next `Remark::take_llvm_remarks` call. Warnings and notes are printed to `stderr`
+/// the same way as by the default LLVM handler.
+///
+pub extern "C" fn diagnostic_handler(
+    diagnostic_info: LLVMDiagnosticInfoRef,
+    _context: *mut c_void,
+) {
+    let (severity, message) = unsafe {
+        let severity = LLVMGetDiagInfoSeverity(diagnostic_info);
+        let description = LLVMGetDiagInfoDescription(diagnostic_info);
+        let message = CStr::from_ptr(description).to_string_lossy().into_owned();
+        LLVMDisposeMessage(description);
+        (severity, message)
+    };
+
+    let prefix = match severity {
+        LLVMDiagnosticSeverity::LLVMDSRemark => {
+            LLVM_REMARKS.with_borrow_mut(|remarks| remarks.push(message));
+            return;
+        }
+        LLVMDiagnosticSeverity::LLVMDSError => {
+            LLVM_ERRORS.with_borrow_mut(|errors| errors.push(message));
+            return;
+        }
+        LLVMDiagnosticSeverity::LLVMDSWarning => "warning",
+        LLVMDiagnosticSeverity::LLVMDSNote => "note",
+    };
+    let _ = writeln!(std::io::stderr(), "{prefix}: {message}");
+}
@@ -0,0 +1,31 @@
+//!
+//! The source map function debug data.
+//!
+
+///
+/// The source map function debug data.
+///
+/// The entry point is not stored here, as it is only known after the final bytecode is assembled.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct FunctionDebugData {
+    /// AST ID of the function definition.
+    pub id: Option<usize>,
+    /// Number of stack slots taken by the function parameters.
+    pub parameter_slots: usize,
+    /// Number of stack slots taken by the function return values.
+    pub return_slots: usize,
+}
+
+impl FunctionDebugData {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(id: Option<usize>, parameter_slots: usize, return_slots: usize) -> Self {
+        Self {
+            id,
+            parameter_slots,
+            return_slots,
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//!
+//! The source map instruction.
+//!
+
+use crate::codegen::source_map::jump_type::JumpType;
+use crate::codegen::source_map::location::Location;
+
+///
+/// The source map instruction.
+///
+/// Represents an instruction of the emitted assembly along with its source location.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Instruction {
+    /// The instruction mnemonic.
+    pub mnemonic: String,
+    /// The source location.
+    pub location: Option<Location>,
+    /// The jump type, which is only resolved for `JUMP` instructions.
+    pub jump_type: JumpType,
+}
+
+impl Instruction {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(mnemonic: String, location: Option<Location>) -> Self {
+        Self {
+            mnemonic,
+            location,
+            jump_type: JumpType::default(),
+        }
+    }
+
+    ///
+    /// Whether the instruction matches the specified bytecode opcode.
+    ///
+    /// All `PUSH` instructions are considered equal, as their width may be changed by the
+    /// assembler and linker. Pseudo-instructions unknown to the EVM are resolved into `PUSH`es.
+    ///
+    pub fn matches(&self, opcode: solx_utils::Opcode) -> bool {
+        let opcode_name = match opcode.name() {
+            Some(name) => name,
+            None => return false,
+        };
+        if self.mnemonic == opcode_name {
+            return true;
+        }
+        opcode.is_push() && (self.mnemonic.starts_with("PUSH") || !Self::is_known(&self.mnemonic))
+    }
+
+    ///
+    /// Whether the mnemonic is a valid EVM opcode name.
+    ///
+    fn is_known(mnemonic: &str) -> bool {
+        (0..=u8::MAX).any(|byte| solx_utils::Opcode(byte).name() == Some(mnemonic))
+    }
+}
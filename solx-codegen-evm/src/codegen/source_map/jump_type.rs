@@ -0,0 +1,30 @@
+//!
+//! The source map jump type.
+//!
+
+///
+/// The source map jump type.
+///
+/// Follows the `solc` convention, where jumps into and out of functions are distinguished from
+/// the regular ones, so debuggers can maintain the call stack.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum JumpType {
+    /// A jump into a function.
+    Into,
+    /// A jump out of a function.
+    OutOf,
+    /// A regular jump or not a jump at all.
+    #[default]
+    Regular,
+}
+
+impl std::fmt::Display for JumpType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Into => write!(f, "i"),
+            Self::OutOf => write!(f, "o"),
+            Self::Regular => write!(f, "-"),
+        }
+    }
+}
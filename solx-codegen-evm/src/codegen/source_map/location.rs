@@ -0,0 +1,53 @@
+//!
+//! The source map location.
+//!
+
+///
+/// The source map location.
+///
+/// Follows the `solc` convention, where `-1` denotes an unknown value.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct Location {
+    /// The byte offset of the range start in the source file.
+    pub start: isize,
+    /// The length of the range in bytes.
+    pub length: isize,
+    /// The source file index.
+    pub source_index: isize,
+}
+
+impl Default for Location {
+    fn default() -> Self {
+        Self {
+            start: -1,
+            length: -1,
+            source_index: -1,
+        }
+    }
+}
+
+impl Location {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(start: isize, length: isize, source_index: isize) -> Self {
+        Self {
+            start,
+            length,
+            source_index,
+        }
+    }
+
+    ///
+    /// Creates a location from a `begin`-`end` range.
+    ///
+    pub fn new_with_range(begin: isize, end: isize, source_index: isize) -> Self {
+        let length = if begin >= 0 && end >= begin {
+            end - begin
+        } else {
+            -1
+        };
+        Self::new(begin, length, source_index)
+    }
+}
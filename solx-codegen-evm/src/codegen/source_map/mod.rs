@@ -0,0 +1,345 @@
+//!
+//! The source map.
+//!
+
+pub mod function_debug_data;
+pub mod instruction;
+pub mod jump_type;
+pub mod location;
+
+use std::collections::BTreeMap;
+
+use self::function_debug_data::FunctionDebugData;
+use self::instruction::Instruction;
+use self::jump_type::JumpType;
+use self::location::Location;
+
+///
+/// The source map.
+///
+/// Is extracted from the line tables of the emitted assembly, and resolved against the final
+/// bytecode after linking, since instruction widths are only known at that point.
+/// The function labels are kept as well, so the function entry points can be resolved the same way.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SourceMap {
+    /// The assembly instructions with their source locations.
+    pub instructions: Vec<Instruction>,
+    /// The function labels, mapped to the indexes of their first instructions.
+    pub labels: BTreeMap<String, usize>,
+    /// The function debug data, which is only set if requested.
+    pub functions: Option<BTreeMap<String, FunctionDebugData>>,
+}
+
+impl SourceMap {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(instructions: Vec<Instruction>, labels: BTreeMap<String, usize>) -> Self {
+        Self {
+            instructions,
+            labels,
+            functions: None,
+        }
+    }
+
+    ///
+    /// Parses the assembly text emitted with line tables.
+    ///
+    /// The line numbers in `.loc` directives are 1-based indexes into `locations`.
+    /// Line `0` means that the instruction has no source location.
+    ///
+    /// A `JUMP` to a label pushed right before it is a jump into a function if the label is a
+    /// function one. A `JUMP` to a dynamic destination can only be a return from a function.
+    ///
+    pub fn try_from_assembly(assembly: &str, locations: &[Location]) -> anyhow::Result<Self> {
+        let mut instructions = Vec::new();
+        let mut operands = Vec::new();
+        let mut labels = BTreeMap::new();
+        let mut current_location = None;
+        for line in assembly.lines() {
+            let line = line.split(';').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(directive) = line.strip_prefix(".loc") {
+                let index = directive
+                    .split_whitespace()
+                    .nth(1)
+                    .ok_or_else(|| anyhow::anyhow!("Invalid `.loc` directive: `{line}`"))?
+                    .parse::<usize>()
+                    .map_err(|error| {
+                        anyhow::anyhow!("Invalid `.loc` directive `{line}`: {error}")
+                    })?;
+                current_location = index
+                    .checked_sub(1)
+                    .and_then(|index| locations.get(index))
+                    .copied();
+                continue;
+            }
+            if line.starts_with('.') {
+                continue;
+            }
+            if let Some(label) = line.strip_suffix(':') {
+                labels.insert(label.trim_matches('"').to_owned(), instructions.len());
+                continue;
+            }
+
+            let mut tokens = line.split_whitespace();
+            let mnemonic = tokens.next().expect("Always exists");
+            if !mnemonic.chars().all(|character| {
+                character.is_ascii_uppercase() || character.is_ascii_digit() || character == '_'
+            }) {
+                continue;
+            }
+            instructions.push(Instruction::new(mnemonic.to_owned(), current_location));
+            operands.push(
+                tokens
+                    .next()
+                    .map(|operand| operand.trim_matches(['@', '"'])),
+            );
+        }
+
+        for index in 0..instructions.len() {
+            if instructions[index].mnemonic != "JUMP" {
+                continue;
+            }
+            let destination = index
+                .checked_sub(1)
+                .filter(|previous| instructions[*previous].mnemonic.starts_with("PUSH"))
+                .and_then(|previous| operands[previous])
+                .filter(|operand| {
+                    !operand.starts_with(|character: char| character.is_ascii_digit())
+                });
+            instructions[index].jump_type = match destination {
+                Some(label) if labels.contains_key(label) => JumpType::Into,
+                Some(_) => JumpType::Regular,
+                None => JumpType::OutOf,
+            };
+        }
+
+        Ok(Self::new(instructions, labels))
+    }
+
+    ///
+    /// Resolves the source map against the main code of the final bytecode, returning it in the
+    /// `solc` compressed `s:l:f:j` format.
+    ///
+    /// `main_code` must not include the appended dependencies and metadata.
+    ///
+    pub fn compress(&self, main_code: &[u8]) -> anyhow::Result<String> {
+        let entries = self
+            .align(main_code)?
+            .into_iter()
+            .map(|(_offset, index)| {
+                (
+                    self.instructions[index].location.unwrap_or_default(),
+                    self.instructions[index].jump_type,
+                )
+            })
+            .collect::<Vec<(Location, JumpType)>>();
+
+        let mut compressed = Vec::with_capacity(entries.len());
+        let mut previous: Option<(Location, JumpType)> = None;
+        for (location, jump_type) in entries.into_iter() {
+            let mut fields = match previous {
+                Some((previous_location, previous_jump_type)) => vec![
+                    Self::field(location.start, previous_location.start),
+                    Self::field(location.length, previous_location.length),
+                    Self::field(location.source_index, previous_location.source_index),
+                    Self::field(jump_type, previous_jump_type),
+                ],
+                None => vec![
+                    location.start.to_string(),
+                    location.length.to_string(),
+                    location.source_index.to_string(),
+                    jump_type.to_string(),
+                ],
+            };
+            while fields.last().is_some_and(|field| field.is_empty()) {
+                fields.pop();
+            }
+            compressed.push(fields.join(":"));
+            previous = Some((location, jump_type));
+        }
+        Ok(compressed.join(";"))
+    }
+
+    ///
+    /// Resolves the function label entry points against the main code of the final bytecode.
+    ///
+    /// `main_code` must not include the appended dependencies and metadata.
+    /// The functions that have been inlined or removed by the optimizer are omitted.
+    ///
+    pub fn entry_points(&self, main_code: &[u8]) -> anyhow::Result<BTreeMap<&str, u64>> {
+        let alignment = self.align(main_code)?;
+        Ok(self
+            .labels
+            .iter()
+            .filter_map(|(label, label_index)| {
+                alignment
+                    .iter()
+                    .find(|(_offset, index)| index >= label_index)
+                    .map(|(offset, _index)| (label.as_str(), *offset as u64))
+            })
+            .collect())
+    }
+
+    ///
+    /// Aligns the assembly instructions with the `main_code` of the final bytecode.
+    ///
+    /// Returns the bytecode instruction offsets, each with the index of the matching assembly
+    /// instruction, or an error if any instruction of the main code cannot be matched.
+    ///
+    /// A single assembly instruction missing from the bytecode is skipped. Otherwise, the alignment
+    /// is resynchronized on a `JUMPDEST` at the next function label position, as labels are the
+    /// only points where the assembly and the bytecode are known to meet.
+    ///
+    fn align(&self, main_code: &[u8]) -> anyhow::Result<Vec<(usize, usize)>> {
+        let mut alignment = Vec::with_capacity(self.instructions.len());
+        let mut offset = 0;
+        let mut index = 0;
+        while offset < main_code.len() && index < self.instructions.len() {
+            let opcode = solx_utils::Opcode(main_code[offset]);
+            let matched = if self.instructions[index].matches(opcode) {
+                index
+            } else if self
+                .instructions
+                .get(index + 1)
+                .is_some_and(|instruction| instruction.matches(opcode))
+            {
+                index + 1
+            } else if opcode.name() == Some("JUMPDEST") {
+                self.labels
+                    .values()
+                    .copied()
+                    .filter(|label_index| {
+                        *label_index > index
+                            && self
+                                .instructions
+                                .get(*label_index)
+                                .is_some_and(|instruction| instruction.matches(opcode))
+                    })
+                    .min()
+                    .ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "Source map alignment failed: no label matches the `JUMPDEST` at offset {offset}"
+                        )
+                    })?
+            } else {
+                anyhow::bail!(
+                    "Source map alignment failed: the `{}` instruction at offset {offset} does not match the assembly",
+                    opcode.name().unwrap_or("INVALID"),
+                );
+            };
+            alignment.push((offset, matched));
+            index = matched + 1;
+            offset += 1 + opcode.push_size();
+        }
+        if offset < main_code.len() {
+            anyhow::bail!(
+                "Source map alignment failed: the bytecode at offset {offset} has no assembly instructions left"
+            );
+        }
+        Ok(alignment)
+    }
+
+    ///
+    /// Returns the compressed field value, which is empty if it has not changed.
+    ///
+    fn field<T>(value: T, previous: T) -> String
+    where
+        T: PartialEq + ToString,
+    {
+        if value == previous {
+            String::new()
+        } else {
+            value.to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::codegen::source_map::location::Location;
+    use crate::codegen::source_map::SourceMap;
+
+    const ASSEMBLY: &str = r#"
+	.text
+__entry:
+	.loc	1 2 0
+	PUSH1 0x80
+	.loc	1 1 0
+	PUSH4 @.FUNC_RET0
+	PUSH4 @callee
+	JUMP
+.FUNC_RET0:
+	JUMPDEST
+	STOP
+callee:
+	.loc	1 3 0
+	JUMPDEST
+	JUMP
+"#;
+
+    const BYTECODE: &[u8] = &[
+        0x60, 0x80, 0x63, 0x00, 0x00, 0x00, 0x0e, 0x63, 0x00, 0x00, 0x00, 0x10, 0x56, 0x5b, 0x00,
+        0x5b, 0x56,
+    ];
+
+    #[test]
+    fn compress() {
+        let locations = [
+            Location::new(0, 10, 0),
+            Location::new(2, 3, 0),
+            Location::new(5, 4, 0),
+        ];
+        let source_map = SourceMap::try_from_assembly(ASSEMBLY, &locations).expect("Always valid");
+        assert_eq!(
+            source_map.compress(BYTECODE).expect("Always valid"),
+            "2:3:0:-;0:10;;:::i;:::-;;5:4;:::o",
+        );
+    }
+
+    #[test]
+    fn entry_points() {
+        let source_map = SourceMap::try_from_assembly(ASSEMBLY, &[]).expect("Always valid");
+        let entry_points = source_map.entry_points(BYTECODE).expect("Always valid");
+        assert_eq!(entry_points.get("__entry"), Some(&0));
+        assert_eq!(entry_points.get("callee"), Some(&15));
+    }
+
+    #[test]
+    fn entry_points_resync() {
+        let assembly = r#"
+	.text
+__entry:
+	PUSH1 0x80
+	POP
+	POP
+	STOP
+callee:
+	JUMPDEST
+	JUMP
+"#;
+        let bytecode = [0x60, 0x80, 0x5b, 0x56];
+        let source_map = SourceMap::try_from_assembly(assembly, &[]).expect("Always valid");
+        let entry_points = source_map.entry_points(&bytecode).expect("Always valid");
+        assert_eq!(entry_points.get("__entry"), Some(&0));
+        assert_eq!(entry_points.get("callee"), Some(&2));
+    }
+
+    #[test]
+    fn compress_mismatch() {
+        let source_map = SourceMap::try_from_assembly(ASSEMBLY, &[]).expect("Always valid");
+
+        let mut inserted = BYTECODE.to_vec();
+        inserted.insert(2, 0x01);
+        assert!(source_map.compress(inserted.as_slice()).is_err());
+
+        let mut appended = BYTECODE.to_vec();
+        appended.push(0x01);
+        assert!(source_map.compress(appended.as_slice()).is_err());
+    }
+}
@@ -9,24 +9,26 @@
 pub enum Warning {
     /// Deploy code size warning.
     #[error(
-        "{0} bytecode size is {found}B that exceeds the EVM limit of {1}B",
-        solx_utils::CodeSegment::Deploy,
-        crate::r#const::DEPLOY_CODE_SIZE_LIMIT
+        "{0} bytecode size is {found}B that exceeds the EVM limit of {limit}B",
+        solx_utils::CodeSegment::Deploy
     )]
     DeployCodeSize {
         /// Bytecode size.
         found: usize,
+        /// Bytecode size limit.
+        limit: usize,
     },
 
     /// Runtime code size warning.
     #[error(
-        "{0} bytecode size is {found}B that exceeds the EVM limit of {1}B",
-        solx_utils::CodeSegment::Runtime,
-        crate::r#const::RUNTIME_CODE_SIZE_LIMIT
+        "{0} bytecode size is {found}B that exceeds the EVM limit of {limit}B",
+        solx_utils::CodeSegment::Runtime
     )]
     RuntimeCodeSize {
         /// Bytecode size.
         found: usize,
+        /// Bytecode size limit.
+        limit: usize,
     },
 }
 
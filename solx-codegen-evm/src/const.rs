@@ -14,11 +14,8 @@ pub static YUL_OBJECT_DEPLOYED_SUFFIX: &str = "_deployed";
 /// Library deploy address Yul identifier.
 pub static LIBRARY_DEPLOY_ADDRESS_TAG: &str = "library_deploy_address";
 
-/// The deploy bytecode size limit.
-pub const DEPLOY_CODE_SIZE_LIMIT: usize = 49152;
-
-/// The runtime bytecode size limit.
-pub const RUNTIME_CODE_SIZE_LIMIT: usize = 24576;
-
 /// The `solc` user memory offset.
 pub const SOLC_USER_MEMORY_OFFSET: u64 = 128;
+
+/// The debug information producer.
+pub const DEBUG_INFO_PRODUCER: &str = "solx";
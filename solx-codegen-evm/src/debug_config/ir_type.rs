@@ -15,6 +15,8 @@ pub enum IRType {
     EVMLA,
     /// Whether to dump the Ethereal IR code.
     EthIR,
+    /// Whether to dump the Ethereal IR control-flow graphs.
+    EthIRGraph,
     /// Whether to dump the LLVM IR code.
     LLVM,
     /// Whether to dump the EVM assembly code.
@@ -29,6 +31,7 @@ impl IRType {
         match self {
             Self::Yul => solx_utils::EXTENSION_YUL,
             Self::EthIR => solx_utils::EXTENSION_ETHIR,
+            Self::EthIRGraph => solx_utils::EXTENSION_DOT,
             Self::EVMLA => solx_utils::EXTENSION_EVMLA,
             Self::LLVM => solx_utils::EXTENSION_LLVM_SOURCE,
             Self::EVMAssembly => solx_utils::EXTENSION_EVM_ASSEMBLY,
@@ -73,6 +73,26 @@ impl DebugConfig {
         Ok(())
     }
 
+    ///
+    /// Dumps the Ethereal IR control-flow graph of a function in the Graphviz DOT format.
+    ///
+    pub fn dump_ethir_graph(
+        &self,
+        contract_path: &str,
+        function_name: &str,
+        graph: &str,
+    ) -> anyhow::Result<()> {
+        let suffix = Self::sanitize_filename_fragment(function_name);
+
+        let mut file_path = self.output_directory.to_owned();
+        let full_file_name =
+            Self::full_file_name(contract_path, Some(suffix.as_str()), IRType::EthIRGraph);
+        file_path.push(full_file_name);
+        std::fs::write(file_path, graph)?;
+
+        Ok(())
+    }
+
     ///
     /// Dumps the unoptimized LLVM IR.
     ///
@@ -44,7 +44,13 @@ pub use self::codegen::instructions::storage;
 pub use self::codegen::link;
 pub use self::codegen::minimal_deploy_code;
 pub use self::codegen::profiler::run::Run;
+pub use self::codegen::profiler::trace_event::TraceEvent;
 pub use self::codegen::profiler::Profiler;
+pub use self::codegen::remark::format::Format as RemarkFormat;
+pub use self::codegen::remark::Remark;
+pub use self::codegen::source_map::instruction::Instruction as SourceMapInstruction;
+pub use self::codegen::source_map::location::Location as SourceLocation;
+pub use self::codegen::source_map::SourceMap;
 pub use self::codegen::warning::Warning;
 pub use self::codegen::DummyLLVMWritable;
 pub use self::codegen::WriteLLVM;
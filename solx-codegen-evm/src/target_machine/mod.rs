@@ -2,8 +2,12 @@
 //! EVM target machine.
 //!
 
+pub mod options_guard;
+
 use crate::optimizer::settings::Settings as OptimizerSettings;
 
+use self::options_guard::OptionsGuard;
+
 ///
 /// EVM target machine.
 ///
@@ -13,6 +17,8 @@ pub struct TargetMachine {
     target_machine: inkwell::targets::TargetMachine,
     /// The optimizer settings.
     optimizer_settings: OptimizerSettings,
+    /// The LLVM options guard, held as long as the target machine is used.
+    _options_guard: OptionsGuard,
 }
 
 impl TargetMachine {
@@ -22,6 +28,14 @@ impl TargetMachine {
     ///
     /// A shortcut constructor.
     ///
+    /// The EVM version is passed as the target CPU, so the instruction selection only uses
+    /// the opcodes available on it.
+    ///
+    /// The spill area and metadata sizes are always passed, as LLVM options are process-wide
+    /// and must not leak from a translation unit previously compiled in the same process.
+    /// The options are guarded until the target machine is dropped, so it must be dropped
+    /// before another one is created on the same thread.
+    ///
     /// Supported LLVM options:
     /// `-evm-stack-region-size <value>`
     /// `-evm-stack-region-offset <value>`
@@ -29,31 +43,33 @@ impl TargetMachine {
     ///
     pub fn new(
         optimizer_settings: &OptimizerSettings,
+        evm_version: solx_utils::EVMVersion,
         llvm_options: &[String],
     ) -> anyhow::Result<Self> {
         let mut arguments = Vec::with_capacity(1 + llvm_options.len());
         arguments.push(Self::TARGET.to_string());
         arguments.extend_from_slice(llvm_options);
-        if let Some(size) = optimizer_settings.spill_area_size {
+        if optimizer_settings.spill_area_size.is_some() {
             arguments.push(format!(
                 "-evm-stack-region-offset={}",
                 crate::r#const::SOLC_USER_MEMORY_OFFSET
             ));
-            arguments.push(format!("-evm-stack-region-size={size}"));
-        }
-        if let Some(size) = optimizer_settings.metadata_size {
-            arguments.push(format!("-evm-metadata-size={size}"));
-        }
-        if arguments.len() > 1 {
-            let arguments: Vec<&str> = arguments.iter().map(|argument| argument.as_str()).collect();
-            inkwell::support::parse_command_line_options(arguments.as_slice(), "LLVM options");
         }
+        arguments.push(format!(
+            "-evm-stack-region-size={}",
+            optimizer_settings.spill_area_size.unwrap_or_default()
+        ));
+        arguments.push(format!(
+            "-evm-metadata-size={}",
+            optimizer_settings.metadata_size.unwrap_or_default()
+        ));
+        let options_guard = OptionsGuard::acquire(arguments);
 
         let target_machine = inkwell::targets::Target::from_name(Self::TARGET.to_string().as_str())
             .ok_or_else(|| anyhow::anyhow!("LLVM target machine `{}` not found", Self::TARGET))?
             .create_target_machine(
                 &inkwell::targets::TargetTriple::create(Self::TARGET.triple()),
-                "",
+                evm_version.to_string().as_str(),
                 "",
                 optimizer_settings.level_back_end,
                 inkwell::targets::RelocMode::Default,
@@ -69,6 +85,7 @@ impl TargetMachine {
         Ok(Self {
             target_machine,
             optimizer_settings: optimizer_settings.to_owned(),
+            _options_guard: options_guard,
         })
     }
 
@@ -0,0 +1,62 @@
+//!
+//! The process-wide LLVM options guard.
+//!
+
+use std::sync::Condvar;
+use std::sync::Mutex;
+
+/// The LLVM options currently set in the process, and the number of translation units using them.
+static OPTIONS: Mutex<(Vec<String>, usize)> = Mutex::new((Vec::new(), 0));
+
+/// Wakes up the translation units waiting for the current LLVM options to be released.
+static OPTIONS_RELEASED: Condvar = Condvar::new();
+
+///
+/// The process-wide LLVM options guard.
+///
+/// LLVM command-line options are process-wide and read throughout the code generation, so
+/// the translation units compiled in the same process at the same time must agree on them.
+/// The units with the same options share them and are compiled in parallel, whereas the units
+/// with different ones wait until the current options are released by all their users.
+///
+#[derive(Debug)]
+pub struct OptionsGuard;
+
+impl OptionsGuard {
+    ///
+    /// Sets `arguments` as the LLVM options, blocking until no other translation unit
+    /// uses different ones.
+    ///
+    pub fn acquire(arguments: Vec<String>) -> Self {
+        let mut options = OPTIONS.lock().expect("Sync");
+        loop {
+            let (current, users) = &mut *options;
+            if *current == arguments {
+                *users += 1;
+                return Self;
+            }
+            if *users == 0 {
+                let argument_references: Vec<&str> =
+                    arguments.iter().map(|argument| argument.as_str()).collect();
+                inkwell::support::parse_command_line_options(
+                    argument_references.as_slice(),
+                    "LLVM options",
+                );
+                *current = arguments;
+                *users = 1;
+                return Self;
+            }
+            options = OPTIONS_RELEASED.wait(options).expect("Sync");
+        }
+    }
+}
+
+impl Drop for OptionsGuard {
+    fn drop(&mut self) {
+        let mut options = OPTIONS.lock().expect("Sync");
+        options.1 -= 1;
+        if options.1 == 0 {
+            OPTIONS_RELEASED.notify_all();
+        }
+    }
+}
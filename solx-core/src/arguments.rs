@@ -58,13 +58,27 @@ pub struct Arguments {
     #[arg(long = "optimization-size-fallback")]
     pub size_fallback: bool,
 
+    /// Set the deploy bytecode size limit in bytes.
+    /// The default is the EIP-3860 limit of 49152 bytes.
+    #[arg(long)]
+    pub deploy_code_size_limit: Option<usize>,
+
+    /// Set the runtime bytecode size limit in bytes.
+    /// The default is the EIP-170 limit of 24576 bytes.
+    #[arg(long)]
+    pub runtime_code_size_limit: Option<usize>,
+
+    /// Treat bytecode size limit overruns as errors rather than warnings.
+    #[arg(long)]
+    pub code_size_overrun_error: bool,
+
     /// Pass arbitrary space-separated options to LLVM.
     /// The argument must be a single-quoted string following a `=` separator.
     /// Example: `--llvm-options='arg1 arg2 arg3 ... argN'`.
     #[arg(long)]
     pub llvm_options: Option<String>,
 
-    /// EVM version `solc` will produce Yul or EVM assembly for.
+    /// EVM version to produce Yul, EVM assembly, and the bytecode for.
     /// The default is chosen by `solc`.
     #[arg(long)]
     pub evm_version: Option<solx_utils::EVMVersion>,
@@ -79,6 +93,17 @@ pub struct Arguments {
     #[arg(long)]
     pub standard_json: Option<Option<String>>,
 
+    /// Cache the compiled translation units in the specified directory, and reuse them on subsequent runs.
+    /// The cache is invalidated automatically when the IR, optimizer settings, or compiler version change.
+    #[arg(long)]
+    pub cache_dir: Option<PathBuf>,
+
+    /// Keep running, and rebuild the project every time the input files or their imports are changed.
+    /// Only the contracts whose IR has changed are recompiled, and the output directory is updated atomically.
+    /// Requires `--output-dir`.
+    #[arg(long)]
+    pub watch: bool,
+
     /// Sets the number of threads, where each thread compiles its own translation unit in a child process.
     #[arg(short, long)]
     pub threads: Option<usize>,
@@ -89,6 +114,30 @@ pub struct Arguments {
     #[arg(long, alias = "strict-assembly")]
     pub yul: bool,
 
+    /// Switch to linker mode, linking the input bytecode files in place.
+    /// Files with the `json` extension are treated as standard JSON output, others as hexadecimal bytecode.
+    /// Only `--libraries` can be used in this mode.
+    #[arg(long)]
+    pub link: bool,
+
+    /// Switch to metadata inspection mode, decoding the CBOR metadata at the end of the input bytecode files.
+    /// The files are expected to contain hexadecimal bytecode, optionally prefixed with `0x`.
+    /// The compiler versions and the metadata hash of each file are printed as JSON.
+    #[arg(long)]
+    pub inspect_metadata: bool,
+
+    /// Switch to verification mode, recompiling a contract from its metadata and comparing the result with its bytecode.
+    /// Expects two input files: the metadata JSON and the hexadecimal deploy or runtime bytecode.
+    /// The sources that are not embedded into the metadata are read relative to `--base-path` and `--include-path`.
+    #[arg(long)]
+    pub verify: bool,
+
+    /// Switch to Language Server Protocol mode, communicating with the client via stdin and stdout.
+    /// The open Solidity documents are compiled on opening and saving, and the messages are published as diagnostics.
+    /// The bytecode sizes and multi-pass pipeline decisions of each contract are reported as code lenses.
+    #[arg(long)]
+    pub lsp: bool,
+
     /// Switch to LLVM IR mode.
     /// Only one input LLVM IR file is allowed.
     /// Cannot be used with standard JSON mode.
@@ -96,12 +145,19 @@ pub struct Arguments {
     #[arg(long)]
     pub llvm_ir: bool,
 
+    /// Switch to EVM legacy assembly mode.
+    /// The input files are deploy code assemblies emitted by `solc --asm-json`, optionally with the `extraMetadata` object.
+    /// The contracts created by the input ones are compiled from their `.data` sections, unless passed separately.
+    /// Cannot be used with standard JSON mode.
+    #[arg(long)]
+    pub evmla: bool,
+
     /// Enable the `solc` IR codegen.
     #[arg(long)]
     pub via_ir: bool,
 
     /// Set the metadata hash type.
-    /// Available types: `none`, `ipfs`.
+    /// Available types: `none`, `ipfs`, `bzzr1`.
     /// The default is `ipfs`.
     #[arg(long)]
     pub metadata_hash: Option<solx_utils::MetadataHashType>,
@@ -175,6 +231,20 @@ pub struct Arguments {
     #[arg(long = "benchmarks")]
     pub output_benchmarks: bool,
 
+    /// Emit the bytecode size breakdown of the compiled contracts.
+    #[arg(long = "size")]
+    pub output_size: bool,
+
+    /// Emit the optimization remarks explaining the inlining, spill area, and size fallback decisions.
+    /// The format is either `yaml` or `json`, and defaults to `yaml`.
+    #[arg(long = "remarks", num_args = 0..=1, default_missing_value = "yaml")]
+    pub output_remarks: Option<solx_codegen_evm::RemarkFormat>,
+
+    /// Write the compilation pipeline trace in the Chrome trace format to the specified file.
+    /// The trace can be opened with `chrome://tracing` or Perfetto.
+    #[arg(long = "trace")]
+    pub output_trace: Option<PathBuf>,
+
     /// Dump all IRs to files in the specified directory.
     /// Only for testing and debugging.
     #[arg(long)]
@@ -221,23 +291,144 @@ impl Arguments {
             return Arc::new(Mutex::new(messages));
         }
 
-        if self.standard_json.is_none() && self.inputs.is_empty() {
+        if self.standard_json.is_none() && !self.lsp && self.inputs.is_empty() {
             messages.push(solx_standard_json::OutputError::new_error(
                 format!("No input files given. For standard input, specify `{}` explicitly, or visit `--help` to see all options.", solx_standard_json::InputSource::STDIN_INPUT_IDENTIFIER).as_str(),
             ));
         }
 
-        let modes_count = [self.yul, self.llvm_ir, self.standard_json.is_some()]
-            .iter()
-            .filter(|&&x| x)
-            .count();
+        let modes_count = [
+            self.yul,
+            self.llvm_ir,
+            self.evmla,
+            self.link,
+            self.inspect_metadata,
+            self.verify,
+            self.standard_json.is_some(),
+            self.lsp,
+        ]
+        .iter()
+        .filter(|&&x| x)
+        .count();
         if modes_count > 1 {
             messages.push(solx_standard_json::OutputError::new_error(
-                "Only one mode is allowed at the same time: Yul, LLVM IR, standard JSON.",
+                "Only one mode is allowed at the same time: Yul, LLVM IR, EVM assembly, linker, metadata inspection, verification, standard JSON, LSP.",
             ));
         }
 
-        if self.yul || self.llvm_ir {
+        if self.lsp {
+            if self.inputs.iter().any(|input| !input.contains('=')) {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    "Input files cannot be passed in LSP mode, as the open documents are sent by the client.",
+                ));
+            }
+            if !self.libraries.is_empty() {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    "Libraries cannot be passed in LSP mode, as the open documents are compiled without linking.",
+                ));
+            }
+            if self.output_bytecode
+                || self.output_bytecode_runtime
+                || self.output_assembly
+                || self.output_metadata
+                || self.output_abi
+                || self.output_hashes
+                || self.output_userdoc
+                || self.output_devdoc
+                || self.output_storage_layout
+                || self.output_transient_storage_layout
+                || self.output_ast_json
+                || self.output_asm_solc_json
+                || self.output_ir
+                || self.output_benchmarks
+                || self.output_size
+                || self.output_remarks.is_some()
+                || self.output_trace.is_some()
+                || self.output_dir.is_some()
+            {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    "No outputs can be emitted in LSP mode, as the results are sent to the client.",
+                ));
+            }
+        }
+
+        if self.watch {
+            if self.link
+                || self.inspect_metadata
+                || self.verify
+                || self.standard_json.is_some()
+                || self.lsp
+            {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    "Watch mode can only be used with Solidity, Yul, LLVM IR, and EVM assembly input files.",
+                ));
+            }
+            if self.output_dir.is_none() {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    "Watch mode requires an output directory.",
+                ));
+            }
+            if self
+                .inputs
+                .iter()
+                .any(|input| input == solx_standard_json::InputSource::STDIN_INPUT_IDENTIFIER)
+            {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    "Standard input cannot be watched.",
+                ));
+            }
+        }
+
+        if (self.link || self.inspect_metadata || self.verify)
+            && (self.output_bytecode
+                || self.output_bytecode_runtime
+                || self.output_assembly
+                || self.output_metadata
+                || self.output_abi
+                || self.output_hashes
+                || self.output_userdoc
+                || self.output_devdoc
+                || self.output_storage_layout
+                || self.output_transient_storage_layout
+                || self.output_ast_json
+                || self.output_asm_solc_json
+                || self.output_ir
+                || self.output_benchmarks
+                || self.output_size
+                || self.output_remarks.is_some()
+                || self.output_trace.is_some()
+                || self.output_dir.is_some())
+        {
+            messages.push(solx_standard_json::OutputError::new_error(if self.link {
+                "No outputs can be emitted in linker mode, as the input files are linked in place."
+            } else if self.inspect_metadata {
+                "No outputs can be emitted in metadata inspection mode, as only the metadata is printed."
+            } else {
+                "No outputs can be emitted in verification mode, as only the comparison is printed."
+            }));
+        }
+
+        if self.verify {
+            if self.inputs.len() != 2 {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    "Verification mode requires exactly two input files: the metadata JSON and the bytecode.",
+                ));
+            }
+            if !self.libraries.is_empty()
+                || self.evm_version.is_some()
+                || self.via_ir
+                || self.optimization.is_some()
+                || self.size_fallback
+                || self.llvm_options.is_some()
+                || self.metadata_literal
+            {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    "Compilation settings are taken from the metadata in verification mode.",
+                ));
+            }
+        }
+
+        if self.yul || self.llvm_ir || self.evmla {
             if self.base_path.is_some() {
                 messages.push(solx_standard_json::OutputError::new_error(
                     "`base-path` is only allowed in Solidity mode.",
@@ -270,12 +461,6 @@ impl Arguments {
                 ));
             }
 
-            if self.evm_version.is_some() {
-                messages.push(solx_standard_json::OutputError::new_error(
-                    "EVM version is only allowed in Solidity mode.",
-                ));
-            }
-
             if self.via_ir {
                 messages.push(solx_standard_json::OutputError::new_error(
                     "IR codegen settings are only available in Solidity mode.",
@@ -298,6 +483,9 @@ impl Arguments {
                 || self.output_asm_solc_json
                 || self.output_ir
                 || self.output_benchmarks
+                || self.output_size
+                || self.output_remarks.is_some()
+                || self.output_trace.is_some()
             {
                 messages.push(solx_standard_json::OutputError::new_error(
                     "Cannot output data outside of JSON in standard JSON mode.",
@@ -351,6 +539,19 @@ impl Arguments {
                     "LLVM options must be specified in standard JSON input settings.",
                 ));
             }
+            if self.cache_dir.is_some() {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    "Cache directory must be specified in standard JSON input settings.",
+                ));
+            }
+            if self.deploy_code_size_limit.is_some()
+                || self.runtime_code_size_limit.is_some()
+                || self.code_size_overrun_error
+            {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    "Bytecode size limits must be specified in standard JSON input settings.",
+                ));
+            }
             if self.metadata_hash.is_some() {
                 messages.push(solx_standard_json::OutputError::new_error(
                     "Metadata hash mode must be specified in standard JSON input settings.",
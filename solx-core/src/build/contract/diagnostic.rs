@@ -0,0 +1,70 @@
+//!
+//! Multi-pass compilation pipeline diagnostic.
+//!
+
+///
+/// Multi-pass compilation pipeline diagnostic.
+///
+/// Reports the decisions made by the pipeline to overcome the EVM limits, so users can
+/// see which contracts are close to them.
+///
+#[derive(Debug, thiserror::Error, Clone, serde::Serialize, serde::Deserialize)]
+pub enum Diagnostic {
+    /// The stack-too-deep errors were resolved by allocating a spill area.
+    #[error(
+        "{code_segment} code stack-too-deep errors have been resolved by allocating a spill area of {spill_area_size}B after {pass_count} compilation passes"
+    )]
+    SpillArea {
+        /// Code segment that triggered the stack-too-deep errors.
+        code_segment: solx_utils::CodeSegment,
+        /// Spill area size in bytes.
+        spill_area_size: u64,
+        /// Number of compilation passes.
+        pass_count: usize,
+    },
+
+    /// The size fallback was activated to fit the EVM bytecode size limit.
+    #[error(
+        "{code_segment} code exceeds the EVM bytecode size limit, so it has been recompiled with size optimizations after {pass_count} compilation passes"
+    )]
+    SizeFallback {
+        /// Code segment that exceeded the bytecode size limit.
+        code_segment: solx_utils::CodeSegment,
+        /// Number of compilation passes.
+        pass_count: usize,
+    },
+
+    /// The compiled code could not be stored in the cache.
+    #[error("{code_segment} code has not been cached: {message}")]
+    CacheWrite {
+        /// Code segment that has not been cached.
+        code_segment: solx_utils::CodeSegment,
+        /// The cache writing error message.
+        message: String,
+    },
+}
+
+impl Diagnostic {
+    ///
+    /// Converts the diagnostic into a standard JSON message.
+    ///
+    /// Spill area allocations are reported as information, whereas size fallbacks are warnings,
+    /// as they may compromise the performance of the contract. Cache writing failures are warnings
+    /// as well, since they do not affect the compiled code.
+    ///
+    pub fn to_standard_json(&self, path: &str) -> solx_standard_json::OutputError {
+        let r#type = match self {
+            Self::SpillArea { .. } => "Info",
+            Self::SizeFallback { .. } | Self::CacheWrite { .. } => "Warning",
+        };
+        solx_standard_json::OutputError::new(
+            r#type,
+            None,
+            self.to_string(),
+            Some(solx_standard_json::OutputErrorSourceLocation::new(
+                path.to_owned(),
+            )),
+            None,
+        )
+    }
+}
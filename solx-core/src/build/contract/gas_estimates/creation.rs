@@ -0,0 +1,34 @@
+//!
+//! The contract creation gas estimates.
+//!
+
+use crate::build::contract::gas_estimates::gas::Gas;
+
+///
+/// The contract creation gas estimates.
+///
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Creation {
+    /// The cost of storing the runtime code.
+    pub code_deposit_cost: Gas,
+    /// The cost of executing the deploy code, omitted if the estimation budget is exhausted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execution_cost: Option<Gas>,
+    /// The sum of the above, omitted if the execution cost is.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_cost: Option<Gas>,
+}
+
+impl Creation {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(code_deposit_cost: Gas, execution_cost: Option<Gas>) -> Self {
+        Self {
+            code_deposit_cost,
+            execution_cost,
+            total_cost: execution_cost.map(|execution_cost| code_deposit_cost + execution_cost),
+        }
+    }
+}
@@ -0,0 +1,622 @@
+//!
+//! The static gas estimator.
+//!
+
+pub mod state;
+
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use num::One;
+use num::ToPrimitive;
+use num::Zero;
+
+use crate::build::contract::gas_estimates::gas::Gas;
+
+use self::state::State;
+use self::state::Value;
+
+///
+/// The static gas estimator.
+///
+/// Explores all execution paths of the final bytecode, propagating the values known at compile
+/// time, and returns the most expensive one. The cost is reported as infinite if a path contains
+/// a loop, an external call or contract creation, or a memory access of an unknown size.
+///
+/// The exploration is a depth-first search over the states seen at jump destinations. A state
+/// reached again while it is still being explored is a loop, whereas a state already explored
+/// with at least as much gas spent is not explored again, as its continuations are the same.
+///
+/// The calldata is modeled as the function selector followed by unknown bytes, while all other
+/// environment values are unknown.
+///
+/// The trailing CBOR metadata is not treated as code, so neither jump destinations nor
+/// instructions are taken from it.
+///
+pub struct Estimator<'a> {
+    /// The bytecode.
+    bytecode: &'a [u8],
+    /// The length of the bytecode without the CBOR metadata.
+    code_length: usize,
+    /// The valid jump destinations.
+    jump_destinations: BTreeSet<usize>,
+    /// The function selector, if a runtime function is estimated.
+    selector: Option<Vec<u8>>,
+    /// The target EVM version, which the state access costs depend on.
+    evm_version: solx_utils::EVMVersion,
+    /// The maximum gas spent on reaching each state fingerprint seen at jump destinations.
+    visited: HashMap<u64, u64>,
+    /// The fingerprints of the states whose continuations are being explored.
+    active: HashSet<u64>,
+    /// The number of instructions executed so far.
+    steps: usize,
+    /// Whether the exploration budget has been exhausted.
+    is_exhausted: bool,
+}
+
+///
+/// The exploration task.
+///
+enum Task {
+    /// Runs a path from the state.
+    Run(State),
+    /// Finishes the exploration of the continuations of the state fingerprint.
+    Leave(u64),
+}
+
+impl<'a> Estimator<'a> {
+    /// The maximum number of instructions executed across all paths.
+    const STEPS_LIMIT: usize = 1 << 20;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        bytecode: &'a [u8],
+        selector: Option<Vec<u8>>,
+        evm_version: solx_utils::EVMVersion,
+    ) -> Self {
+        let code_length =
+            bytecode.len() - solx_utils::metadata_length(bytecode).unwrap_or_default();
+
+        let mut jump_destinations = BTreeSet::new();
+        let mut offset = 0;
+        while offset < code_length {
+            let opcode = solx_utils::Opcode(bytecode[offset]);
+            if opcode.name() == Some("JUMPDEST") {
+                jump_destinations.insert(offset);
+            }
+            offset += 1 + opcode.push_size();
+        }
+
+        Self {
+            bytecode,
+            code_length,
+            jump_destinations,
+            selector,
+            evm_version,
+            visited: HashMap::new(),
+            active: HashSet::new(),
+            steps: 0,
+            is_exhausted: false,
+        }
+    }
+
+    ///
+    /// Returns the cost of the most expensive execution path.
+    ///
+    /// Returns `None` if the exploration budget is exhausted before all paths are explored,
+    /// as the cost is neither known to be bounded nor unbounded then.
+    ///
+    pub fn estimate(mut self) -> Option<Gas> {
+        let mut maximum = 0;
+        let mut tasks = vec![Task::Run(State::default())];
+        while let Some(task) = tasks.pop() {
+            let state = match task {
+                Task::Run(state) => state,
+                Task::Leave(fingerprint) => {
+                    self.active.remove(&fingerprint);
+                    continue;
+                }
+            };
+            match self.run(state, &mut tasks) {
+                Some(gas) => maximum = maximum.max(gas),
+                None if self.is_exhausted => return None,
+                None => return Some(Gas::Infinite),
+            }
+        }
+        Some(Gas::Finite(maximum))
+    }
+
+    ///
+    /// Runs a path until it terminates or reaches a jump destination, scheduling the paths
+    /// forked at conditional jumps and the continuation at the jump destination.
+    ///
+    /// Returns the gas spent on the path so far, or `None` if the cost of the path cannot be
+    /// bounded or the budget is exhausted.
+    ///
+    fn run(&mut self, mut state: State, tasks: &mut Vec<Task>) -> Option<u64> {
+        loop {
+            self.steps += 1;
+            if self.steps > Self::STEPS_LIMIT {
+                self.is_exhausted = true;
+                return None;
+            }
+
+            if state.pc >= self.code_length {
+                return Some(state.gas);
+            }
+            let opcode = solx_utils::Opcode(self.bytecode[state.pc]);
+            let name = match opcode.name() {
+                Some(name) => name,
+                None => return Some(state.gas),
+            };
+            let mut next_pc = state.pc + 1 + opcode.push_size();
+
+            match name {
+                "STOP" | "INVALID" => return Some(state.gas),
+                "RETURN" | "REVERT" => {
+                    let offset = state.pop()?;
+                    let size = state.pop()?;
+                    state.expand_memory(&offset, &size)?;
+                    return Some(state.gas);
+                }
+                "CALL" | "CALLCODE" | "DELEGATECALL" | "STATICCALL" | "CREATE" | "CREATE2"
+                | "SELFDESTRUCT" => return None,
+
+                "JUMPDEST" => {
+                    let fingerprint = state.fingerprint();
+                    if self.active.contains(&fingerprint) {
+                        return None;
+                    }
+                    if self
+                        .visited
+                        .get(&fingerprint)
+                        .is_some_and(|gas| *gas >= state.gas)
+                    {
+                        return Some(state.gas);
+                    }
+                    self.visited.insert(fingerprint, state.gas);
+                    self.active.insert(fingerprint);
+
+                    let gas = state.gas;
+                    state.charge(1)?;
+                    state.pc = next_pc;
+                    tasks.push(Task::Leave(fingerprint));
+                    tasks.push(Task::Run(state));
+                    return Some(gas);
+                }
+                "JUMP" => {
+                    state.charge(8)?;
+                    let destination = state.pop()?;
+                    next_pc = self.jump_destination(&destination)?;
+                }
+                "JUMPI" => {
+                    state.charge(10)?;
+                    let destination = state.pop()?;
+                    let condition = state.pop()?;
+                    match condition {
+                        Some(condition) if condition.is_zero() => {}
+                        Some(_) => next_pc = self.jump_destination(&destination)?,
+                        None => {
+                            let mut fallthrough = state.clone();
+                            fallthrough.pc = next_pc;
+                            tasks.push(Task::Run(fallthrough));
+                            next_pc = self.jump_destination(&destination)?;
+                        }
+                    }
+                }
+
+                "PUSH0" => {
+                    state.charge(2)?;
+                    state.push(Some(num::BigUint::zero()))?;
+                }
+                name if name.starts_with("PUSH") => {
+                    state.charge(3)?;
+                    let start = (state.pc + 1).min(self.bytecode.len());
+                    let end = next_pc.min(self.bytecode.len());
+                    let mut bytes = self.bytecode[start..end].to_vec();
+                    bytes.resize(opcode.push_size(), 0);
+                    state.push(Some(num::BigUint::from_bytes_be(bytes.as_slice())))?;
+                }
+                name if name.starts_with("DUP") => {
+                    state.charge(3)?;
+                    let depth = opcode.0 as usize - 0x80 + 1;
+                    let index = state.stack.len().checked_sub(depth)?;
+                    let value = state.stack[index].clone();
+                    state.push(value)?;
+                }
+                name if name.starts_with("SWAP") => {
+                    state.charge(3)?;
+                    let depth = opcode.0 as usize - 0x90 + 1;
+                    let top = state.stack.len().checked_sub(1)?;
+                    let index = top.checked_sub(depth)?;
+                    state.stack.swap(top, index);
+                }
+                "POP" => {
+                    state.charge(2)?;
+                    state.pop()?;
+                }
+
+                "ADD" | "SUB" | "MUL" | "DIV" | "MOD" | "AND" | "OR" | "XOR" | "EQ" | "LT"
+                | "GT" | "SHL" | "SHR" | "BYTE" => {
+                    let cost = match name {
+                        "MUL" | "DIV" | "MOD" => 5,
+                        _ => 3,
+                    };
+                    state.charge(cost)?;
+                    let left = state.pop()?;
+                    let right = state.pop()?;
+                    let result = left
+                        .zip(right)
+                        .and_then(|(left, right)| Self::binary(name, left, right));
+                    state.push(result)?;
+                }
+                "ISZERO" | "NOT" | "CLZ" => {
+                    let cost = match name {
+                        "CLZ" => 5,
+                        _ => 3,
+                    };
+                    state.charge(cost)?;
+                    let operand = state.pop()?;
+                    let result = operand.map(|operand| match name {
+                        "ISZERO" => Self::boolean(operand.is_zero()),
+                        "CLZ" => {
+                            num::BigUint::from(solx_utils::BIT_LENGTH_FIELD as u64 - operand.bits())
+                        }
+                        _ => Self::mask() ^ operand,
+                    });
+                    state.push(result)?;
+                }
+                "SDIV" | "SMOD" | "SIGNEXTEND" => Self::unknown(&mut state, 5, 2, 1)?,
+                "SLT" | "SGT" | "SAR" => Self::unknown(&mut state, 3, 2, 1)?,
+                "ADDMOD" | "MULMOD" => Self::unknown(&mut state, 8, 3, 1)?,
+                "EXP" => {
+                    let _base = state.pop()?;
+                    let exponent = state.pop()?;
+                    let exponent_bytes = exponent
+                        .map(|exponent| exponent.bits().div_ceil(8))
+                        .unwrap_or(solx_utils::BYTE_LENGTH_FIELD as u64);
+                    state.charge(10 + 50 * exponent_bytes)?;
+                    state.push(None)?;
+                }
+                "KECCAK256" => {
+                    let offset = state.pop()?;
+                    let size = state.pop()?;
+                    state.charge(30 + 6 * Self::words(&size)?)?;
+                    state.expand_memory(&offset, &size)?;
+                    state.push(None)?;
+                }
+
+                "ADDRESS" | "ORIGIN" | "CALLER" | "CALLVALUE" | "CALLDATASIZE" | "GASPRICE"
+                | "COINBASE" | "TIMESTAMP" | "NUMBER" | "PREVRANDAO" | "GASLIMIT" | "CHAINID"
+                | "RETURNDATASIZE" | "PC" | "MSIZE" | "GAS" | "BASEFEE" | "BLOBBASEFEE" => {
+                    Self::unknown(&mut state, 2, 0, 1)?
+                }
+                "CODESIZE" => {
+                    state.charge(2)?;
+                    state.push(Some(num::BigUint::from(self.bytecode.len())))?;
+                }
+                "SELFBALANCE" => Self::unknown(&mut state, 5, 0, 1)?,
+                "BLOCKHASH" => Self::unknown(&mut state, 20, 1, 1)?,
+                "BLOBHASH" => Self::unknown(&mut state, 3, 1, 1)?,
+                "BALANCE" | "EXTCODESIZE" | "EXTCODEHASH" => {
+                    Self::unknown(&mut state, self.state_access_cost(name), 1, 1)?
+                }
+                "SLOAD" => Self::unknown(&mut state, self.state_access_cost(name), 1, 1)?,
+                "TLOAD" => Self::unknown(&mut state, 100, 1, 1)?,
+                "SSTORE" => Self::unknown(&mut state, self.state_access_cost(name), 2, 0)?,
+                "TSTORE" => Self::unknown(&mut state, 100, 2, 0)?,
+                "CALLDATALOAD" => {
+                    state.charge(3)?;
+                    let offset = state.pop()?;
+                    let value = match (offset, self.selector.as_ref()) {
+                        (Some(offset), Some(selector)) if offset.is_zero() => {
+                            let mut word = selector.to_owned();
+                            word.resize(solx_utils::BYTE_LENGTH_FIELD, 0);
+                            Some(num::BigUint::from_bytes_be(word.as_slice()))
+                        }
+                        _ => None,
+                    };
+                    state.push(value)?;
+                }
+
+                "MLOAD" => {
+                    state.charge(3)?;
+                    let offset = state.pop()?;
+                    state.expand_memory(
+                        &offset,
+                        &Some(num::BigUint::from(solx_utils::BYTE_LENGTH_FIELD)),
+                    )?;
+                    let value = state.load(&offset);
+                    state.push(value)?;
+                }
+                "MSTORE" => {
+                    state.charge(3)?;
+                    let offset = state.pop()?;
+                    let value = state.pop()?;
+                    state.expand_memory(
+                        &offset,
+                        &Some(num::BigUint::from(solx_utils::BYTE_LENGTH_FIELD)),
+                    )?;
+                    state.store(&offset, value);
+                }
+                "MSTORE8" => {
+                    state.charge(3)?;
+                    let offset = state.pop()?;
+                    let _value = state.pop()?;
+                    state.expand_memory(&offset, &Some(num::BigUint::one()))?;
+                    state.invalidate(&offset, &Some(num::BigUint::one()));
+                }
+                "CALLDATACOPY" | "CODECOPY" | "RETURNDATACOPY" | "MCOPY" => {
+                    let destination = state.pop()?;
+                    let _source = state.pop()?;
+                    let size = state.pop()?;
+                    state.charge(3 + 3 * Self::words(&size)?)?;
+                    state.expand_memory(&destination, &size)?;
+                    state.invalidate(&destination, &size);
+                }
+                "EXTCODECOPY" => {
+                    let _address = state.pop()?;
+                    let destination = state.pop()?;
+                    let _source = state.pop()?;
+                    let size = state.pop()?;
+                    state.charge(self.state_access_cost(name) + 3 * Self::words(&size)?)?;
+                    state.expand_memory(&destination, &size)?;
+                    state.invalidate(&destination, &size);
+                }
+                name if name.starts_with("LOG") => {
+                    let topics = opcode.0 as u64 - 0xa0;
+                    let offset = state.pop()?;
+                    let size = state.pop()?;
+                    for _ in 0..topics {
+                        state.pop()?;
+                    }
+                    let size_bytes = size.as_ref()?.to_u64()?;
+                    state.charge(375 + 375 * topics + 8 * size_bytes)?;
+                    state.expand_memory(&offset, &size)?;
+                }
+
+                _ => return None,
+            }
+
+            state.pc = next_pc;
+        }
+    }
+
+    ///
+    /// Pops `inputs` values and pushes `outputs` unknown values, charging `cost`.
+    ///
+    fn unknown(state: &mut State, cost: u64, inputs: usize, outputs: usize) -> Option<()> {
+        state.charge(cost)?;
+        for _ in 0..inputs {
+            state.pop()?;
+        }
+        for _ in 0..outputs {
+            state.push(None)?;
+        }
+        Some(())
+    }
+
+    ///
+    /// Returns the worst-case cost of the state access instruction `name` in the target EVM
+    /// version, which is the cold access cost since `berlin`.
+    ///
+    fn state_access_cost(&self, name: &str) -> u64 {
+        use solx_utils::EVMVersion;
+
+        match (name, self.evm_version) {
+            ("SLOAD", version) if version >= EVMVersion::Berlin => 2100,
+            ("SLOAD", version) if version >= EVMVersion::Istanbul => 800,
+            ("SLOAD", version) if version >= EVMVersion::TangerineWhistle => 200,
+            ("SLOAD", _) => 50,
+            ("SSTORE", version) if version >= EVMVersion::Berlin => 22100,
+            ("SSTORE", _) => 20000,
+            (_, version) if version >= EVMVersion::Berlin => 2600,
+            (_, version) if version >= EVMVersion::Istanbul => 700,
+            ("BALANCE" | "EXTCODEHASH", version) if version >= EVMVersion::TangerineWhistle => 400,
+            (_, version) if version >= EVMVersion::TangerineWhistle => 700,
+            (_, _) => 20,
+        }
+    }
+
+    ///
+    /// Checks if the known `destination` is a valid jump destination.
+    ///
+    fn jump_destination(&self, destination: &Value) -> Option<usize> {
+        let destination = destination.as_ref()?.to_usize()?;
+        self.jump_destinations
+            .contains(&destination)
+            .then_some(destination)
+    }
+
+    ///
+    /// Computes a binary operation over known values.
+    ///
+    fn binary(name: &str, left: num::BigUint, right: num::BigUint) -> Value {
+        let result = match name {
+            "ADD" => (left + right) & Self::mask(),
+            "SUB" => ((left + (Self::mask() + 1u32)) - right) & Self::mask(),
+            "MUL" => (left * right) & Self::mask(),
+            "DIV" if right.is_zero() => num::BigUint::zero(),
+            "DIV" => left / right,
+            "MOD" if right.is_zero() => num::BigUint::zero(),
+            "MOD" => left % right,
+            "AND" => left & right,
+            "OR" => left | right,
+            "XOR" => left ^ right,
+            "EQ" => Self::boolean(left == right),
+            "LT" => Self::boolean(left < right),
+            "GT" => Self::boolean(left > right),
+            "SHL" => match left.to_usize() {
+                Some(shift) if shift < solx_utils::BIT_LENGTH_FIELD => {
+                    (right << shift) & Self::mask()
+                }
+                _ => num::BigUint::zero(),
+            },
+            "SHR" => match left.to_usize() {
+                Some(shift) if shift < solx_utils::BIT_LENGTH_FIELD => right >> shift,
+                _ => num::BigUint::zero(),
+            },
+            "BYTE" => match left.to_usize() {
+                Some(index) if index < solx_utils::BYTE_LENGTH_FIELD => {
+                    (right >> (8 * (solx_utils::BYTE_LENGTH_FIELD - 1 - index)))
+                        & num::BigUint::from(u8::MAX)
+                }
+                _ => num::BigUint::zero(),
+            },
+            _ => return None,
+        };
+        Some(result)
+    }
+
+    ///
+    /// Returns the number of words in the known `size`.
+    ///
+    fn words(size: &Value) -> Option<u64> {
+        Some(
+            size.as_ref()?
+                .to_u64()?
+                .div_ceil(solx_utils::BYTE_LENGTH_FIELD as u64),
+        )
+    }
+
+    ///
+    /// Converts a boolean into a value.
+    ///
+    fn boolean(value: bool) -> num::BigUint {
+        if value {
+            num::BigUint::one()
+        } else {
+            num::BigUint::zero()
+        }
+    }
+
+    ///
+    /// Returns the 256-bit mask.
+    ///
+    fn mask() -> num::BigUint {
+        (num::BigUint::one() << solx_utils::BIT_LENGTH_FIELD) - 1u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::build::contract::gas_estimates::estimator::Estimator;
+    use crate::build::contract::gas_estimates::gas::Gas;
+
+    #[test]
+    fn straight_line() {
+        let bytecode = hex::decode("60016002015000").expect("Always valid");
+        assert_eq!(
+            Estimator::new(bytecode.as_slice(), None, solx_utils::EVMVersion::default()).estimate(),
+            Some(Gas::Finite(11)),
+        );
+    }
+
+    #[test]
+    fn most_expensive_branch() {
+        let bytecode = hex::decode("346007575f50005b00").expect("Always valid");
+        assert_eq!(
+            Estimator::new(bytecode.as_slice(), None, solx_utils::EVMVersion::default()).estimate(),
+            Some(Gas::Finite(19)),
+        );
+    }
+
+    #[test]
+    fn loop_is_infinite() {
+        let bytecode = hex::decode("5b5f56").expect("Always valid");
+        assert_eq!(
+            Estimator::new(bytecode.as_slice(), None, solx_utils::EVMVersion::default()).estimate(),
+            Some(Gas::Infinite),
+        );
+    }
+
+    #[test]
+    fn selector_dispatch() {
+        let bytecode =
+            hex::decode("5f3560e01c631234567814600f57005b5f5f5500").expect("Always valid");
+        assert_eq!(
+            Estimator::new(
+                bytecode.as_slice(),
+                Some(vec![0x12, 0x34, 0x56, 0x78]),
+                solx_utils::EVMVersion::default(),
+            )
+            .estimate(),
+            Some(Gas::Finite(22135)),
+        );
+        assert_eq!(
+            Estimator::new(
+                bytecode.as_slice(),
+                Some(vec![0xde, 0xad, 0xbe, 0xef]),
+                solx_utils::EVMVersion::default(),
+            )
+            .estimate(),
+            Some(Gas::Finite(30)),
+        );
+        assert_eq!(
+            Estimator::new(bytecode.as_slice(), None, solx_utils::EVMVersion::default()).estimate(),
+            Some(Gas::Finite(22135)),
+        );
+    }
+
+    #[test]
+    fn metadata_is_not_code() {
+        let bytecode = hex::decode("600456a15b0002").expect("Always valid");
+        assert_eq!(
+            Estimator::new(bytecode.as_slice(), None, solx_utils::EVMVersion::default()).estimate(),
+            Some(Gas::Infinite),
+        );
+    }
+
+    #[test]
+    fn joined_branches() {
+        let mut bytecode = Vec::new();
+        for _ in 0..30 {
+            let target = bytecode.len() as u8 + 6;
+            bytecode.extend_from_slice(&[0x34, 0x60, target, 0x57, 0x5f, 0x50, 0x5b]);
+        }
+        bytecode.push(0x00);
+        assert_eq!(
+            Estimator::new(bytecode.as_slice(), None, solx_utils::EVMVersion::default()).estimate(),
+            Some(Gas::Finite(600)),
+        );
+    }
+
+    #[test]
+    fn budget_exhausted() {
+        let bytecode = hex::decode("621000005b600190038060045700").expect("Always valid");
+        assert_eq!(
+            Estimator::new(bytecode.as_slice(), None, solx_utils::EVMVersion::default()).estimate(),
+            None,
+        );
+    }
+
+    #[test]
+    fn state_access_costs() {
+        let bytecode = hex::decode("5f545f5f5500").expect("Always valid");
+        for (evm_version, gas) in [
+            (solx_utils::EVMVersion::Homestead, 20056),
+            (solx_utils::EVMVersion::Byzantium, 20206),
+            (solx_utils::EVMVersion::Istanbul, 20806),
+            (solx_utils::EVMVersion::Berlin, 24206),
+            (solx_utils::EVMVersion::Osaka, 24206),
+        ] {
+            assert_eq!(
+                Estimator::new(bytecode.as_slice(), None, evm_version).estimate(),
+                Some(Gas::Finite(gas)),
+                "{evm_version}",
+            );
+        }
+    }
+
+    #[test]
+    fn count_leading_zeros() {
+        let bytecode = hex::decode("60ff60011e03600a57005b5f5f5500").expect("Always valid");
+        assert_eq!(
+            Estimator::new(bytecode.as_slice(), None, solx_utils::EVMVersion::Osaka).estimate(),
+            Some(Gas::Finite(27)),
+        );
+    }
+}
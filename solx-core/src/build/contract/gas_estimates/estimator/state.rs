@@ -0,0 +1,154 @@
+//!
+//! The gas estimator execution state.
+//!
+
+use std::collections::BTreeMap;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+use num::ToPrimitive;
+
+///
+/// The abstract stack or memory value, which is `None` if unknown.
+///
+pub type Value = Option<num::BigUint>;
+
+///
+/// The gas estimator execution state.
+///
+/// Tracks the known values on the stack and in memory, so the jump targets, memory offsets, and
+/// dispatcher conditions can be resolved without executing the code.
+///
+#[derive(Debug, Default, Clone)]
+pub struct State {
+    /// The program counter.
+    pub pc: usize,
+    /// The gas spent so far.
+    pub gas: u64,
+    /// The stack.
+    pub stack: Vec<Value>,
+    /// The memory words written at known offsets.
+    pub memory: BTreeMap<u64, Value>,
+    /// The memory size in words.
+    pub memory_words: u64,
+}
+
+impl State {
+    /// The maximum supported memory size in bytes.
+    const MEMORY_SIZE_LIMIT: u64 = 1 << 32;
+
+    /// The maximum stack depth.
+    const STACK_DEPTH_LIMIT: usize = 1024;
+
+    ///
+    /// Adds the gas cost, returning `None` on overflow.
+    ///
+    pub fn charge(&mut self, gas: u64) -> Option<()> {
+        self.gas = self.gas.checked_add(gas)?;
+        Some(())
+    }
+
+    ///
+    /// Pops a value, returning `None` on stack underflow.
+    ///
+    pub fn pop(&mut self) -> Option<Value> {
+        self.stack.pop()
+    }
+
+    ///
+    /// Pushes a value, returning `None` on stack overflow.
+    ///
+    pub fn push(&mut self, value: Value) -> Option<()> {
+        if self.stack.len() >= Self::STACK_DEPTH_LIMIT {
+            return None;
+        }
+        self.stack.push(value);
+        Some(())
+    }
+
+    ///
+    /// Charges the memory expansion cost of accessing `size` bytes at `offset`.
+    ///
+    /// Returns `None` if the accessed range is not known.
+    ///
+    pub fn expand_memory(&mut self, offset: &Value, size: &Value) -> Option<()> {
+        let size = size.as_ref()?.to_u64()?;
+        if size == 0 {
+            return Some(());
+        }
+        let offset = offset.as_ref()?.to_u64()?;
+        let end = offset.checked_add(size)?;
+        if end > Self::MEMORY_SIZE_LIMIT {
+            return None;
+        }
+
+        let words = end.div_ceil(solx_utils::BYTE_LENGTH_FIELD as u64);
+        if words > self.memory_words {
+            let cost = Self::memory_cost(words) - Self::memory_cost(self.memory_words);
+            self.memory_words = words;
+            self.charge(cost)?;
+        }
+        Some(())
+    }
+
+    ///
+    /// Loads a memory word.
+    ///
+    pub fn load(&self, offset: &Value) -> Value {
+        let offset = offset.as_ref()?.to_u64()?;
+        self.memory.get(&offset).cloned().flatten()
+    }
+
+    ///
+    /// Stores a memory word.
+    ///
+    pub fn store(&mut self, offset: &Value, value: Value) {
+        self.invalidate(
+            offset,
+            &Some(num::BigUint::from(solx_utils::BYTE_LENGTH_FIELD)),
+        );
+        if let Some(offset) = offset.as_ref().and_then(|offset| offset.to_u64()) {
+            self.memory.insert(offset, value);
+        }
+    }
+
+    ///
+    /// Forgets the memory words overlapping `size` bytes at `offset`.
+    ///
+    /// Forgets the entire memory if the range is not known.
+    ///
+    pub fn invalidate(&mut self, offset: &Value, size: &Value) {
+        let range = offset
+            .as_ref()
+            .and_then(|offset| offset.to_u64())
+            .zip(size.as_ref().and_then(|size| size.to_u64()));
+        match range {
+            Some((_offset, 0)) => {}
+            Some((offset, size)) => {
+                let start = offset.saturating_sub(solx_utils::BYTE_LENGTH_FIELD as u64 - 1);
+                let end = offset.saturating_add(size);
+                self.memory.retain(|key, _| *key < start || *key >= end);
+            }
+            None => self.memory.clear(),
+        }
+    }
+
+    ///
+    /// Returns the fingerprint of the state, excluding the spent gas.
+    ///
+    pub fn fingerprint(&self) -> u64 {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        self.pc.hash(&mut hasher);
+        self.stack.hash(&mut hasher);
+        self.memory.hash(&mut hasher);
+        self.memory_words.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    ///
+    /// Returns the total cost of memory of `words` size.
+    ///
+    fn memory_cost(words: u64) -> u64 {
+        3 * words + words * words / 512
+    }
+}
@@ -0,0 +1,48 @@
+//!
+//! The gas estimate.
+//!
+
+///
+/// The gas estimate.
+///
+/// Is serialized as a decimal string, or `infinite` if the cost is unbounded.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gas {
+    /// The upper bound of the cost.
+    Finite(u64),
+    /// The cost cannot be bounded statically.
+    Infinite,
+}
+
+impl std::ops::Add for Gas {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        match (self, other) {
+            (Self::Finite(left), Self::Finite(right)) => left
+                .checked_add(right)
+                .map(Self::Finite)
+                .unwrap_or(Self::Infinite),
+            _ => Self::Infinite,
+        }
+    }
+}
+
+impl serde::Serialize for Gas {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.to_string().as_str())
+    }
+}
+
+impl std::fmt::Display for Gas {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Finite(value) => write!(f, "{value}"),
+            Self::Infinite => write!(f, "infinite"),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//!
+//! The contract gas estimates.
+//!
+
+pub mod creation;
+pub mod estimator;
+pub mod gas;
+
+use std::collections::BTreeMap;
+
+use self::creation::Creation;
+use self::estimator::Estimator;
+use self::gas::Gas;
+
+///
+/// The contract gas estimates.
+///
+/// Follows the `solc` standard JSON output format.
+///
+#[derive(Debug, serde::Serialize)]
+pub struct GasEstimates {
+    /// The contract creation costs.
+    pub creation: Creation,
+    /// The external function costs, mapped from their signatures.
+    pub external: BTreeMap<String, Gas>,
+}
+
+impl GasEstimates {
+    /// The code deposit cost per byte.
+    pub const CODE_DEPOSIT_COST_PER_BYTE: u64 = 200;
+
+    ///
+    /// Estimates the costs of the final deploy and runtime bytecode for `evm_version`.
+    ///
+    /// `method_identifiers` maps function signatures to their hexadecimal selectors.
+    /// The costs whose exploration budget is exhausted are omitted.
+    ///
+    pub fn new(
+        deploy_bytecode: &[u8],
+        runtime_bytecode: &[u8],
+        method_identifiers: &BTreeMap<String, String>,
+        evm_version: solx_utils::EVMVersion,
+    ) -> Self {
+        let code_deposit_cost =
+            Gas::Finite(Self::CODE_DEPOSIT_COST_PER_BYTE * runtime_bytecode.len() as u64);
+        let execution_cost = Estimator::new(deploy_bytecode, None, evm_version).estimate();
+
+        let external = method_identifiers
+            .iter()
+            .filter_map(|(signature, selector)| {
+                let gas = match hex::decode(selector) {
+                    Ok(selector) => {
+                        Estimator::new(runtime_bytecode, Some(selector), evm_version).estimate()?
+                    }
+                    Err(_) => Gas::Infinite,
+                };
+                Some((signature.to_owned(), gas))
+            })
+            .collect();
+
+        Self {
+            creation: Creation::new(code_deposit_cost, execution_cost),
+            external,
+        }
+    }
+}
@@ -2,6 +2,8 @@
 //! Solidity contract build.
 //!
 
+pub mod diagnostic;
+pub mod gas_estimates;
 pub mod object;
 
 use std::collections::BTreeMap;
@@ -11,6 +13,7 @@ use std::path::PathBuf;
 
 use normpath::PathExt;
 
+use self::gas_estimates::GasEstimates;
 use self::object::Object;
 
 ///
@@ -84,6 +87,7 @@ impl Contract {
     pub fn write_to_terminal(
         mut self,
         output_selection: &solx_standard_json::InputSelection,
+        remarks_format: solx_codegen_evm::RemarkFormat,
     ) -> anyhow::Result<()> {
         writeln!(
             std::io::stdout(),
@@ -171,6 +175,27 @@ impl Contract {
             }
         }
 
+        if let Some(Ok(deploy_object)) = self.deploy_object_result.as_ref() {
+            if output_selection.check_selection(
+                self.name.path.as_str(),
+                self.name.name.as_deref(),
+                solx_standard_json::InputSelector::BytecodeSize,
+            ) {
+                let size = deploy_object.size().expect("Always exists");
+                writeln!(std::io::stdout(), "Deploy code size: {size}")?;
+            }
+        }
+        if let Some(Ok(runtime_object)) = self.runtime_object_result.as_ref() {
+            if output_selection.check_selection(
+                self.name.path.as_str(),
+                self.name.name.as_deref(),
+                solx_standard_json::InputSelector::RuntimeBytecodeSize,
+            ) {
+                let size = runtime_object.size().expect("Always exists");
+                writeln!(std::io::stdout(), "Runtime code size: {size}")?;
+            }
+        }
+
         if output_selection.check_selection(
             self.name.path.as_str(),
             self.name.name.as_deref(),
@@ -262,6 +287,9 @@ impl Contract {
                 self.userdoc.expect("Always exists")
             )?;
         }
+        if let Some(remarks) = self.take_remarks(output_selection, remarks_format)? {
+            writeln!(std::io::stdout(), "Optimization remarks:\n{remarks}")?;
+        }
         if let (Some(deploy_object_result), Some(runtime_object_result)) =
             (self.deploy_object_result, self.runtime_object_result)
         {
@@ -298,6 +326,7 @@ impl Contract {
         mut self,
         output_directory: &Path,
         output_selection: &solx_standard_json::InputSelection,
+        remarks_format: solx_codegen_evm::RemarkFormat,
         overwrite: bool,
     ) -> anyhow::Result<()> {
         let contract_path = PathBuf::from(self.name.path.as_str());
@@ -564,6 +593,48 @@ impl Contract {
             let yul = self.yul.expect("Always exists").to_string();
             Self::write_to_file(output_path.as_path(), yul, overwrite)?;
         }
+        if let (Some(Ok(deploy_object)), Some(Ok(runtime_object))) = (
+            self.deploy_object_result.as_ref(),
+            self.runtime_object_result.as_ref(),
+        ) {
+            if output_selection.check_selection(
+                self.name.path.as_str(),
+                self.name.name.as_deref(),
+                solx_standard_json::InputSelector::BytecodeSize,
+            ) || output_selection.check_selection(
+                self.name.path.as_str(),
+                self.name.name.as_deref(),
+                solx_standard_json::InputSelector::RuntimeBytecodeSize,
+            ) {
+                let output_name = format!(
+                    "{contract_path}_{}_size.txt",
+                    self.name.name.as_deref().unwrap_or(contract_name),
+                );
+                let mut output_path = output_directory.to_owned();
+                output_path.push(output_name.as_str());
+
+                let output = format!(
+                    "Deploy code size: {}\nRuntime code size: {}\n",
+                    deploy_object.size().expect("Always exists"),
+                    runtime_object.size().expect("Always exists"),
+                );
+                Self::write_to_file(output_path.as_path(), output, overwrite)?;
+            }
+        }
+        if let Some(remarks) = self.take_remarks(output_selection, remarks_format)? {
+            let output_name = format!(
+                "{contract_path}_{}_remarks.{}",
+                self.name.name.as_deref().unwrap_or(contract_name),
+                match remarks_format {
+                    solx_codegen_evm::RemarkFormat::YAML => solx_utils::EXTENSION_YAML,
+                    solx_codegen_evm::RemarkFormat::JSON => solx_utils::EXTENSION_JSON,
+                },
+            );
+            let mut output_path = output_directory.to_owned();
+            output_path.push(output_name.as_str());
+
+            Self::write_to_file(output_path.as_path(), remarks, overwrite)?;
+        }
         if let (Some(deploy_object_result), Some(runtime_object_result)) =
             (self.deploy_object_result, self.runtime_object_result)
         {
@@ -604,12 +675,15 @@ impl Contract {
     ///
     /// Writes the contract text assembly and bytecode to the standard JSON.
     ///
+    /// Returns an error if the source maps cannot be resolved against the final bytecode.
+    ///
     pub fn write_to_standard_json(
         mut self,
         standard_json_contract: &mut solx_standard_json::OutputContract,
         output_selection: &solx_standard_json::InputSelection,
         is_bytecode_linked: bool,
-    ) {
+        evm_version: solx_utils::EVMVersion,
+    ) -> anyhow::Result<()> {
         if let Some(value) = self.metadata.take().filter(|_| {
             output_selection.check_selection(
                 self.name.path.as_str(),
@@ -677,6 +751,18 @@ impl Contract {
         let evm = standard_json_contract
             .evm
             .get_or_insert_with(solx_standard_json::OutputContractEVM::default);
+        if output_selection.check_selection(
+            self.name.path.as_str(),
+            self.name.name.as_deref(),
+            solx_standard_json::InputSelector::GasEstimates,
+        ) {
+            evm.gas_estimates = Some(
+                self.gas_estimates(evm_version)
+                    .filter(|_| is_bytecode_linked)
+                    .map(|gas_estimates| serde_json::to_value(gas_estimates).expect("Always valid"))
+                    .unwrap_or_else(|| serde_json::json!({})),
+            );
+        }
         if let Some(value) = self.method_identifiers.take().filter(|_| {
             output_selection.check_selection(
                 self.name.path.as_str(),
@@ -695,13 +781,6 @@ impl Contract {
         }) {
             evm.legacy_assembly = Some(value);
         }
-        if output_selection.check_selection(
-            self.name.path.as_str(),
-            self.name.name.as_deref(),
-            solx_standard_json::InputSelector::GasEstimates,
-        ) {
-            evm.gas_estimates = Some(serde_json::json!({}));
-        }
 
         evm.bytecode = Some(solx_standard_json::OutputContractEVMBytecode::new(
             if is_bytecode_linked {
@@ -742,6 +821,36 @@ impl Contract {
                         })
                 })
                 .unwrap_or_default(),
+            self.deploy_object_result
+                .as_mut()
+                .and_then(|result| result.as_mut().expect("Always exists").llvm_ir.take()),
+            self.deploy_object_result.as_mut().and_then(|result| {
+                result
+                    .as_mut()
+                    .expect("Always exists")
+                    .llvm_ir_optimized
+                    .take()
+            }),
+            self.deploy_object_result
+                .as_mut()
+                .filter(|_| {
+                    output_selection.check_selection(
+                        self.name.path.as_str(),
+                        self.name.name.as_deref(),
+                        solx_standard_json::InputSelector::BytecodeRemarks,
+                    )
+                })
+                .map(|result| {
+                    std::mem::take(&mut result.as_mut().expect("Always exists").remarks)
+                        .into_iter()
+                        .map(|remark| {
+                            solx_standard_json::OutputContractEVMBytecodeRemark::new(
+                                remark.pipeline_element,
+                                remark.message,
+                            )
+                        })
+                        .collect()
+                }),
             if is_bytecode_linked
                 && output_selection.check_selection(
                     self.name.path.as_str(),
@@ -783,12 +892,30 @@ impl Contract {
             } else {
                 vec![]
             },
+            if output_selection.check_selection(
+                self.name.path.as_str(),
+                self.name.name.as_deref(),
+                solx_standard_json::InputSelector::BytecodeSize,
+            ) {
+                self.deploy_object_result
+                    .as_ref()
+                    .filter(|_| is_bytecode_linked)
+                    .and_then(|result| result.as_ref().expect("Always exists").size())
+            } else {
+                None
+            },
             if output_selection.check_selection(
                 self.name.path.as_str(),
                 self.name.name.as_deref(),
                 solx_standard_json::InputSelector::BytecodeOpcodes,
             ) {
-                Some(String::new())
+                Some(
+                    self.deploy_object_result
+                        .as_ref()
+                        .filter(|_| is_bytecode_linked)
+                        .and_then(|result| result.as_ref().expect("Always exists").opcodes())
+                        .unwrap_or_default(),
+                )
             } else {
                 None
             },
@@ -797,7 +924,19 @@ impl Contract {
                 self.name.name.as_deref(),
                 solx_standard_json::InputSelector::BytecodeSourceMap,
             ) {
-                Some(String::new())
+                Some(
+                    self.deploy_object_result
+                        .as_ref()
+                        .filter(|_| is_bytecode_linked)
+                        .and_then(|result| {
+                            result
+                                .as_ref()
+                                .expect("Always exists")
+                                .source_map_compressed()
+                        })
+                        .transpose()?
+                        .unwrap_or_default(),
+                )
             } else {
                 None
             },
@@ -806,7 +945,19 @@ impl Contract {
                 self.name.name.as_deref(),
                 solx_standard_json::InputSelector::BytecodeFunctionDebugData,
             ) {
-                Some(BTreeMap::new())
+                Some(
+                    self.deploy_object_result
+                        .as_ref()
+                        .filter(|_| is_bytecode_linked)
+                        .and_then(|result| {
+                            result
+                                .as_ref()
+                                .expect("Always exists")
+                                .function_debug_data()
+                        })
+                        .transpose()?
+                        .unwrap_or_default(),
+                )
             } else {
                 None
             },
@@ -861,6 +1012,36 @@ impl Contract {
                         })
                 })
                 .unwrap_or_default(),
+            self.runtime_object_result
+                .as_mut()
+                .and_then(|result| result.as_mut().expect("Always exists").llvm_ir.take()),
+            self.runtime_object_result.as_mut().and_then(|result| {
+                result
+                    .as_mut()
+                    .expect("Always exists")
+                    .llvm_ir_optimized
+                    .take()
+            }),
+            self.runtime_object_result
+                .as_mut()
+                .filter(|_| {
+                    output_selection.check_selection(
+                        self.name.path.as_str(),
+                        self.name.name.as_deref(),
+                        solx_standard_json::InputSelector::RuntimeBytecodeRemarks,
+                    )
+                })
+                .map(|result| {
+                    std::mem::take(&mut result.as_mut().expect("Always exists").remarks)
+                        .into_iter()
+                        .map(|remark| {
+                            solx_standard_json::OutputContractEVMBytecodeRemark::new(
+                                remark.pipeline_element,
+                                remark.message,
+                            )
+                        })
+                        .collect()
+                }),
             if is_bytecode_linked
                 && output_selection.check_selection(
                     self.name.path.as_str(),
@@ -902,12 +1083,30 @@ impl Contract {
             } else {
                 vec![]
             },
+            if output_selection.check_selection(
+                self.name.path.as_str(),
+                self.name.name.as_deref(),
+                solx_standard_json::InputSelector::RuntimeBytecodeSize,
+            ) {
+                self.runtime_object_result
+                    .as_ref()
+                    .filter(|_| is_bytecode_linked)
+                    .and_then(|result| result.as_ref().expect("Always exists").size())
+            } else {
+                None
+            },
             if output_selection.check_selection(
                 self.name.path.as_str(),
                 self.name.name.as_deref(),
                 solx_standard_json::InputSelector::RuntimeBytecodeOpcodes,
             ) {
-                Some(String::new())
+                Some(
+                    self.runtime_object_result
+                        .as_ref()
+                        .filter(|_| is_bytecode_linked)
+                        .and_then(|result| result.as_ref().expect("Always exists").opcodes())
+                        .unwrap_or_default(),
+                )
             } else {
                 None
             },
@@ -916,7 +1115,19 @@ impl Contract {
                 self.name.name.as_deref(),
                 solx_standard_json::InputSelector::RuntimeBytecodeSourceMap,
             ) {
-                Some(String::new())
+                Some(
+                    self.runtime_object_result
+                        .as_ref()
+                        .filter(|_| is_bytecode_linked)
+                        .and_then(|result| {
+                            result
+                                .as_ref()
+                                .expect("Always exists")
+                                .source_map_compressed()
+                        })
+                        .transpose()?
+                        .unwrap_or_default(),
+                )
             } else {
                 None
             },
@@ -925,7 +1136,19 @@ impl Contract {
                 self.name.name.as_deref(),
                 solx_standard_json::InputSelector::RuntimeBytecodeFunctionDebugData,
             ) {
-                Some(BTreeMap::new())
+                Some(
+                    self.runtime_object_result
+                        .as_ref()
+                        .filter(|_| is_bytecode_linked)
+                        .and_then(|result| {
+                            result
+                                .as_ref()
+                                .expect("Always exists")
+                                .function_debug_data()
+                        })
+                        .transpose()?
+                        .unwrap_or_default(),
+                )
             } else {
                 None
             },
@@ -943,11 +1166,103 @@ impl Contract {
                 self.name.name.as_deref(),
                 solx_standard_json::InputSelector::RuntimeBytecodeImmutableReferences,
             ) {
-                Some(serde_json::json!({}))
+                Some(
+                    self.runtime_object_result
+                        .as_ref()
+                        .filter(|_| is_bytecode_linked)
+                        .and_then(|result| {
+                            result
+                                .as_ref()
+                                .expect("Always exists")
+                                .immutables
+                                .to_owned()
+                        })
+                        .unwrap_or_default(),
+                )
             } else {
                 None
             },
         ));
+        Ok(())
+    }
+
+    ///
+    /// Estimates the gas costs of the final bytecode.
+    ///
+    /// Returns `None` if the bytecode is not available.
+    ///
+    pub fn gas_estimates(&self, evm_version: solx_utils::EVMVersion) -> Option<GasEstimates> {
+        let deploy_bytecode = self
+            .deploy_object_result
+            .as_ref()?
+            .as_ref()
+            .expect("Always exists")
+            .bytecode
+            .as_deref()?;
+        let runtime_bytecode = self
+            .runtime_object_result
+            .as_ref()?
+            .as_ref()
+            .expect("Always exists")
+            .bytecode
+            .as_deref()?;
+        let method_identifiers = self.method_identifiers.clone().unwrap_or_default();
+        Some(GasEstimates::new(
+            deploy_bytecode,
+            runtime_bytecode,
+            &method_identifiers,
+            evm_version,
+        ))
+    }
+
+    ///
+    /// Takes the selected optimization remarks of the deploy and runtime code, and serializes
+    /// them into `format`.
+    ///
+    /// Returns `None` if the remarks are selected for neither code segment.
+    ///
+    fn take_remarks(
+        &mut self,
+        output_selection: &solx_standard_json::InputSelection,
+        format: solx_codegen_evm::RemarkFormat,
+    ) -> anyhow::Result<Option<String>> {
+        let mut remarks = None;
+        for (object_result, selector) in [
+            (
+                self.deploy_object_result.as_mut(),
+                solx_standard_json::InputSelector::BytecodeRemarks,
+            ),
+            (
+                self.runtime_object_result.as_mut(),
+                solx_standard_json::InputSelector::RuntimeBytecodeRemarks,
+            ),
+        ] {
+            let Some(Ok(object)) = object_result else {
+                continue;
+            };
+            if !output_selection.check_selection(
+                self.name.path.as_str(),
+                self.name.name.as_deref(),
+                selector,
+            ) {
+                continue;
+            }
+            remarks
+                .get_or_insert_with(Vec::new)
+                .extend(std::mem::take(&mut object.remarks));
+        }
+
+        remarks
+            .map(|remarks: Vec<solx_codegen_evm::Remark>| match format {
+                solx_codegen_evm::RemarkFormat::YAML => Ok(remarks
+                    .iter()
+                    .map(solx_codegen_evm::Remark::to_yaml)
+                    .collect::<String>()),
+                solx_codegen_evm::RemarkFormat::JSON => {
+                    serde_json::to_string_pretty(&remarks).map_err(anyhow::Error::from)
+                }
+            })
+            .transpose()
     }
 
     ///
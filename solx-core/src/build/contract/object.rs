@@ -5,6 +5,8 @@
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
+use crate::build::contract::diagnostic::Diagnostic;
+
 ///
 /// Bytecode object.
 ///
@@ -18,6 +20,10 @@ pub struct Object {
     pub contract_name: solx_utils::ContractName,
     /// Text assembly.
     pub assembly: Option<String>,
+    /// Unoptimized LLVM IR.
+    pub llvm_ir: Option<String>,
+    /// Optimized LLVM IR.
+    pub llvm_ir_optimized: Option<String>,
     /// Bytecode.
     pub bytecode: Option<Vec<u8>>,
     /// Hexadecimal bytecode.
@@ -30,6 +36,8 @@ pub struct Object {
     pub metadata_bytes: Option<Vec<u8>>,
     /// Immutables of the runtime code.
     pub immutables: Option<BTreeMap<String, BTreeSet<u64>>>,
+    /// Source map of the assembly instructions.
+    pub source_map: Option<solx_codegen_evm::SourceMap>,
     /// Dependencies.
     pub dependencies: solx_yul::Dependencies,
     /// Linker symbols that were not provided at compile time.
@@ -37,12 +45,22 @@ pub struct Object {
     pub unlinked_symbols: BTreeMap<String, Vec<u64>>,
     /// Whether the object is already assembled.
     pub is_assembled: bool,
+    /// Total size of the dependencies embedded during assembly.
+    pub dependencies_size: usize,
+    /// Bytecode size limit of the code segment.
+    pub size_limit: usize,
     /// Whether the size fallback was activated during the compilation.
     pub is_size_fallback: bool,
     /// Compilation warnings.
     pub warnings: Vec<solx_codegen_evm::Warning>,
+    /// Optimization remarks.
+    pub remarks: Vec<solx_codegen_evm::Remark>,
+    /// Multi-pass compilation pipeline diagnostics.
+    pub diagnostics: Vec<Diagnostic>,
     /// Compilation pipeline benchmarks.
     pub benchmarks: Vec<(String, u64)>,
+    /// Compilation pipeline trace events.
+    pub trace_events: Vec<solx_codegen_evm::TraceEvent>,
 }
 
 impl Object {
@@ -56,33 +74,46 @@ impl Object {
         identifier: String,
         contract_name: solx_utils::ContractName,
         assembly: Option<String>,
+        llvm_ir: Option<String>,
+        llvm_ir_optimized: Option<String>,
         bytecode: Option<Vec<u8>>,
         via_ir: bool,
         code_segment: solx_utils::CodeSegment,
         immutables: Option<BTreeMap<String, BTreeSet<u64>>>,
         metadata_bytes: Option<Vec<u8>>,
+        source_map: Option<solx_codegen_evm::SourceMap>,
         dependencies: solx_yul::Dependencies,
         is_size_fallback: bool,
         warnings: Vec<solx_codegen_evm::Warning>,
+        remarks: Vec<solx_codegen_evm::Remark>,
         benchmarks: Vec<(String, u64)>,
+        trace_events: Vec<solx_codegen_evm::TraceEvent>,
     ) -> Self {
         let bytecode_hex = bytecode.as_ref().map(hex::encode);
         Self {
             identifier,
             contract_name,
             assembly,
+            llvm_ir,
+            llvm_ir_optimized,
             bytecode,
             bytecode_hex,
             via_ir,
             code_segment,
             immutables,
             metadata_bytes,
+            source_map,
             dependencies,
             unlinked_symbols: BTreeMap::new(),
             is_assembled: false,
+            dependencies_size: 0,
+            size_limit: solx_utils::CodeSizeLimits::default().get(code_segment),
             is_size_fallback,
             warnings,
+            remarks,
+            diagnostics: Vec::new(),
             benchmarks,
+            trace_events,
         }
     }
 
@@ -156,6 +187,29 @@ impl Object {
         )
     }
 
+    ///
+    /// Returns the total size of the dependencies to be embedded during assembly.
+    ///
+    /// # Panics
+    /// If the bytecode of any dependency is `None`.
+    ///
+    pub fn dependencies_size(&self, all_objects: &[&Self]) -> usize {
+        self.dependencies
+            .inner
+            .iter()
+            .map(|dependency| {
+                all_objects
+                    .iter()
+                    .find(|object| object.identifier.as_str() == dependency.as_str())
+                    .expect("Dependency not found")
+                    .bytecode
+                    .as_ref()
+                    .expect("Bytecode is not set")
+                    .len()
+            })
+            .sum()
+    }
+
     ///
     /// Links the object with its linker symbols.
     ///
@@ -191,11 +245,7 @@ impl Object {
 
         let mut bytecode_hex = hex::encode(linked_object_with_placeholders.as_slice());
         for (symbol, offsets) in self.unlinked_symbols.iter() {
-            let hash = solx_utils::Keccak256Hash::from_slice(symbol.as_bytes()).to_vec();
-            let placeholder = format!(
-                "__${}$__",
-                hex::encode(&hash[0..Self::LIBRARY_PLACEHOLDER_LENGTH])
-            );
+            let placeholder = Self::library_placeholder(symbol.as_str());
             for offset in offsets.iter() {
                 let offset = *offset as usize;
                 unsafe {
@@ -212,7 +262,143 @@ impl Object {
     }
 
     ///
-    /// Extracts warnings in standard JSON format.
+    /// Returns the disassembled bytecode in the `solc` format.
+    ///
+    /// Must be called after linking, so the unlinked library addresses are zeroed.
+    ///
+    pub fn opcodes(&self) -> Option<String> {
+        let bytecode = self.bytecode.as_deref()?;
+        let placeholders = self
+            .unlinked_symbols
+            .iter()
+            .flat_map(|(symbol, offsets)| {
+                let placeholder = Self::library_placeholder(symbol.as_str());
+                offsets
+                    .iter()
+                    .map(move |offset| (*offset as usize, placeholder.clone()))
+            })
+            .collect::<BTreeMap<usize, String>>();
+        Some(solx_utils::disassemble(bytecode, &placeholders))
+    }
+
+    ///
+    /// Returns the bytecode size breakdown.
+    ///
+    /// Must be called after assembly, as the dependencies are only embedded then.
+    ///
+    pub fn size(&self) -> Option<solx_standard_json::OutputContractEVMBytecodeSize> {
+        let bytecode = self.bytecode.as_deref()?;
+        let metadata = match self.code_segment {
+            solx_utils::CodeSegment::Deploy => 0,
+            solx_utils::CodeSegment::Runtime => self
+                .metadata_bytes
+                .as_ref()
+                .map(Vec::len)
+                .unwrap_or_default(),
+        };
+        let immutables = self
+            .immutables
+            .iter()
+            .flat_map(|immutables| immutables.values())
+            .map(|offsets| offsets.len() * solx_utils::BYTE_LENGTH_FIELD)
+            .sum();
+        Some(solx_standard_json::OutputContractEVMBytecodeSize::new(
+            bytecode.len(),
+            metadata,
+            immutables,
+            self.dependencies_size,
+            self.size_limit,
+        ))
+    }
+
+    ///
+    /// Returns the placeholder of the unlinked library `symbol`.
+    ///
+    pub fn library_placeholder(symbol: &str) -> String {
+        let hash = solx_utils::Keccak256Hash::from_slice(symbol.as_bytes()).to_vec();
+        format!(
+            "__${}$__",
+            hex::encode(&hash[0..Self::LIBRARY_PLACEHOLDER_LENGTH])
+        )
+    }
+
+    ///
+    /// Returns the main code of the bytecode, without the appended dependencies and metadata.
+    ///
+    fn main_code(&self) -> Option<&[u8]> {
+        let bytecode = self.bytecode.as_deref()?;
+        let metadata = match self.code_segment {
+            solx_utils::CodeSegment::Deploy => 0,
+            solx_utils::CodeSegment::Runtime => self
+                .metadata_bytes
+                .as_ref()
+                .map(Vec::len)
+                .unwrap_or_default(),
+        };
+        let length = bytecode
+            .len()
+            .checked_sub(self.dependencies_size + metadata)?;
+        Some(&bytecode[..length])
+    }
+
+    ///
+    /// Returns the source map in the `solc` compressed format.
+    ///
+    /// Must be called after linking, as the source map is resolved against the final bytecode.
+    ///
+    pub fn source_map_compressed(&self) -> Option<anyhow::Result<String>> {
+        let source_map = self.source_map.as_ref()?;
+        let main_code = self.main_code()?;
+        Some(
+            source_map
+                .compress(main_code)
+                .map_err(|error| anyhow::anyhow!("{} source map: {error}", self.identifier)),
+        )
+    }
+
+    ///
+    /// Returns the function debug data in the `solc` format.
+    ///
+    /// Must be called after linking, as the entry points are resolved against the final bytecode.
+    ///
+    pub fn function_debug_data(
+        &self,
+    ) -> Option<
+        anyhow::Result<
+            BTreeMap<String, solx_standard_json::OutputContractEVMBytecodeFunctionDebugData>,
+        >,
+    > {
+        let source_map = self.source_map.as_ref()?;
+        let functions = source_map.functions.as_ref()?;
+        let main_code = self.main_code()?;
+
+        let entry_points = match source_map.entry_points(main_code) {
+            Ok(entry_points) => entry_points,
+            Err(error) => {
+                return Some(Err(anyhow::anyhow!(
+                    "{} function debug data: {error}",
+                    self.identifier
+                )))
+            }
+        };
+        Some(Ok(functions
+            .iter()
+            .map(|(name, function)| {
+                (
+                    name.to_owned(),
+                    solx_standard_json::OutputContractEVMBytecodeFunctionDebugData::new(
+                        entry_points.get(name.as_str()).copied(),
+                        function.id,
+                        function.parameter_slots,
+                        function.return_slots,
+                    ),
+                )
+            })
+            .collect()))
+    }
+
+    ///
+    /// Extracts warnings and pipeline diagnostics in standard JSON format.
     ///
     pub fn take_warnings_standard_json(
         &mut self,
@@ -230,6 +416,11 @@ impl Object {
                     None,
                 )
             })
+            .chain(
+                self.diagnostics
+                    .drain(..)
+                    .map(|diagnostic| diagnostic.to_standard_json(path)),
+            )
             .collect::<Vec<solx_standard_json::OutputError>>()
     }
 }
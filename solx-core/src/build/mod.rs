@@ -5,6 +5,7 @@
 pub mod contract;
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::io::Write;
 use std::path::Path;
 use std::path::PathBuf;
@@ -33,9 +34,16 @@ pub struct Build {
     pub messages: Arc<Mutex<Vec<solx_standard_json::OutputError>>>,
     /// Compilation pipeline benchmarks.
     pub benchmarks: Vec<(String, u64)>,
+    /// Compilation pipeline trace events.
+    pub trace_events: Vec<solx_codegen_evm::TraceEvent>,
+    /// The format of the optimization remarks written to the terminal or files.
+    pub remarks_format: solx_codegen_evm::RemarkFormat,
 }
 
 impl Build {
+    /// The name of the staging directory used for atomic writing.
+    pub const STAGING_DIRECTORY_NAME: &'static str = ".solx_staging";
+
     ///
     /// A shortcut constructor.
     ///
@@ -49,6 +57,8 @@ impl Build {
             ast_jsons,
             messages,
             benchmarks: Vec::new(),
+            trace_events: Vec::new(),
+            remarks_format: solx_codegen_evm::RemarkFormat::default(),
         }
     }
 
@@ -105,12 +115,15 @@ impl Build {
                         object.contract_name.full_path.to_owned(),
                         object.code_segment,
                         assembled_object,
+                        object.dependencies_size(all_objects.as_slice()),
                     ));
                 }
                 assembled_objects_data
             };
 
-            for (full_path, code_segment, assembled_object) in assembled_objects_data.into_iter() {
+            for (full_path, code_segment, assembled_object, dependencies_size) in
+                assembled_objects_data.into_iter()
+            {
                 let contract = self
                     .contracts
                     .get_mut(full_path.as_str())
@@ -120,6 +133,7 @@ impl Build {
                     None => continue,
                 };
                 object.bytecode = Some(assembled_object.as_slice().to_owned());
+                object.dependencies_size = dependencies_size;
                 for undefined_reference in assembled_object
                     .get_undefined_references_evm()
                     .into_iter()
@@ -147,7 +161,41 @@ impl Build {
             }
         }
 
-        Self::new(self.contracts, ast_jsons, self.messages)
+        let mut build = Self::new(self.contracts, ast_jsons, self.messages);
+        build.trace_events = self.trace_events;
+        build
+    }
+
+    ///
+    /// Writes the compilation pipeline trace to `path` in the Chrome trace format.
+    ///
+    /// The trace combines the pipeline elements of this process with the translation units
+    /// compiled by the workers, which are distinguished by their process and thread IDs.
+    ///
+    pub fn write_trace(&self, path: &Path) -> anyhow::Result<()> {
+        let mut trace_events = self
+            .contracts
+            .values()
+            .flat_map(|contract| contract.objects_ref())
+            .flat_map(|object| object.trace_events.iter())
+            .chain(self.trace_events.iter())
+            .collect::<Vec<&solx_codegen_evm::TraceEvent>>();
+        trace_events.sort_by_key(|event| {
+            (
+                event.pid,
+                event.tid,
+                event.timestamp,
+                std::cmp::Reverse(event.duration),
+            )
+        });
+
+        let trace = serde_json::json!({
+            "traceEvents": trace_events,
+            "displayTimeUnit": "ms",
+        });
+        std::fs::write(path, trace.to_string())
+            .map_err(|error| anyhow::anyhow!("File {path:?} writing: {error}"))?;
+        Ok(())
     }
 
     ///
@@ -186,7 +234,7 @@ impl Build {
         }
 
         for contract in self.contracts.into_values() {
-            contract.write_to_terminal(output_selection)?;
+            contract.write_to_terminal(output_selection, self.remarks_format)?;
         }
 
         Ok(())
@@ -204,6 +252,83 @@ impl Build {
         self.take_and_write_warnings();
         self.exit_on_error();
 
+        self.write_artifacts(output_directory, output_selection, overwrite)?;
+
+        writeln!(
+            std::io::stderr(),
+            "Compiler run successful. Artifact(s) can be found in directory {output_directory:?}."
+        )?;
+        Ok(())
+    }
+
+    ///
+    /// Writes all contracts to the specified directory atomically, replacing `previous_artifacts`.
+    ///
+    /// The artifacts are written to a staging directory first, and then renamed into the output
+    /// directory one by one. Thus, a failed build never leaves the directory half-updated, and
+    /// readers never observe a partially written file. The previous artifacts that are not produced
+    /// anymore are removed, whereas other files in the directory are left intact.
+    ///
+    /// Returns the paths to the written artifacts.
+    ///
+    pub fn write_to_directory_atomically(
+        mut self,
+        output_directory: &Path,
+        output_selection: &solx_standard_json::InputSelection,
+        overwrite: bool,
+        previous_artifacts: &BTreeSet<PathBuf>,
+    ) -> anyhow::Result<BTreeSet<PathBuf>> {
+        self.take_and_write_warnings();
+        self.check_errors()?;
+
+        let staging_directory = output_directory.join(Self::STAGING_DIRECTORY_NAME);
+        if staging_directory.exists() {
+            std::fs::remove_dir_all(staging_directory.as_path())?;
+        }
+        self.write_artifacts(staging_directory.as_path(), output_selection, true)?;
+
+        let mut artifacts = BTreeSet::new();
+        for entry in std::fs::read_dir(staging_directory.as_path())? {
+            let entry = entry?;
+            let output_path = output_directory.join(entry.file_name());
+            if output_path.exists() && !overwrite && !previous_artifacts.contains(&output_path) {
+                std::fs::remove_dir_all(staging_directory.as_path())?;
+                anyhow::bail!(
+                    "Refusing to overwrite an existing file {output_path:?} (use --overwrite to force)."
+                );
+            }
+            artifacts.insert(output_path);
+        }
+        for output_path in artifacts.iter() {
+            let staging_path =
+                staging_directory.join(output_path.file_name().expect("Always exists"));
+            std::fs::rename(staging_path.as_path(), output_path.as_path())
+                .map_err(|error| anyhow::anyhow!("File {output_path:?} writing: {error}"))?;
+        }
+        std::fs::remove_dir_all(staging_directory.as_path())?;
+        for output_path in previous_artifacts.difference(&artifacts) {
+            if output_path.exists() {
+                std::fs::remove_file(output_path.as_path())
+                    .map_err(|error| anyhow::anyhow!("File {output_path:?} removing: {error}"))?;
+            }
+        }
+
+        writeln!(
+            std::io::stderr(),
+            "Compiler run successful. Artifact(s) can be found in directory {output_directory:?}."
+        )?;
+        Ok(artifacts)
+    }
+
+    ///
+    /// Writes all contracts to the specified directory.
+    ///
+    fn write_artifacts(
+        self,
+        output_directory: &Path,
+        output_selection: &solx_standard_json::InputSelection,
+        overwrite: bool,
+    ) -> anyhow::Result<()> {
         std::fs::create_dir_all(output_directory)?;
 
         for (path, ast_json) in self.ast_jsons.into_iter().flatten() {
@@ -251,13 +376,14 @@ impl Build {
         }
 
         for contract in self.contracts.into_values() {
-            contract.write_to_directory(output_directory, output_selection, overwrite)?;
+            contract.write_to_directory(
+                output_directory,
+                output_selection,
+                self.remarks_format,
+                overwrite,
+            )?;
         }
 
-        writeln!(
-            std::io::stderr(),
-            "Compiler run successful. Artifact(s) can be found in directory {output_directory:?}."
-        )?;
         Ok(())
     }
 
@@ -269,6 +395,7 @@ impl Build {
         standard_json: &mut solx_standard_json::Output,
         output_selection: &solx_standard_json::InputSelection,
         is_bytecode_linked: bool,
+        evm_version: solx_utils::EVMVersion,
         benchmarks: Vec<(String, u64)>,
     ) -> anyhow::Result<()> {
         for (path, ast_json) in self.ast_jsons.iter_mut().flatten() {
@@ -323,32 +450,48 @@ impl Build {
 
             let name = contract.name.clone();
 
-            match standard_json
+            let result = match standard_json
                 .contracts
                 .get_mut(name.path.as_str())
                 .and_then(|contracts| {
                     contracts.get_mut(name.name.as_deref().unwrap_or(name.path.as_str()))
                 }) {
-                Some(standard_json_contract) => {
-                    contract.write_to_standard_json(
-                        standard_json_contract,
-                        output_selection,
-                        is_bytecode_linked,
-                    );
-                }
+                Some(standard_json_contract) => contract.write_to_standard_json(
+                    standard_json_contract,
+                    output_selection,
+                    is_bytecode_linked,
+                    evm_version,
+                ),
                 None => {
                     let contracts = standard_json
                         .contracts
                         .entry(name.path.clone())
                         .or_default();
                     let mut standard_json_contract = solx_standard_json::OutputContract::default();
-                    contract.write_to_standard_json(
+                    let result = contract.write_to_standard_json(
                         &mut standard_json_contract,
                         output_selection,
                         is_bytecode_linked,
+                        evm_version,
+                    );
+                    contracts.insert(
+                        name.name.unwrap_or(name.path.clone()),
+                        standard_json_contract,
                     );
-                    contracts.insert(name.name.unwrap_or(name.path), standard_json_contract);
+                    result
                 }
+            };
+            if let Err(error) = result {
+                standard_json
+                    .errors
+                    .push(solx_standard_json::OutputError::new_error_with_data(
+                        None,
+                        error,
+                        Some(solx_standard_json::OutputErrorSourceLocation::new(
+                            name.path,
+                        )),
+                        None,
+                    ));
             }
         }
         standard_json
@@ -13,16 +13,27 @@ pub mod arguments;
 pub mod build;
 pub mod r#const;
 pub mod error;
+pub mod linker;
+pub mod lsp;
 pub mod process;
 pub mod project;
 pub mod solc;
+pub mod verifier;
+pub mod watcher;
 pub mod yul;
 
 pub use self::arguments::Arguments;
+pub use self::build::contract::diagnostic::Diagnostic as EVMContractDiagnostic;
 pub use self::build::contract::Contract as EVMContractBuild;
 pub use self::build::Build as EVMBuild;
 pub use self::error::stack_too_deep::StackTooDeep as StackTooDeepError;
 pub use self::error::Error;
+pub use self::linker::Linker;
+pub use self::lsp::Server as LspServer;
+pub use self::process::cache::Cache as EVMProcessCache;
+pub use self::process::executor::in_process::InProcess as EVMProcessInProcess;
+pub use self::process::executor::subprocess::Subprocess as EVMProcessSubprocess;
+pub use self::process::executor::Executor as EVMProcessExecutor;
 pub use self::process::input::Input as EVMProcessInput;
 pub use self::process::output::Output as EVMProcessOutput;
 pub use self::process::run as run_recursive;
@@ -31,12 +42,17 @@ pub use self::project::contract::Contract as ProjectContract;
 pub use self::project::Project;
 pub use self::r#const::*;
 pub use self::solc::Solc;
+pub use self::verifier::Verifier;
+pub use self::watcher::Watcher;
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 use std::io::Write;
+use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::time::SystemTime;
 
 use solx_standard_json::CollectableError;
 
@@ -82,6 +98,14 @@ pub fn main(
 
     let (input_files, remappings) = arguments.split_input_files_and_remappings()?;
 
+    if arguments.link {
+        return self::link_evm(input_files.as_slice(), arguments.libraries.as_slice());
+    }
+
+    if arguments.inspect_metadata {
+        return self::inspect_metadata_evm(input_files.as_slice());
+    }
+
     let mut optimizer_settings = match arguments.optimization {
         Some(mode) => solx_codegen_evm::OptimizerSettings::try_from_cli(mode)?,
         None if arguments.standard_json.is_none() => {
@@ -152,6 +176,14 @@ pub fn main(
     if arguments.output_benchmarks {
         selectors.insert(solx_standard_json::InputSelector::Benchmarks);
     }
+    if arguments.output_size {
+        selectors.insert(solx_standard_json::InputSelector::BytecodeSize);
+        selectors.insert(solx_standard_json::InputSelector::RuntimeBytecodeSize);
+    }
+    if arguments.output_remarks.is_some() {
+        selectors.insert(solx_standard_json::InputSelector::BytecodeRemarks);
+        selectors.insert(solx_standard_json::InputSelector::RuntimeBytecodeRemarks);
+    }
     let output_selection = solx_standard_json::InputSelection::new(selectors);
 
     let llvm_options: Vec<String> = arguments
@@ -180,38 +212,54 @@ pub fn main(
         None => None,
     };
 
+    let code_size_limits = solx_utils::CodeSizeLimits::new(
+        arguments
+            .deploy_code_size_limit
+            .unwrap_or(solx_utils::CodeSizeLimits::DEFAULT_DEPLOY),
+        arguments
+            .runtime_code_size_limit
+            .unwrap_or(solx_utils::CodeSizeLimits::DEFAULT_RUNTIME),
+        arguments.code_size_overrun_error,
+    );
+
     let metadata_hash_type = arguments
         .metadata_hash
         .unwrap_or(solx_utils::MetadataHashType::IPFS);
     let append_cbor = !arguments.no_cbor_metadata;
     let use_import_callback = !arguments.no_import_callback;
+    let cache = match arguments.cache_dir.as_deref() {
+        Some(cache_dir) => Some(EVMProcessCache::try_new(cache_dir)?),
+        None if arguments.watch => Some(EVMProcessCache::new_in_memory()),
+        None => None,
+    };
 
-    let build = if arguments.yul {
-        self::yul_to_evm(
+    if arguments.lsp {
+        let optimizer = solx_standard_json::InputOptimizer::new(
+            arguments.optimization.unwrap_or(
+                solx_standard_json::InputOptimizer::default_mode().expect("Always exists"),
+            ),
+            arguments.size_fallback,
+        );
+        let server = LspServer::new(
             solc,
-            input_files.as_slice(),
-            arguments.libraries.as_slice(),
-            &output_selection,
-            messages,
-            metadata_hash_type,
-            append_cbor,
-            optimizer_settings,
-            llvm_options,
-            debug_config,
-        )
-    } else if arguments.llvm_ir {
-        self::llvm_ir_to_evm(
-            input_files.as_slice(),
-            arguments.libraries.as_slice(),
-            &output_selection,
-            messages,
-            metadata_hash_type,
-            append_cbor,
-            optimizer_settings,
+            EVMProcessSubprocess::default(),
+            remappings,
+            optimizer,
+            arguments.evm_version,
+            arguments.via_ir,
             llvm_options,
+            code_size_limits,
+            arguments.cache_dir,
+            arguments.base_path,
+            arguments.include_path,
+            arguments.allow_paths,
+            use_import_callback,
             debug_config,
-        )
-    } else if let Some(standard_json) = arguments.standard_json {
+        );
+        return server.run();
+    }
+
+    if let Some(standard_json) = arguments.standard_json {
         return self::standard_json_evm(
             solc,
             standard_json.map(PathBuf::from),
@@ -222,28 +270,123 @@ pub fn main(
             use_import_callback,
             debug_config,
         );
-    } else {
-        self::standard_output_evm(
-            solc,
-            input_files.as_slice(),
-            arguments.libraries.as_slice(),
-            &output_selection,
-            messages,
-            arguments.evm_version,
-            arguments.via_ir,
+    }
+
+    if arguments.verify {
+        let [metadata_path, bytecode_path] = input_files.as_slice() else {
+            anyhow::bail!("Verification mode requires the metadata JSON and the bytecode files.");
+        };
+        return self::verify_evm(
+            &solc,
+            metadata_path.as_path(),
+            bytecode_path.as_path(),
             metadata_hash_type,
-            arguments.metadata_literal,
             append_cbor,
             arguments.base_path,
             arguments.include_path,
             arguments.allow_paths,
-            use_import_callback,
-            remappings,
-            optimizer_settings,
-            llvm_options,
+            arguments.cache_dir,
             debug_config,
-        )
-    }?;
+        );
+    }
+
+    let build_evm =
+        |messages: Arc<Mutex<Vec<solx_standard_json::OutputError>>>| -> anyhow::Result<EVMBuild> {
+            let mut build = if arguments.yul {
+                self::yul_to_evm(
+                    &solc,
+                    input_files.as_slice(),
+                    arguments.libraries.as_slice(),
+                    &output_selection,
+                    messages,
+                    arguments.evm_version,
+                    metadata_hash_type,
+                    append_cbor,
+                    optimizer_settings.clone(),
+                    llvm_options.clone(),
+                    code_size_limits,
+                    debug_config.clone(),
+                    arguments.output_trace.as_deref(),
+                    cache.as_ref(),
+                )
+            } else if arguments.llvm_ir {
+                self::llvm_ir_to_evm(
+                    input_files.as_slice(),
+                    arguments.libraries.as_slice(),
+                    &output_selection,
+                    messages,
+                    arguments.evm_version,
+                    metadata_hash_type,
+                    append_cbor,
+                    optimizer_settings.clone(),
+                    llvm_options.clone(),
+                    code_size_limits,
+                    debug_config.clone(),
+                    arguments.output_trace.as_deref(),
+                    cache.as_ref(),
+                )
+            } else if arguments.evmla {
+                self::evmla_to_evm(
+                    &solc,
+                    input_files.as_slice(),
+                    arguments.libraries.as_slice(),
+                    &output_selection,
+                    messages,
+                    arguments.evm_version,
+                    metadata_hash_type,
+                    append_cbor,
+                    optimizer_settings.clone(),
+                    llvm_options.clone(),
+                    code_size_limits,
+                    debug_config.clone(),
+                    arguments.output_trace.as_deref(),
+                    cache.as_ref(),
+                )
+            } else {
+                self::standard_output_evm(
+                    &solc,
+                    input_files.as_slice(),
+                    arguments.libraries.as_slice(),
+                    &output_selection,
+                    messages,
+                    arguments.evm_version,
+                    arguments.via_ir,
+                    metadata_hash_type,
+                    arguments.metadata_literal,
+                    append_cbor,
+                    arguments.base_path.clone(),
+                    arguments.include_path.clone(),
+                    arguments.allow_paths.clone(),
+                    use_import_callback,
+                    remappings.clone(),
+                    optimizer_settings.clone(),
+                    llvm_options.clone(),
+                    code_size_limits,
+                    debug_config.clone(),
+                    arguments.output_trace.as_deref(),
+                    cache.as_ref(),
+                )
+            }?;
+            build.remarks_format = arguments.output_remarks.unwrap_or_default();
+            if let Some(ref cache) = cache {
+                cache.evict_unused();
+            }
+            Ok(build)
+        };
+
+    if arguments.watch {
+        return self::watch_evm(
+            build_evm,
+            input_files.as_slice(),
+            arguments.output_dir.as_deref().expect("Always exists"),
+            &output_selection,
+            arguments.overwrite,
+            arguments.base_path.as_deref(),
+            arguments.include_path.as_slice(),
+        );
+    }
+
+    let build = build_evm(messages)?;
 
     if output_selection.is_empty() {
         writeln!(
@@ -253,8 +396,8 @@ pub fn main(
         return Ok(());
     }
 
-    if let Some(output_directory) = arguments.output_dir {
-        build.write_to_directory(&output_directory, &output_selection, arguments.overwrite)?;
+    if let Some(ref output_directory) = arguments.output_dir {
+        build.write_to_directory(output_directory, &output_selection, arguments.overwrite)?;
     } else {
         build.write_to_terminal(&output_selection)?;
     }
@@ -262,26 +405,270 @@ pub fn main(
     Ok(())
 }
 
+///
+/// Runs the linker mode for the EVM target.
+///
+/// Links the bytecode files at `paths` in place, reporting the unresolved libraries.
+///
+pub fn link_evm(paths: &[PathBuf], libraries: &[String]) -> anyhow::Result<()> {
+    let libraries = solx_utils::Libraries::try_from(libraries)?;
+    let linker_symbols = libraries.as_linker_symbols()?;
+    let linker = Linker::new(&linker_symbols);
+
+    for path in paths.iter() {
+        let unresolved = linker.link_file(path.as_path())?;
+        if !unresolved.is_empty() {
+            writeln!(
+                std::io::stdout(),
+                "{}: unresolved libraries: {}",
+                path.display(),
+                unresolved.into_iter().collect::<Vec<String>>().join(", "),
+            )?;
+        }
+    }
+    writeln!(std::io::stdout(), "Linking completed.")?;
+
+    Ok(())
+}
+
+///
+/// Runs the metadata inspection mode for the EVM target.
+///
+/// Decodes the CBOR metadata of the bytecode files at `paths`, and prints the compiler versions
+/// and the metadata hash of each file as JSON.
+///
+pub fn inspect_metadata_evm(paths: &[PathBuf]) -> anyhow::Result<()> {
+    let mut output = serde_json::Map::new();
+    for path in paths.iter() {
+        let bytecode = self::read_hex_bytecode(path.as_path())?;
+        let cbor = solx_utils::CBOR::from_bytecode(bytecode.as_slice())
+            .map_err(|error| anyhow::anyhow!("File {path:?} metadata decoding: {error}"))?;
+
+        let mut metadata = serde_json::Map::new();
+        for (name, version) in cbor.version_data.into_iter() {
+            metadata.insert(name, serde_json::Value::String(version.to_string()));
+        }
+        if let Some((r#type, hash)) = cbor.hash {
+            let hash = if r#type == solx_utils::MetadataHashType::IPFS.to_string() {
+                solx_utils::IPFSHash::try_from_bytes(hash)
+                    .map_err(|error| anyhow::anyhow!("File {path:?} metadata decoding: {error}"))?
+                    .as_base58_str()
+                    .to_owned()
+            } else {
+                hex::encode(hash)
+            };
+            metadata.insert(r#type, serde_json::Value::String(hash));
+        }
+        output.insert(
+            path.display().to_string(),
+            serde_json::Value::Object(metadata),
+        );
+    }
+    writeln!(
+        std::io::stdout(),
+        "{}",
+        serde_json::to_string_pretty(&output).expect("Always valid")
+    )?;
+
+    Ok(())
+}
+
+///
+/// Runs the verification mode for the EVM target.
+///
+/// Recompiles the contract from the metadata at `metadata_path`, and compares the result with the
+/// bytecode at `bytecode_path`, printing the differences. The sources that are not embedded into the
+/// metadata are read relative to `base_path` or the current directory, and then `include_paths`.
+///
+pub fn verify_evm(
+    solc: &impl Solc,
+    metadata_path: &Path,
+    bytecode_path: &Path,
+    metadata_hash_type: solx_utils::MetadataHashType,
+    append_cbor: bool,
+    base_path: Option<String>,
+    include_paths: Vec<String>,
+    allow_paths: Option<String>,
+    cache_dir: Option<PathBuf>,
+    debug_config: Option<solx_codegen_evm::DebugConfig>,
+) -> anyhow::Result<()> {
+    let verifier = Verifier::try_from_path(metadata_path)?;
+    let bytecode = self::read_hex_bytecode(bytecode_path)?;
+    for warning in verifier.version_warnings(solc.version()).into_iter() {
+        writeln!(std::io::stderr(), "Warning: {warning}")?;
+    }
+
+    let mut source_roots = vec![PathBuf::from(base_path.as_deref().unwrap_or("."))];
+    source_roots.extend(include_paths.iter().map(PathBuf::from));
+    let mut solc_input = verifier.to_standard_json_input(
+        source_roots.as_slice(),
+        metadata_hash_type,
+        append_cbor,
+    )?;
+    solc_input.settings.cache_dir = cache_dir;
+
+    let solc_output = standard_json_evm_output(
+        solc,
+        solc_input,
+        Arc::new(Mutex::new(Vec::new())),
+        base_path,
+        include_paths,
+        allow_paths,
+        false,
+        debug_config,
+        &EVMProcessSubprocess::default(),
+    )?;
+    solc_output.check_errors()?;
+
+    let comparison = verifier.compare(&solc_output, bytecode)?;
+    write!(std::io::stdout(), "{comparison}")?;
+    if !comparison.is_match() {
+        anyhow::bail!("Verification failed.");
+    }
+
+    Ok(())
+}
+
+///
+/// Reads the hexadecimal bytecode at `path`, optionally prefixed with `0x`.
+///
+/// The library placeholders of unlinked bytecode are masked with zeros, as the library addresses
+/// are masked when the bytecode is compared.
+///
+fn read_hex_bytecode(path: &Path) -> anyhow::Result<Vec<u8>> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|error| anyhow::anyhow!("File {path:?} reading: {error}"))?;
+    let content = content.trim();
+    let content = Linker::mask_placeholders(content.strip_prefix("0x").unwrap_or(content));
+    hex::decode(content)
+        .map_err(|error| anyhow::anyhow!("File {path:?} hexadecimal decoding: {error}"))
+}
+
+///
+/// Runs the watch mode for the EVM target.
+///
+/// Rebuilds the project with `build_evm` every time the input files or the sources they import
+/// are changed, and updates the artifacts in `output_directory` atomically. The translation units
+/// are expected to be cached by `build_evm`, so only the contracts whose IR has changed are
+/// recompiled. Compilation errors are reported without stopping the watch.
+///
+/// The modification times are recorded before each build, so the changes made while the project
+/// is being built trigger another rebuild.
+///
+pub fn watch_evm(
+    build_evm: impl Fn(Arc<Mutex<Vec<solx_standard_json::OutputError>>>) -> anyhow::Result<EVMBuild>,
+    input_files: &[PathBuf],
+    output_directory: &Path,
+    output_selection: &solx_standard_json::InputSelection,
+    overwrite: bool,
+    base_path: Option<&str>,
+    include_paths: &[String],
+) -> anyhow::Result<()> {
+    let mut watched_paths = input_files.iter().cloned().collect::<BTreeSet<PathBuf>>();
+    let mut artifacts = BTreeSet::new();
+
+    loop {
+        let build_start = SystemTime::now();
+        let mut watcher = Watcher::new(watched_paths.iter().cloned());
+        let result = build_evm(Arc::new(Mutex::new(Vec::new()))).and_then(|build| {
+            watched_paths = input_files.iter().cloned().collect();
+            let source_paths = build
+                .ast_jsons
+                .iter()
+                .flat_map(|ast_jsons| ast_jsons.keys())
+                .chain(build.contracts.values().map(|contract| &contract.name.path));
+            for source_path in source_paths {
+                if let Some(path) = self::resolve_source_path(source_path, base_path, include_paths)
+                {
+                    watched_paths.insert(path);
+                }
+            }
+
+            if output_selection.is_empty() {
+                writeln!(
+                    std::io::stderr(),
+                    "Compiler run successful. No output generated."
+                )?;
+                return Ok(());
+            }
+            artifacts = build.write_to_directory_atomically(
+                output_directory,
+                output_selection,
+                overwrite,
+                &artifacts,
+            )?;
+            Ok(())
+        });
+        if let Err(error) = result {
+            writeln!(std::io::stderr(), "{error}")?;
+        }
+
+        watcher.update(watched_paths.iter().cloned(), build_start);
+        writeln!(
+            std::io::stderr(),
+            "Watching {} file(s) for changes...",
+            watcher.paths().count()
+        )?;
+        let changed_paths = watcher.wait();
+        writeln!(
+            std::io::stderr(),
+            "Changes detected in {}. Rebuilding...",
+            changed_paths
+                .iter()
+                .map(|path| format!("{path:?}"))
+                .collect::<Vec<String>>()
+                .join(", ")
+        )?;
+    }
+}
+
+///
+/// Resolves the source unit `path` to a file, the way the default import callback does.
+///
+/// Returns `None` if the file cannot be found, e.g. if the source was passed via stdin.
+///
+fn resolve_source_path(
+    path: &str,
+    base_path: Option<&str>,
+    include_paths: &[String],
+) -> Option<PathBuf> {
+    base_path
+        .into_iter()
+        .chain(include_paths.iter().map(String::as_str))
+        .map(|directory| Path::new(directory).join(path))
+        .chain(std::iter::once(PathBuf::from(path)))
+        .find(|path| path.is_file())
+}
+
 ///
 /// Runs the Yul mode for the EVM target.
 ///
 pub fn yul_to_evm(
-    solc: impl Solc,
+    solc: &impl Solc,
     paths: &[PathBuf],
     libraries: &[String],
     output_selection: &solx_standard_json::InputSelection,
     messages: Arc<Mutex<Vec<solx_standard_json::OutputError>>>,
+    evm_version: Option<solx_utils::EVMVersion>,
     metadata_hash_type: solx_utils::MetadataHashType,
     append_cbor: bool,
     optimizer_settings: solx_codegen_evm::OptimizerSettings,
     llvm_options: Vec<String>,
+    code_size_limits: solx_utils::CodeSizeLimits,
     debug_config: Option<solx_codegen_evm::DebugConfig>,
+    output_trace: Option<&Path>,
+    cache: Option<&EVMProcessCache>,
 ) -> anyhow::Result<EVMBuild> {
+    let mut profiler = solx_codegen_evm::Profiler::default();
+
     let libraries = solx_utils::Libraries::try_from(libraries)?;
     let linker_symbols = libraries.as_linker_symbols()?;
 
+    let run_solc_validate_yul = profiler.start_pipeline_element("solc_Yul_Validation");
     solc.validate_yul_paths(paths, libraries.clone())?;
+    run_solc_validate_yul.borrow_mut().finish();
 
+    let run_solx_yul_project = profiler.start_pipeline_element("solx_Yul_IR_Analysis");
     let project = Project::try_from_yul_paths(
         solc.version(),
         paths,
@@ -290,27 +677,24 @@ pub fn yul_to_evm(
         None,
         debug_config.as_ref(),
     )?;
+    run_solx_yul_project.borrow_mut().finish();
 
-    let mut build = project.compile_to_evm(
-        messages,
+    self::compile_project_to_evm(
+        project,
+        profiler,
+        linker_symbols,
         output_selection,
+        messages,
+        evm_version,
         metadata_hash_type,
         append_cbor,
         optimizer_settings,
         llvm_options,
+        code_size_limits,
         debug_config,
-    )?;
-    build.take_and_write_warnings();
-    build.check_errors()?;
-
-    Ok(if output_selection.is_bytecode_set_for_any() {
-        let mut build = build.link(linker_symbols);
-        build.take_and_write_warnings();
-        build.check_errors()?;
-        build
-    } else {
-        build
-    })
+        output_trace,
+        cache,
+    )
 }
 
 ///
@@ -321,17 +705,112 @@ pub fn llvm_ir_to_evm(
     libraries: &[String],
     output_selection: &solx_standard_json::InputSelection,
     messages: Arc<Mutex<Vec<solx_standard_json::OutputError>>>,
+    evm_version: Option<solx_utils::EVMVersion>,
     metadata_hash_type: solx_utils::MetadataHashType,
     append_cbor: bool,
     optimizer_settings: solx_codegen_evm::OptimizerSettings,
     llvm_options: Vec<String>,
+    code_size_limits: solx_utils::CodeSizeLimits,
     debug_config: Option<solx_codegen_evm::DebugConfig>,
+    output_trace: Option<&Path>,
+    cache: Option<&EVMProcessCache>,
 ) -> anyhow::Result<EVMBuild> {
+    let mut profiler = solx_codegen_evm::Profiler::default();
+
     let libraries = solx_utils::Libraries::try_from(libraries)?;
     let linker_symbols = libraries.as_linker_symbols()?;
 
+    let run_solx_llvm_ir_project = profiler.start_pipeline_element("solx_LLVM_IR_Analysis");
     let project = Project::try_from_llvm_ir_paths(paths, libraries, output_selection, None)?;
+    run_solx_llvm_ir_project.borrow_mut().finish();
 
+    self::compile_project_to_evm(
+        project,
+        profiler,
+        linker_symbols,
+        output_selection,
+        messages,
+        evm_version,
+        metadata_hash_type,
+        append_cbor,
+        optimizer_settings,
+        llvm_options,
+        code_size_limits,
+        debug_config,
+        output_trace,
+        cache,
+    )
+}
+
+///
+/// Runs the EVM legacy assembly mode for the EVM target.
+///
+pub fn evmla_to_evm(
+    solc: &impl Solc,
+    paths: &[PathBuf],
+    libraries: &[String],
+    output_selection: &solx_standard_json::InputSelection,
+    messages: Arc<Mutex<Vec<solx_standard_json::OutputError>>>,
+    evm_version: Option<solx_utils::EVMVersion>,
+    metadata_hash_type: solx_utils::MetadataHashType,
+    append_cbor: bool,
+    optimizer_settings: solx_codegen_evm::OptimizerSettings,
+    llvm_options: Vec<String>,
+    code_size_limits: solx_utils::CodeSizeLimits,
+    debug_config: Option<solx_codegen_evm::DebugConfig>,
+    output_trace: Option<&Path>,
+    cache: Option<&EVMProcessCache>,
+) -> anyhow::Result<EVMBuild> {
+    let mut profiler = solx_codegen_evm::Profiler::default();
+
+    let libraries = solx_utils::Libraries::try_from(libraries)?;
+    let linker_symbols = libraries.as_linker_symbols()?;
+
+    let run_solx_evmla_project = profiler.start_pipeline_element("solx_EVM_Assembly_Analysis");
+    let project =
+        Project::try_from_evmla_paths(solc.version(), paths, libraries, output_selection, None)?;
+    run_solx_evmla_project.borrow_mut().finish();
+
+    self::compile_project_to_evm(
+        project,
+        profiler,
+        linker_symbols,
+        output_selection,
+        messages,
+        evm_version,
+        metadata_hash_type,
+        append_cbor,
+        optimizer_settings,
+        llvm_options,
+        code_size_limits,
+        debug_config,
+        output_trace,
+        cache,
+    )
+}
+
+///
+/// Compiles and links the `project` built by one of the IR modes for the EVM target.
+///
+/// The `profiler` must already contain the IR analysis run, so the trace covers the whole pipeline.
+///
+fn compile_project_to_evm(
+    project: Project,
+    mut profiler: solx_codegen_evm::Profiler,
+    linker_symbols: BTreeMap<String, [u8; solx_utils::BYTE_LENGTH_ETH_ADDRESS]>,
+    output_selection: &solx_standard_json::InputSelection,
+    messages: Arc<Mutex<Vec<solx_standard_json::OutputError>>>,
+    evm_version: Option<solx_utils::EVMVersion>,
+    metadata_hash_type: solx_utils::MetadataHashType,
+    append_cbor: bool,
+    optimizer_settings: solx_codegen_evm::OptimizerSettings,
+    llvm_options: Vec<String>,
+    code_size_limits: solx_utils::CodeSizeLimits,
+    debug_config: Option<solx_codegen_evm::DebugConfig>,
+    output_trace: Option<&Path>,
+    cache: Option<&EVMProcessCache>,
+) -> anyhow::Result<EVMBuild> {
+    let run_solx_compile = profiler.start_pipeline_element("solx_Compilation");
     let mut build = project.compile_to_evm(
         messages,
         output_selection,
@@ -339,26 +818,40 @@ pub fn llvm_ir_to_evm(
         append_cbor,
         optimizer_settings,
         llvm_options,
+        &BTreeMap::new(),
+        code_size_limits,
+        evm_version.unwrap_or_default(),
         debug_config,
+        output_trace.is_some(),
+        &EVMProcessSubprocess::default(),
+        cache,
     )?;
+    run_solx_compile.borrow_mut().finish();
     build.take_and_write_warnings();
-    build.check_errors()?;
+    self::check_errors(&mut build, &profiler, output_trace)?;
 
-    Ok(if output_selection.is_bytecode_set_for_any() {
+    let mut build = if output_selection.is_bytecode_set_for_any() {
+        let run_solx_link = profiler.start_pipeline_element("solx_Linking");
         let mut build = build.link(linker_symbols);
+        run_solx_link.borrow_mut().finish();
         build.take_and_write_warnings();
-        build.check_errors()?;
+        self::check_errors(&mut build, &profiler, output_trace)?;
         build
     } else {
         build
-    })
+    };
+    build.trace_events.extend(profiler.to_trace_events());
+    if let Some(output_trace) = output_trace {
+        build.write_trace(output_trace)?;
+    }
+    Ok(build)
 }
 
 ///
 /// Runs the standard output mode for the EVM target.
 ///
 pub fn standard_output_evm(
-    solc: impl Solc,
+    solc: &impl Solc,
     paths: &[PathBuf],
     libraries: &[String],
     output_selection: &solx_standard_json::InputSelection,
@@ -375,7 +868,10 @@ pub fn standard_output_evm(
     remappings: BTreeSet<String>,
     optimizer_settings: solx_codegen_evm::OptimizerSettings,
     llvm_options: Vec<String>,
+    code_size_limits: solx_utils::CodeSizeLimits,
     debug_config: Option<solx_codegen_evm::DebugConfig>,
+    output_trace: Option<&Path>,
+    cache: Option<&EVMProcessCache>,
 ) -> anyhow::Result<EVMBuild> {
     let mut profiler = solx_codegen_evm::Profiler::default();
 
@@ -425,11 +921,17 @@ pub fn standard_output_evm(
         append_cbor,
         optimizer_settings.clone(),
         llvm_options,
+        &BTreeMap::new(),
+        code_size_limits,
+        evm_version.unwrap_or_default(),
         debug_config.clone(),
+        output_trace.is_some(),
+        &EVMProcessSubprocess::default(),
+        cache,
     )?;
     run_solx_compile.borrow_mut().finish();
     build.take_and_write_warnings();
-    build.check_errors()?;
+    self::check_errors(&mut build, &profiler, output_trace)?;
 
     let mut build = if solc_input
         .settings
@@ -440,15 +942,37 @@ pub fn standard_output_evm(
         let mut build = build.link(linker_symbols);
         run_solx_link.borrow_mut().finish();
         build.take_and_write_warnings();
-        build.check_errors()?;
+        self::check_errors(&mut build, &profiler, output_trace)?;
         build
     } else {
         build
     };
     build.benchmarks = profiler.to_vec();
+    build.trace_events.extend(profiler.to_trace_events());
+    if let Some(output_trace) = output_trace {
+        build.write_trace(output_trace)?;
+    }
     Ok(build)
 }
 
+///
+/// Checks `build` for errors.
+///
+/// If there are any, the trace is written to `output_trace` before they are returned, so the
+/// failing builds can be profiled as well.
+///
+fn check_errors(
+    build: &mut EVMBuild,
+    profiler: &solx_codegen_evm::Profiler,
+    output_trace: Option<&Path>,
+) -> anyhow::Result<()> {
+    if let (true, Some(output_trace)) = (build.has_errors(), output_trace) {
+        build.trace_events.extend(profiler.to_trace_events());
+        build.write_trace(output_trace)?;
+    }
+    build.check_errors()
+}
+
 ///
 /// Runs the standard JSON mode for the EVM target.
 ///
@@ -462,7 +986,66 @@ pub fn standard_json_evm(
     use_import_callback: bool,
     debug_config: Option<solx_codegen_evm::DebugConfig>,
 ) -> anyhow::Result<()> {
-    let mut solc_input = solx_standard_json::Input::try_from(json_path.as_deref())?;
+    let solc_input = solx_standard_json::Input::try_from(json_path.as_deref())?;
+    let solc_output = standard_json_evm_output(
+        &solc,
+        solc_input,
+        messages,
+        base_path,
+        include_paths,
+        allow_paths,
+        use_import_callback,
+        debug_config,
+        &EVMProcessSubprocess::default(),
+    )?;
+    solc_output.write_pruned_and_exit();
+}
+
+///
+/// Compiles the standard JSON `input` for the EVM target and returns the standard JSON output.
+///
+/// This is the high-level API for embedding the compiler as a library. The sources must be
+/// provided inline, as the import callback is disabled. The translation units are compiled by
+/// `executor`: `EVMProcessInProcess` compiles them without spawning subprocesses, whereas
+/// `EVMProcessSubprocess` isolates LLVM crashes in child processes.
+///
+/// Compilation errors and warnings are returned in the output. `Err` is only returned if the
+/// input cannot be processed at all, e.g. if its optimizer settings are invalid.
+///
+pub fn compile(
+    solc: &impl Solc,
+    input: solx_standard_json::Input,
+    executor: &dyn EVMProcessExecutor,
+) -> anyhow::Result<solx_standard_json::Output> {
+    solx_codegen_evm::initialize_target();
+
+    standard_json_evm_output(
+        solc,
+        input,
+        Arc::new(Mutex::new(Vec::new())),
+        None,
+        Vec::new(),
+        None,
+        false,
+        None,
+        executor,
+    )
+}
+
+///
+/// Compiles the standard JSON input for the EVM target and returns the pruned output.
+///
+fn standard_json_evm_output(
+    solc: &impl Solc,
+    mut solc_input: solx_standard_json::Input,
+    messages: Arc<Mutex<Vec<solx_standard_json::OutputError>>>,
+    base_path: Option<String>,
+    include_paths: Vec<String>,
+    allow_paths: Option<String>,
+    use_import_callback: bool,
+    debug_config: Option<solx_codegen_evm::DebugConfig>,
+    executor: &dyn EVMProcessExecutor,
+) -> anyhow::Result<solx_standard_json::Output> {
     let language = solc_input.language;
     let via_ir = solc_input.settings.via_ir;
     let linker_symbols = solc_input.settings.libraries.as_linker_symbols()?;
@@ -494,6 +1077,12 @@ pub fn standard_json_evm(
         optimizer_settings.enable_fallback_to_size();
     }
     let llvm_options = solc_input.settings.llvm_options.clone();
+    let cache = solc_input
+        .settings
+        .cache_dir
+        .as_deref()
+        .map(EVMProcessCache::try_new)
+        .transpose()?;
 
     let metadata_hash_type = solc_input.settings.metadata.bytecode_hash;
     let append_cbor = solc_input.settings.metadata.append_cbor;
@@ -512,7 +1101,8 @@ pub fn standard_json_evm(
             )?;
             run_solc_standard_json.borrow_mut().finish();
             if solc_output.has_errors() {
-                solc_output.write_and_exit(&solc_input.settings.output_selection);
+                solc_output.prune(&solc_input.settings.output_selection);
+                return Ok(solc_output);
             }
             messages
                 .lock()
@@ -529,7 +1119,8 @@ pub fn standard_json_evm(
             )?;
             run_solx_project.borrow_mut().finish();
             if solc_output.has_errors() {
-                solc_output.write_and_exit(&solc_input.settings.output_selection);
+                solc_output.prune(&solc_input.settings.output_selection);
+                return Ok(solc_output);
             }
 
             (solc_output, project)
@@ -539,7 +1130,8 @@ pub fn standard_json_evm(
             let mut solc_output = solc.validate_yul_standard_json(&mut solc_input)?;
             run_solc_validate_yul.borrow_mut().finish();
             if solc_output.has_errors() {
-                solc_output.write_and_exit(&solc_input.settings.output_selection);
+                solc_output.prune(&solc_input.settings.output_selection);
+                return Ok(solc_output);
             }
 
             let run_solx_yul_project = profiler.start_pipeline_element("solx_Yul_IR_Analysis");
@@ -553,7 +1145,8 @@ pub fn standard_json_evm(
             )?;
             run_solx_yul_project.borrow_mut().finish();
             if solc_output.has_errors() {
-                solc_output.write_and_exit(&solc_input.settings.output_selection);
+                solc_output.prune(&solc_input.settings.output_selection);
+                return Ok(solc_output);
             }
 
             (solc_output, project)
@@ -570,7 +1163,28 @@ pub fn standard_json_evm(
             )?;
             run_solx_llvm_ir_project.borrow_mut().finish();
             if solc_output.has_errors() {
-                solc_output.write_and_exit(&solc_input.settings.output_selection);
+                solc_output.prune(&solc_input.settings.output_selection);
+                return Ok(solc_output);
+            }
+
+            (solc_output, project)
+        }
+        solx_standard_json::InputLanguage::EVMAssembly => {
+            let mut solc_output = solx_standard_json::Output::new(&solc_input.sources);
+
+            let run_solx_evmla_project =
+                profiler.start_pipeline_element("solx_EVM_Assembly_Analysis");
+            let project = Project::try_from_evmla_sources(
+                solc.version(),
+                solc_input.sources,
+                solc_input.settings.libraries.clone(),
+                &solc_input.settings.output_selection,
+                Some(&mut solc_output),
+            )?;
+            run_solx_evmla_project.borrow_mut().finish();
+            if solc_output.has_errors() {
+                solc_output.prune(&solc_input.settings.output_selection);
+                return Ok(solc_output);
             }
 
             (solc_output, project)
@@ -585,7 +1199,13 @@ pub fn standard_json_evm(
         append_cbor,
         optimizer_settings.clone(),
         llvm_options,
+        &solc_input.settings.contract_overrides,
+        solc_input.settings.code_size_limits,
+        solc_input.settings.evm_version.unwrap_or_default(),
         debug_config.clone(),
+        false,
+        executor,
+        cache.as_ref(),
     )?;
     run_solx_compile.borrow_mut().finish();
     let output_selection = solc_input.settings.output_selection.clone();
@@ -594,9 +1214,11 @@ pub fn standard_json_evm(
             &mut solc_output,
             &solc_input.settings.output_selection,
             false,
+            solc_input.settings.evm_version.unwrap_or_default(),
             profiler.to_vec(),
         )?;
-        solc_output.write_and_exit(&solc_input.settings.output_selection);
+        solc_output.prune(&solc_input.settings.output_selection);
+        return Ok(solc_output);
     }
     let build = if output_selection.is_bytecode_set_for_any() {
         let run_solx_link = profiler.start_pipeline_element("solx_Linking");
@@ -606,6 +1228,13 @@ pub fn standard_json_evm(
     } else {
         build
     };
-    build.write_to_standard_json(&mut solc_output, &output_selection, true, profiler.to_vec())?;
-    solc_output.write_and_exit(&output_selection);
+    build.write_to_standard_json(
+        &mut solc_output,
+        &output_selection,
+        true,
+        solc_input.settings.evm_version.unwrap_or_default(),
+        profiler.to_vec(),
+    )?;
+    solc_output.prune(&output_selection);
+    Ok(solc_output)
 }
@@ -0,0 +1,194 @@
+//!
+//! The standalone bytecode linker.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use crate::build::contract::object::Object as EVMContractObject;
+
+///
+/// The standalone bytecode linker.
+///
+/// Replaces the `__$<hash>$__` library placeholders in already compiled bytecode.
+/// Since the final bytecode is not an ELF object anymore, the placeholders are patched as text,
+/// the same way as `solc --link` does.
+///
+#[derive(Debug)]
+pub struct Linker {
+    /// Library placeholders, mapped to the library symbols and hexadecimal addresses.
+    libraries: BTreeMap<String, (String, String)>,
+}
+
+impl Linker {
+    /// The library placeholder prefix.
+    pub const PLACEHOLDER_PREFIX: &'static str = "__$";
+
+    /// The library placeholder suffix.
+    pub const PLACEHOLDER_SUFFIX: &'static str = "$__";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        linker_symbols: &BTreeMap<String, [u8; solx_utils::BYTE_LENGTH_ETH_ADDRESS]>,
+    ) -> Self {
+        let libraries = linker_symbols
+            .iter()
+            .map(|(symbol, address)| {
+                (
+                    EVMContractObject::library_placeholder(symbol.as_str()),
+                    (symbol.to_owned(), hex::encode(address)),
+                )
+            })
+            .collect();
+        Self { libraries }
+    }
+
+    ///
+    /// Links the bytecode file at `path` in place.
+    ///
+    /// Files with the `json` extension are treated as `solc` standard JSON output.
+    /// Others are treated as hexadecimal bytecode.
+    ///
+    /// Returns the unresolved libraries.
+    ///
+    pub fn link_file(&self, path: &Path) -> anyhow::Result<BTreeSet<String>> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|error| anyhow::anyhow!("File {path:?} reading: {error}"))?;
+
+        let is_standard_json =
+            path.extension().and_then(|extension| extension.to_str()) == Some("json");
+        let (content, unresolved) = if is_standard_json {
+            let mut output: serde_json::Value = solx_utils::deserialize_from_str(content.as_str())
+                .map_err(|error| anyhow::anyhow!("File {path:?} parsing: {error}"))?;
+            let unresolved = self.link_standard_json(&mut output);
+            (
+                serde_json::to_string(&output).expect("Always valid"),
+                unresolved,
+            )
+        } else {
+            self.link_hex(content.as_str())
+        };
+
+        std::fs::write(path, content)
+            .map_err(|error| anyhow::anyhow!("File {path:?} writing: {error}"))?;
+        Ok(unresolved)
+    }
+
+    ///
+    /// Links the hexadecimal `bytecode`.
+    ///
+    /// Returns the linked bytecode and the unresolved library placeholders.
+    ///
+    pub fn link_hex(&self, bytecode: &str) -> (String, BTreeSet<String>) {
+        let mut bytecode = bytecode.to_owned();
+        for (placeholder, (_symbol, address)) in self.libraries.iter() {
+            bytecode = bytecode.replace(placeholder.as_str(), address.as_str());
+        }
+
+        let mut unresolved = BTreeSet::new();
+        let mut offset = 0;
+        while let Some(position) = bytecode[offset..].find(Self::PLACEHOLDER_PREFIX) {
+            let start = offset + position;
+            let end = start + solx_utils::BYTE_LENGTH_ETH_ADDRESS * 2;
+            match bytecode.get(start..end) {
+                Some(placeholder) if placeholder.ends_with(Self::PLACEHOLDER_SUFFIX) => {
+                    unresolved.insert(placeholder.to_owned());
+                    offset = end;
+                }
+                _ => offset = start + Self::PLACEHOLDER_PREFIX.len(),
+            }
+        }
+        (bytecode, unresolved)
+    }
+
+    ///
+    /// Replaces the library placeholders in the hexadecimal `bytecode` with zero addresses.
+    ///
+    pub fn mask_placeholders(bytecode: &str) -> String {
+        let (mut bytecode, unresolved) = Self::new(&BTreeMap::new()).link_hex(bytecode);
+        let zero_address = "0".repeat(solx_utils::BYTE_LENGTH_ETH_ADDRESS * 2);
+        for placeholder in unresolved.iter() {
+            bytecode = bytecode.replace(placeholder.as_str(), zero_address.as_str());
+        }
+        bytecode
+    }
+
+    ///
+    /// Links the bytecode of all contracts in the `solc` standard JSON `output`.
+    ///
+    /// The link references of the resolved libraries are removed.
+    ///
+    /// Returns the unresolved libraries.
+    ///
+    pub fn link_standard_json(&self, output: &mut serde_json::Value) -> BTreeSet<String> {
+        let mut unresolved = BTreeSet::new();
+
+        let files = match output
+            .get_mut("contracts")
+            .and_then(|contracts| contracts.as_object_mut())
+        {
+            Some(files) => files,
+            None => return unresolved,
+        };
+        for contract in files
+            .values_mut()
+            .filter_map(|file| file.as_object_mut())
+            .flat_map(|file| file.values_mut())
+        {
+            for field in ["bytecode", "deployedBytecode"] {
+                let bytecode = match contract
+                    .get_mut("evm")
+                    .and_then(|evm| evm.get_mut(field))
+                    .and_then(|bytecode| bytecode.as_object_mut())
+                {
+                    Some(bytecode) => bytecode,
+                    None => continue,
+                };
+
+                if let Some(object) = bytecode.get_mut("object") {
+                    if let Some(hex) = object.as_str() {
+                        let (hex, placeholders) = self.link_hex(hex);
+                        *object = serde_json::Value::String(hex);
+                        unresolved.extend(placeholders);
+                    }
+                }
+
+                if let Some(link_references) = bytecode
+                    .get_mut("linkReferences")
+                    .and_then(|link_references| link_references.as_object_mut())
+                {
+                    for (path, libraries) in link_references.iter_mut() {
+                        if let Some(libraries) = libraries.as_object_mut() {
+                            libraries.retain(|name, _references| {
+                                let symbol = format!("{path}:{name}");
+                                !self
+                                    .libraries
+                                    .values()
+                                    .any(|(library, _address)| library == &symbol)
+                            });
+                        }
+                    }
+                    link_references.retain(|_path, libraries| {
+                        libraries
+                            .as_object()
+                            .is_some_and(|libraries| !libraries.is_empty())
+                    });
+
+                    for (path, libraries) in link_references.iter() {
+                        for name in libraries.as_object().expect("Always exists").keys() {
+                            let symbol = format!("{path}:{name}");
+                            unresolved.remove(
+                                EVMContractObject::library_placeholder(symbol.as_str()).as_str(),
+                            );
+                            unresolved.insert(symbol);
+                        }
+                    }
+                }
+            }
+        }
+        unresolved
+    }
+}
@@ -0,0 +1,119 @@
+//!
+//! The Language Server Protocol text document.
+//!
+
+use std::path::Path;
+
+use super::position::Position;
+use super::range::Range;
+
+///
+/// The Language Server Protocol text document.
+///
+#[derive(Debug, Clone)]
+pub struct Document {
+    /// The document text.
+    pub text: String,
+}
+
+impl Document {
+    /// The file URI scheme prefix.
+    pub const FILE_URI_PREFIX: &'static str = "file://";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(text: String) -> Self {
+        Self { text }
+    }
+
+    ///
+    /// Reads the document from the file at `path`.
+    ///
+    pub fn try_from_path(path: &Path) -> anyhow::Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|error| anyhow::anyhow!("File {path:?} reading: {error}"))?;
+        Ok(Self::new(text))
+    }
+
+    ///
+    /// Converts the byte `offset` into a position.
+    ///
+    /// The offset is clamped to the document length.
+    ///
+    pub fn position(&self, offset: usize) -> Position {
+        let mut offset = offset.min(self.text.len());
+        while !self.text.is_char_boundary(offset) {
+            offset -= 1;
+        }
+
+        let prefix = &self.text[..offset];
+        let line = prefix.matches('\n').count();
+        let line_start = prefix
+            .rfind('\n')
+            .map(|index| index + 1)
+            .unwrap_or_default();
+        let character = prefix[line_start..].encode_utf16().count();
+        Position::new(line, character)
+    }
+
+    ///
+    /// Converts the `solc` source location byte offsets into a range.
+    ///
+    /// Negative offsets mean that the location is unknown, so the first line is used.
+    ///
+    pub fn range(&self, start: isize, end: isize) -> Range {
+        if start < 0 {
+            return Range::default();
+        }
+
+        let start = self.position(start as usize);
+        let end = if end < 0 {
+            start
+        } else {
+            self.position(end as usize)
+        };
+        Range::new(start, end)
+    }
+
+    ///
+    /// Converts the file `uri` into a path.
+    ///
+    /// Returns `None` for other URI schemes.
+    ///
+    pub fn path_from_uri(uri: &str) -> Option<String> {
+        let path = uri.strip_prefix(Self::FILE_URI_PREFIX)?;
+
+        let mut bytes = Vec::with_capacity(path.len());
+        let mut iterator = path.bytes();
+        while let Some(byte) = iterator.next() {
+            if byte == b'%' {
+                let high = iterator.next()?;
+                let low = iterator.next()?;
+                let hex = [high, low];
+                let hex = std::str::from_utf8(hex.as_slice()).ok()?;
+                bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            } else {
+                bytes.push(byte);
+            }
+        }
+        String::from_utf8(bytes).ok()
+    }
+
+    ///
+    /// Converts the file `path` into a URI.
+    ///
+    pub fn uri_from_path(path: &str) -> String {
+        let mut uri = String::with_capacity(Self::FILE_URI_PREFIX.len() + path.len());
+        uri.push_str(Self::FILE_URI_PREFIX);
+        for byte in path.bytes() {
+            match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'/' | b'-' | b'_' | b'.' | b'~' => {
+                    uri.push(byte as char)
+                }
+                byte => uri.push_str(format!("%{byte:02X}").as_str()),
+            }
+        }
+        uri
+    }
+}
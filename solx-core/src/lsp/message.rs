@@ -0,0 +1,157 @@
+//!
+//! The Language Server Protocol message.
+//!
+
+use std::io::BufRead;
+use std::io::Read;
+use std::io::Write;
+
+///
+/// The Language Server Protocol message.
+///
+/// Requests, responses, and notifications share the JSON-RPC 2.0 envelope, and are told apart
+/// by the presence of `id` and `method`.
+///
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct Message {
+    /// The JSON-RPC version.
+    pub jsonrpc: String,
+    /// The request ID. Unset for notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<serde_json::Value>,
+    /// The method name. Unset for responses.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    /// The method parameters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub params: Option<serde_json::Value>,
+    /// The response result.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<serde_json::Value>,
+    /// The response error.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<serde_json::Value>,
+}
+
+impl Message {
+    /// The JSON-RPC version.
+    pub const JSONRPC_VERSION: &'static str = "2.0";
+
+    /// The message content length header.
+    pub const HEADER_CONTENT_LENGTH: &'static str = "Content-Length";
+
+    /// The JSON-RPC error code for unknown methods.
+    pub const ERROR_CODE_METHOD_NOT_FOUND: i64 = -32601;
+
+    /// The JSON-RPC error code for malformed method parameters.
+    pub const ERROR_CODE_INVALID_PARAMS: i64 = -32602;
+
+    ///
+    /// A shortcut constructor for a successful response.
+    ///
+    pub fn new_response(id: serde_json::Value, result: serde_json::Value) -> Self {
+        Self {
+            jsonrpc: Self::JSONRPC_VERSION.to_owned(),
+            id: Some(id),
+            method: None,
+            params: None,
+            result: Some(result),
+            error: None,
+        }
+    }
+
+    ///
+    /// A shortcut constructor for an error response.
+    ///
+    pub fn new_error_response(id: serde_json::Value, code: i64, message: String) -> Self {
+        Self {
+            jsonrpc: Self::JSONRPC_VERSION.to_owned(),
+            id: Some(id),
+            method: None,
+            params: None,
+            result: None,
+            error: Some(serde_json::json!({
+                "code": code,
+                "message": message,
+            })),
+        }
+    }
+
+    ///
+    /// A shortcut constructor for a notification.
+    ///
+    pub fn new_notification(method: &str, params: serde_json::Value) -> Self {
+        Self {
+            jsonrpc: Self::JSONRPC_VERSION.to_owned(),
+            id: None,
+            method: Some(method.to_owned()),
+            params: Some(params),
+            result: None,
+            error: None,
+        }
+    }
+
+    ///
+    /// Reads a message from `reader`.
+    ///
+    /// Returns `None` if the stream has been closed.
+    ///
+    pub fn read(reader: &mut impl BufRead) -> anyhow::Result<Option<Self>> {
+        let mut content_length = None;
+        loop {
+            let mut header = String::new();
+            if reader
+                .read_line(&mut header)
+                .map_err(|error| anyhow::anyhow!("LSP message header reading: {error}"))?
+                == 0
+            {
+                return Ok(None);
+            }
+
+            let header = header.trim_end();
+            if header.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = header.split_once(':') {
+                if name
+                    .trim()
+                    .eq_ignore_ascii_case(Self::HEADER_CONTENT_LENGTH)
+                {
+                    content_length = Some(value.trim().parse::<usize>().map_err(|error| {
+                        anyhow::anyhow!("LSP message content length parsing: {error}")
+                    })?);
+                }
+            }
+        }
+        let content_length = content_length.ok_or_else(|| {
+            anyhow::anyhow!(
+                "LSP message `{}` header is missing",
+                Self::HEADER_CONTENT_LENGTH
+            )
+        })?;
+
+        let mut buffer = vec![0u8; content_length];
+        reader
+            .read_exact(buffer.as_mut_slice())
+            .map_err(|error| anyhow::anyhow!("LSP message content reading: {error}"))?;
+        let message = serde_json::from_slice(buffer.as_slice())
+            .map_err(|error| anyhow::anyhow!("LSP message content parsing: {error}"))?;
+        Ok(Some(message))
+    }
+
+    ///
+    /// Writes the message to `writer`.
+    ///
+    pub fn write(&self, writer: &mut impl Write) -> anyhow::Result<()> {
+        let content = serde_json::to_vec(self).expect("Always valid");
+        write!(
+            writer,
+            "{}: {}\r\n\r\n",
+            Self::HEADER_CONTENT_LENGTH,
+            content.len()
+        )?;
+        writer.write_all(content.as_slice())?;
+        writer.flush()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,548 @@
+//!
+//! The Language Server Protocol server.
+//!
+
+pub mod document;
+pub mod message;
+pub mod params;
+pub mod position;
+pub mod range;
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use crate::process::executor::Executor as EVMProcessExecutor;
+use crate::solc::Solc;
+
+use self::document::Document;
+use self::message::Message;
+use self::params::DidChangeTextDocument;
+use self::params::DidOpenTextDocument;
+use self::params::TextDocument;
+use self::range::Range;
+
+///
+/// The Language Server Protocol server.
+///
+/// Compiles the open Solidity documents with the standard JSON pipeline on opening and saving,
+/// publishes the messages as diagnostics, and reports the bytecode sizes and multi-pass pipeline
+/// decisions of each contract as code lenses.
+///
+/// Malformed notifications are logged and skipped, and malformed requests are answered with
+/// an error, so a misbehaving client cannot shut the server down.
+///
+#[derive(Debug)]
+pub struct Server<S: Solc, E: EVMProcessExecutor> {
+    /// The `solc` compiler.
+    solc: S,
+    /// The translation unit executor.
+    executor: E,
+    /// The import remappings.
+    remappings: BTreeSet<String>,
+    /// The optimizer settings.
+    optimizer: solx_standard_json::InputOptimizer,
+    /// The EVM version.
+    evm_version: Option<solx_utils::EVMVersion>,
+    /// Whether to enable the `solc` IR codegen.
+    via_ir: bool,
+    /// The extra LLVM options.
+    llvm_options: Vec<String>,
+    /// The bytecode size limits.
+    code_size_limits: solx_utils::CodeSizeLimits,
+    /// The translation unit cache directory.
+    cache_dir: Option<PathBuf>,
+    /// The `solc` base path.
+    base_path: Option<String>,
+    /// The `solc` include paths.
+    include_paths: Vec<String>,
+    /// The `solc` allow paths.
+    allow_paths: Option<String>,
+    /// Whether to use the `solc` import callback.
+    use_import_callback: bool,
+    /// The debug output config.
+    debug_config: Option<solx_codegen_evm::DebugConfig>,
+
+    /// The open documents, mapped from their paths.
+    documents: BTreeMap<String, Document>,
+    /// The code lenses of the last compilation, mapped from the document paths.
+    code_lenses: BTreeMap<String, Vec<serde_json::Value>>,
+    /// The paths with diagnostics published by the last compilation.
+    published_paths: BTreeSet<String>,
+}
+
+impl<S: Solc, E: EVMProcessExecutor> Server<S, E> {
+    /// The Solidity language identifier.
+    pub const LANGUAGE_ID_SOLIDITY: &'static str = "solidity";
+
+    /// The diagnostic source name.
+    pub const DIAGNOSTIC_SOURCE: &'static str = "solx";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        solc: S,
+        executor: E,
+        remappings: BTreeSet<String>,
+        optimizer: solx_standard_json::InputOptimizer,
+        evm_version: Option<solx_utils::EVMVersion>,
+        via_ir: bool,
+        llvm_options: Vec<String>,
+        code_size_limits: solx_utils::CodeSizeLimits,
+        cache_dir: Option<PathBuf>,
+        base_path: Option<String>,
+        include_paths: Vec<String>,
+        allow_paths: Option<String>,
+        use_import_callback: bool,
+        debug_config: Option<solx_codegen_evm::DebugConfig>,
+    ) -> Self {
+        Self {
+            solc,
+            executor,
+            remappings,
+            optimizer,
+            evm_version,
+            via_ir,
+            llvm_options,
+            code_size_limits,
+            cache_dir,
+            base_path,
+            include_paths,
+            allow_paths,
+            use_import_callback,
+            debug_config,
+
+            documents: BTreeMap::new(),
+            code_lenses: BTreeMap::new(),
+            published_paths: BTreeSet::new(),
+        }
+    }
+
+    ///
+    /// Serves the client via `stdin` and `stdout` until the `exit` notification.
+    ///
+    pub fn run(mut self) -> anyhow::Result<()> {
+        let mut stdin = std::io::stdin().lock();
+        while let Some(message) = Message::read(&mut stdin)? {
+            let Some(method) = message.method.as_deref() else {
+                continue;
+            };
+            let params = message.params.unwrap_or_default();
+
+            let result = match method {
+                "initialize" => Some(Self::capabilities()),
+                "shutdown" => Some(serde_json::Value::Null),
+                "exit" => return Ok(()),
+                "textDocument/didOpen" => {
+                    let Some(params) =
+                        Self::params::<DidOpenTextDocument>(method, params, message.id.as_ref())?
+                    else {
+                        continue;
+                    };
+                    if params.text_document.language_id == Self::LANGUAGE_ID_SOLIDITY {
+                        if let Some(path) = Document::path_from_uri(&params.text_document.uri) {
+                            self.documents
+                                .insert(path, Document::new(params.text_document.text));
+                            self.compile()?;
+                        }
+                    }
+                    None
+                }
+                "textDocument/didChange" => {
+                    let Some(params) =
+                        Self::params::<DidChangeTextDocument>(method, params, message.id.as_ref())?
+                    else {
+                        continue;
+                    };
+                    if let (Some(document), Some(change)) = (
+                        Document::path_from_uri(&params.text_document.uri)
+                            .and_then(|path| self.documents.get_mut(path.as_str())),
+                        params.content_changes.into_iter().last(),
+                    ) {
+                        document.text = change.text;
+                    }
+                    None
+                }
+                "textDocument/didSave" => {
+                    let Some(params) =
+                        Self::params::<TextDocument>(method, params, message.id.as_ref())?
+                    else {
+                        continue;
+                    };
+                    if let Some(document) = Document::path_from_uri(&params.text_document.uri)
+                        .and_then(|path| self.documents.get_mut(path.as_str()))
+                    {
+                        if let Some(text) = params.text {
+                            document.text = text;
+                        }
+                        self.compile()?;
+                    }
+                    None
+                }
+                "textDocument/didClose" => {
+                    let Some(params) =
+                        Self::params::<TextDocument>(method, params, message.id.as_ref())?
+                    else {
+                        continue;
+                    };
+                    if let Some(path) = Document::path_from_uri(&params.text_document.uri) {
+                        self.documents.remove(path.as_str());
+                    }
+                    None
+                }
+                "textDocument/codeLens" => {
+                    let Some(params) =
+                        Self::params::<TextDocument>(method, params, message.id.as_ref())?
+                    else {
+                        continue;
+                    };
+                    let code_lenses = Document::path_from_uri(&params.text_document.uri)
+                        .and_then(|path| self.code_lenses.get(path.as_str()))
+                        .cloned()
+                        .unwrap_or_default();
+                    Some(serde_json::Value::Array(code_lenses))
+                }
+                _ => None,
+            };
+
+            if let Some(id) = message.id {
+                let response = match result {
+                    Some(result) => Message::new_response(id, result),
+                    None => Message::new_error_response(
+                        id,
+                        Message::ERROR_CODE_METHOD_NOT_FOUND,
+                        format!("Method `{method}` is not supported"),
+                    ),
+                };
+                response.write(&mut std::io::stdout())?;
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Parses the `params` of `method`.
+    ///
+    /// If they are malformed, the request with `id` is answered with an error, whereas
+    /// notifications are logged, and `None` is returned so the message is skipped.
+    ///
+    fn params<T: serde::de::DeserializeOwned>(
+        method: &str,
+        params: serde_json::Value,
+        id: Option<&serde_json::Value>,
+    ) -> anyhow::Result<Option<T>> {
+        let error = match serde_json::from_value(params) {
+            Ok(params) => return Ok(Some(params)),
+            Err(error) => format!("Method `{method}` parameters are invalid: {error}"),
+        };
+        let message = match id {
+            Some(id) => Message::new_error_response(
+                id.to_owned(),
+                Message::ERROR_CODE_INVALID_PARAMS,
+                error,
+            ),
+            None => Message::new_notification(
+                "window/logMessage",
+                serde_json::json!({
+                    "type": 1,
+                    "message": error,
+                }),
+            ),
+        };
+        message.write(&mut std::io::stdout())?;
+        Ok(None)
+    }
+
+    ///
+    /// Returns the server capabilities.
+    ///
+    fn capabilities() -> serde_json::Value {
+        serde_json::json!({
+            "capabilities": {
+                "textDocumentSync": {
+                    "openClose": true,
+                    "change": 1,
+                    "save": {
+                        "includeText": true,
+                    },
+                },
+                "codeLensProvider": {
+                    "resolveProvider": false,
+                },
+            },
+            "serverInfo": {
+                "name": crate::r#const::DEFAULT_EXECUTABLE_NAME,
+                "version": crate::r#const::version(),
+            },
+        })
+    }
+
+    ///
+    /// Compiles the open documents, publishes the diagnostics, and updates the code lenses.
+    ///
+    /// Failures outside of the standard JSON error reporting are shown to the user as messages.
+    ///
+    fn compile(&mut self) -> anyhow::Result<()> {
+        let output = match self.compile_standard_json() {
+            Ok(output) => output,
+            Err(error) => {
+                return Message::new_notification(
+                    "window/showMessage",
+                    serde_json::json!({
+                        "type": 1,
+                        "message": error.to_string(),
+                    }),
+                )
+                .write(&mut std::io::stdout());
+            }
+        };
+
+        let mut documents = self.documents.clone();
+        let contract_ranges = Self::contract_ranges(&output, &mut documents);
+
+        let mut diagnostics: BTreeMap<String, Vec<serde_json::Value>> = self
+            .documents
+            .keys()
+            .chain(self.published_paths.iter())
+            .map(|path| (path.to_owned(), Vec::new()))
+            .collect();
+        let mut code_lenses: BTreeMap<String, Vec<serde_json::Value>> = BTreeMap::new();
+        for error in output.errors.iter() {
+            let Some(location) = error.source_location.as_ref() else {
+                Message::new_notification(
+                    "window/logMessage",
+                    serde_json::json!({
+                        "type": Self::diagnostic_severity(error.severity.as_str()),
+                        "message": error.formatted_message,
+                    }),
+                )
+                .write(&mut std::io::stdout())?;
+                continue;
+            };
+
+            let (path, range) = match Self::document(&mut documents, location.file.as_str()) {
+                Some(document) => (
+                    location.file.to_owned(),
+                    document.range(location.start, location.end),
+                ),
+                None => match contract_ranges.get(location.file.as_str()) {
+                    Some((path, range)) => {
+                        code_lenses
+                            .entry(path.to_owned())
+                            .or_default()
+                            .push(Self::code_lens(*range, error.message.as_str()));
+                        (path.to_owned(), *range)
+                    }
+                    None => continue,
+                },
+            };
+            let mut diagnostic = serde_json::json!({
+                "range": range,
+                "severity": Self::diagnostic_severity(error.severity.as_str()),
+                "source": Self::DIAGNOSTIC_SOURCE,
+                "message": error.message,
+            });
+            if let Some(ref code) = error.error_code {
+                diagnostic["code"] = serde_json::Value::String(code.to_owned());
+            }
+            diagnostics.entry(path).or_default().push(diagnostic);
+        }
+
+        for (path, contracts) in output.contracts.iter() {
+            for (name, contract) in contracts.iter() {
+                let Some((_, range)) = contract_ranges.get(format!("{path}:{name}").as_str())
+                else {
+                    continue;
+                };
+                let Some(evm) = contract.evm.as_ref() else {
+                    continue;
+                };
+                for (code_segment, bytecode) in [
+                    (solx_utils::CodeSegment::Deploy, evm.bytecode.as_ref()),
+                    (
+                        solx_utils::CodeSegment::Runtime,
+                        evm.deployed_bytecode.as_ref(),
+                    ),
+                ] {
+                    if let Some(size) = bytecode.and_then(|bytecode| bytecode.size.as_ref()) {
+                        code_lenses
+                            .entry(path.to_owned())
+                            .or_default()
+                            .push(Self::code_lens(
+                                *range,
+                                format!("{code_segment} code: {size}").as_str(),
+                            ));
+                    }
+                }
+            }
+        }
+
+        self.published_paths.clear();
+        for (path, diagnostics) in diagnostics.into_iter() {
+            if !diagnostics.is_empty() {
+                self.published_paths.insert(path.clone());
+            }
+            Message::new_notification(
+                "textDocument/publishDiagnostics",
+                serde_json::json!({
+                    "uri": Document::uri_from_path(path.as_str()),
+                    "diagnostics": diagnostics,
+                }),
+            )
+            .write(&mut std::io::stdout())?;
+        }
+        self.code_lenses = code_lenses;
+
+        Ok(())
+    }
+
+    ///
+    /// Compiles the open documents with the standard JSON pipeline.
+    ///
+    fn compile_standard_json(&self) -> anyhow::Result<solx_standard_json::Output> {
+        let sources = self
+            .documents
+            .iter()
+            .map(|(path, document)| {
+                (
+                    path.to_owned(),
+                    solx_standard_json::InputSource::from(document.text.clone()),
+                )
+            })
+            .collect();
+        let output_selection = solx_standard_json::InputSelection::new(BTreeSet::from([
+            solx_standard_json::InputSelector::AST,
+            solx_standard_json::InputSelector::BytecodeSize,
+            solx_standard_json::InputSelector::RuntimeBytecodeSize,
+        ]));
+
+        let mut input = solx_standard_json::Input::try_from_solidity_sources(
+            sources,
+            solx_utils::Libraries::default(),
+            self.remappings.clone(),
+            self.optimizer.clone(),
+            self.evm_version,
+            self.via_ir,
+            &output_selection,
+            solx_standard_json::InputMetadata::default(),
+            self.llvm_options.clone(),
+        )?;
+        input.settings.code_size_limits = self.code_size_limits;
+        input.settings.cache_dir = self.cache_dir.clone();
+
+        crate::standard_json_evm_output(
+            &self.solc,
+            input,
+            Arc::new(Mutex::new(Vec::new())),
+            self.base_path.clone(),
+            self.include_paths.clone(),
+            self.allow_paths.clone(),
+            self.use_import_callback,
+            self.debug_config.clone(),
+            &self.executor,
+        )
+    }
+
+    ///
+    /// Returns the ranges of contract names from the AST, mapped from the full contract paths.
+    ///
+    /// The ranges are paired with the paths of the documents they belong to.
+    ///
+    fn contract_ranges(
+        output: &solx_standard_json::Output,
+        documents: &mut BTreeMap<String, Document>,
+    ) -> BTreeMap<String, (String, Range)> {
+        let mut ranges = BTreeMap::new();
+        for (path, source) in output.sources.iter() {
+            let Some(nodes) = source
+                .ast
+                .as_ref()
+                .and_then(|ast| ast.get("nodes"))
+                .and_then(|nodes| nodes.as_array())
+            else {
+                continue;
+            };
+            let Some(document) = Self::document(documents, path.as_str()) else {
+                continue;
+            };
+
+            for node in nodes.iter() {
+                if node.get("nodeType").and_then(|value| value.as_str())
+                    != Some("ContractDefinition")
+                {
+                    continue;
+                }
+                let Some(name) = node.get("name").and_then(|value| value.as_str()) else {
+                    continue;
+                };
+                let Some((start, length)) = node
+                    .get("nameLocation")
+                    .or_else(|| node.get("src"))
+                    .and_then(|value| value.as_str())
+                    .and_then(Self::parse_src)
+                else {
+                    continue;
+                };
+
+                ranges.insert(
+                    format!("{path}:{name}"),
+                    (path.to_owned(), document.range(start, start + length)),
+                );
+            }
+        }
+        ranges
+    }
+
+    ///
+    /// Returns the document at `path`, reading it from the file system if it is not open.
+    ///
+    fn document<'a>(
+        documents: &'a mut BTreeMap<String, Document>,
+        path: &str,
+    ) -> Option<&'a Document> {
+        if !documents.contains_key(path) {
+            let document = Document::try_from_path(Path::new(path)).ok()?;
+            documents.insert(path.to_owned(), document);
+        }
+        documents.get(path)
+    }
+
+    ///
+    /// Parses the `start` and `length` from the AST `src` location.
+    ///
+    fn parse_src(src: &str) -> Option<(isize, isize)> {
+        let mut parts = src.split(':');
+        let start = parts.next()?.parse().ok()?;
+        let length = parts.next()?.parse().ok()?;
+        Some((start, length))
+    }
+
+    ///
+    /// Converts the standard JSON message severity into the LSP diagnostic severity.
+    ///
+    fn diagnostic_severity(severity: &str) -> usize {
+        match severity {
+            "error" => 1,
+            "warning" => 2,
+            "info" => 3,
+            _ => 4,
+        }
+    }
+
+    ///
+    /// Creates a code lens without an action.
+    ///
+    fn code_lens(range: Range, title: &str) -> serde_json::Value {
+        serde_json::json!({
+            "range": range,
+            "command": {
+                "title": title,
+                "command": "",
+            },
+        })
+    }
+}
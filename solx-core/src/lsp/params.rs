@@ -0,0 +1,72 @@
+//!
+//! The Language Server Protocol notification and request parameters.
+//!
+
+///
+/// The text document identifier.
+///
+#[derive(Debug, serde::Deserialize)]
+pub struct TextDocumentIdentifier {
+    /// The document URI.
+    pub uri: String,
+}
+
+///
+/// The text document item transferred on opening.
+///
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextDocumentItem {
+    /// The document URI.
+    pub uri: String,
+    /// The document language identifier.
+    pub language_id: String,
+    /// The document text.
+    pub text: String,
+}
+
+///
+/// The text document content change.
+///
+/// Only full document synchronization is supported, so the change is the entire text.
+///
+#[derive(Debug, serde::Deserialize)]
+pub struct TextDocumentContentChange {
+    /// The new document text.
+    pub text: String,
+}
+
+///
+/// The `textDocument/didOpen` notification parameters.
+///
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidOpenTextDocument {
+    /// The opened document.
+    pub text_document: TextDocumentItem,
+}
+
+///
+/// The `textDocument/didChange` notification parameters.
+///
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidChangeTextDocument {
+    /// The changed document.
+    pub text_document: TextDocumentIdentifier,
+    /// The document content changes.
+    pub content_changes: Vec<TextDocumentContentChange>,
+}
+
+///
+/// The `textDocument/didSave`, `textDocument/didClose`, and `textDocument/codeLens` parameters.
+///
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextDocument {
+    /// The document.
+    pub text_document: TextDocumentIdentifier,
+    /// The document text, sent on saving if requested by the server.
+    #[serde(default)]
+    pub text: Option<String>,
+}
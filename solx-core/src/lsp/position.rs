@@ -0,0 +1,25 @@
+//!
+//! The Language Server Protocol text document position.
+//!
+
+///
+/// The Language Server Protocol text document position.
+///
+/// Both values are zero-based. The character is counted in UTF-16 code units.
+///
+#[derive(Debug, Default, Clone, Copy, serde::Serialize)]
+pub struct Position {
+    /// The line number.
+    pub line: usize,
+    /// The character offset within the line.
+    pub character: usize,
+}
+
+impl Position {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(line: usize, character: usize) -> Self {
+        Self { line, character }
+    }
+}
@@ -0,0 +1,25 @@
+//!
+//! The Language Server Protocol text document range.
+//!
+
+use super::position::Position;
+
+///
+/// The Language Server Protocol text document range.
+///
+#[derive(Debug, Default, Clone, Copy, serde::Serialize)]
+pub struct Range {
+    /// The inclusive start position.
+    pub start: Position,
+    /// The exclusive end position.
+    pub end: Position,
+}
+
+impl Range {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(start: Position, end: Position) -> Self {
+        Self { start, end }
+    }
+}
@@ -0,0 +1,169 @@
+//!
+//! Process for compiling a single compilation unit.
+//!
+//! The cache of the EVM output data.
+//!
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::RwLock;
+
+use super::input::Input as EVMInput;
+use super::output::Output as EVMOutput;
+
+///
+/// The cache of the EVM output data.
+///
+/// The entries are addressed by the hash of the serialized translation unit input, which includes
+/// the IR, optimizer settings, LLVM options, and metadata bytes. The `solx` version and LLVM
+/// revision are hashed as well, so upgrading the compiler invalidates all entries.
+///
+/// The cache is kept on disk if it has a directory, and in memory otherwise. The in-memory cache
+/// lives as long as the process, e.g. between the rebuilds in watch mode, where the entries
+/// not used by the last build are evicted with `evict_unused`.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Cache {
+    /// The cache directory, or `None` if the cache is kept in memory.
+    directory: Option<PathBuf>,
+    /// The in-memory entries, used if there is no cache directory.
+    entries: Arc<RwLock<HashMap<String, Vec<u8>>>>,
+    /// The keys of the in-memory entries read or written since the last eviction.
+    used_keys: Arc<Mutex<HashSet<String>>>,
+}
+
+impl Cache {
+    /// The cache entry file extension.
+    pub const EXTENSION: &'static str = "cbor";
+
+    ///
+    /// Opens the cache at `directory`, creating it if it does not exist.
+    ///
+    pub fn try_new(directory: &Path) -> anyhow::Result<Self> {
+        std::fs::create_dir_all(directory).map_err(|error| {
+            anyhow::anyhow!("Cache directory {directory:?} creating error: {error}")
+        })?;
+        Ok(Self {
+            directory: Some(directory.to_owned()),
+            entries: Arc::default(),
+            used_keys: Arc::default(),
+        })
+    }
+
+    ///
+    /// Creates an empty in-memory cache.
+    ///
+    pub fn new_in_memory() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// Returns the cache key of `input`.
+    ///
+    /// Returns `None` if the input must not be cached, e.g. if the IRs are dumped for debugging.
+    /// The trace flag is left out of the key, as it does not affect the compiled code.
+    ///
+    pub fn key(input: &mut EVMInput) -> Option<String> {
+        if input.debug_config.is_some() {
+            return None;
+        }
+
+        let is_trace_enabled = std::mem::take(&mut input.is_trace_enabled);
+        let mut buffer = Vec::with_capacity(crate::r#const::DEFAULT_SERDE_BUFFER_SIZE);
+        let result = ciborium::into_writer(input, &mut buffer);
+        input.is_trace_enabled = is_trace_enabled;
+        result.ok()?;
+        let hash = solx_utils::Keccak256Hash::from_slices(&[
+            crate::r#const::version().as_bytes(),
+            inkwell::support::get_commit_id().to_bytes(),
+            buffer.as_slice(),
+        ]);
+        Some(hex::encode(hash.as_bytes()))
+    }
+
+    ///
+    /// Returns the cached output for `key`, if any.
+    ///
+    /// Unreadable entries are treated as misses, so a corrupted cache never breaks compilation.
+    ///
+    pub fn get(&self, key: &str) -> Option<EVMOutput> {
+        let buffer = match self.directory.as_deref() {
+            Some(directory) => std::fs::read(Self::entry_path(directory, key)).ok()?,
+            None => {
+                let buffer = self.entries.read().expect("Sync").get(key)?.to_owned();
+                self.used_keys.lock().expect("Sync").insert(key.to_owned());
+                buffer
+            }
+        };
+        ciborium::de::from_reader_with_recursion_limit(buffer.as_slice(), usize::MAX).ok()
+    }
+
+    ///
+    /// Stores `output` for `key`.
+    ///
+    /// On disk, the entry is written to a temporary file first and then renamed, so concurrent
+    /// writers and readers never observe a partially written entry.
+    ///
+    pub fn put(&self, key: &str, output: &EVMOutput) -> anyhow::Result<()> {
+        let mut buffer = Vec::with_capacity(crate::r#const::DEFAULT_SERDE_BUFFER_SIZE);
+        ciborium::into_writer(output, &mut buffer)
+            .map_err(|error| anyhow::anyhow!("Cache entry serializing error: {error}"))?;
+
+        let directory = match self.directory.as_deref() {
+            Some(directory) => directory,
+            None => {
+                self.entries
+                    .write()
+                    .expect("Sync")
+                    .insert(key.to_owned(), buffer);
+                self.used_keys.lock().expect("Sync").insert(key.to_owned());
+                return Ok(());
+            }
+        };
+
+        let entry_path = Self::entry_path(directory, key);
+        let mut temporary_path = entry_path.clone();
+        temporary_path.set_extension(format!(
+            "{}.{}.{:?}",
+            Self::EXTENSION,
+            std::process::id(),
+            std::thread::current().id(),
+        ));
+        std::fs::write(temporary_path.as_path(), buffer.as_slice()).map_err(|error| {
+            anyhow::anyhow!("Cache entry {temporary_path:?} writing error: {error}")
+        })?;
+        std::fs::rename(temporary_path.as_path(), entry_path.as_path()).map_err(|error| {
+            anyhow::anyhow!("Cache entry {entry_path:?} writing error: {error}")
+        })?;
+        Ok(())
+    }
+
+    ///
+    /// Evicts the in-memory entries that have not been read or written since the last eviction.
+    ///
+    /// Called after each build in watch mode, so the memory is only used by the entries of
+    /// the latest build. The on-disk entries are never evicted, as the cache directory may be
+    /// shared by other processes.
+    ///
+    pub fn evict_unused(&self) {
+        let mut used_keys = self.used_keys.lock().expect("Sync");
+        self.entries
+            .write()
+            .expect("Sync")
+            .retain(|key, _| used_keys.contains(key));
+        used_keys.clear();
+    }
+
+    ///
+    /// Returns the path to the entry file for `key` in `directory`.
+    ///
+    fn entry_path(directory: &Path, key: &str) -> PathBuf {
+        let mut path = directory.join(key);
+        path.set_extension(Self::EXTENSION);
+        path
+    }
+}
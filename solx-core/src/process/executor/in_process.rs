@@ -0,0 +1,116 @@
+//!
+//! Process for compiling a single compilation unit.
+//!
+//! The in-process translation unit executor.
+//!
+
+use std::cell::Cell;
+use std::thread::Builder;
+
+use crate::error::Error;
+use crate::process::input::Input as EVMInput;
+use crate::process::output::Output as EVMOutput;
+
+use super::Executor;
+
+thread_local! {
+    /// Whether the current thread is an in-process worker thread.
+    static IS_WORKER_THREAD: Cell<bool> = const { Cell::new(false) };
+    /// The stack-too-deep error reported by LLVM on the current worker thread.
+    static STACK_TOO_DEEP_ERROR: Cell<Option<Error>> = const { Cell::new(None) };
+}
+
+///
+/// The in-process translation unit executor.
+///
+/// Compiles each translation unit on a dedicated worker thread with its own LLVM context,
+/// so the compiler can be linked as a library without spawning subprocesses.
+///
+/// LLVM command-line options, including the spill area and metadata sizes, are process-wide,
+/// so translation units requiring different options wait for each other, whereas the ones
+/// with the same options are compiled in parallel.
+///
+/// On worker threads, the stack-too-deep error handler only records the error and returns,
+/// so the backend fails the code generation with an error status. The recorded error then
+/// replaces the result of the translation unit.
+/// LLVM aborts terminate the whole process, so the subprocess executor must be used if crash
+/// isolation is required.
+///
+#[derive(Debug, Default, Clone)]
+pub struct InProcess;
+
+impl InProcess {
+    ///
+    /// Compiles `input` on the current thread, reporting the recorded stack-too-deep error.
+    ///
+    fn compile(input: EVMInput) -> crate::Result<EVMOutput> {
+        IS_WORKER_THREAD.set(true);
+        inkwell::context::Context::install_stack_error_handler(stack_error_handler);
+        let result = crate::process::compile(input);
+        match STACK_TOO_DEEP_ERROR.take() {
+            Some(error) => Err(error),
+            None => result,
+        }
+    }
+
+    ///
+    /// Returns the message of the worker thread panic `payload`.
+    ///
+    fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+        match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(payload) => payload
+                .downcast::<&'static str>()
+                .map(|message| (*message).to_owned())
+                .unwrap_or_else(|_| "unknown panic".to_owned()),
+        }
+    }
+}
+
+impl Executor for InProcess {
+    fn execute(&self, path: &str, input: &EVMInput) -> crate::Result<EVMOutput> {
+        let input = input.clone();
+
+        let worker = Builder::new()
+            .stack_size(crate::WORKER_THREAD_STACK_SIZE)
+            .spawn(move || Self::compile(input))
+            .expect("Threading error");
+
+        worker.join().unwrap_or_else(|payload| {
+            Err(solx_standard_json::OutputError::new_error_with_data(
+                None,
+                format!(
+                    "In-process compilation failed: {}",
+                    Self::panic_message(payload)
+                ),
+                Some(solx_standard_json::OutputErrorSourceLocation::new(
+                    path.to_owned(),
+                )),
+                None,
+            )
+            .into())
+        })
+    }
+}
+
+///
+/// Handles LLVM stack-too-deep errors on in-process worker threads.
+///
+/// Falls back to `crate::process::evm_stack_error_handler` on other threads.
+///
+/// # Safety
+///
+/// This function is unsafe because it is called from the LLVM stackifier.
+/// The function records the error for `InProcess::compile` and returns to LLVM, which fails the
+/// code generation through the C API, so no Rust unwinding crosses the LLVM frames.
+///
+pub unsafe extern "C" fn stack_error_handler(spill_area_size: u64) {
+    if !IS_WORKER_THREAD.get() {
+        return unsafe { crate::process::evm_stack_error_handler(spill_area_size) };
+    }
+
+    STACK_TOO_DEEP_ERROR.set(Some(Error::stack_too_deep(
+        spill_area_size,
+        solx_codegen_evm::IS_SIZE_FALLBACK.get(),
+    )));
+}
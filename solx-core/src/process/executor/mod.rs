@@ -0,0 +1,24 @@
+//!
+//! Process for compiling a single compilation unit.
+//!
+//! The translation unit executor.
+//!
+
+pub mod in_process;
+pub mod subprocess;
+
+use super::input::Input as EVMInput;
+use super::output::Output as EVMOutput;
+
+///
+/// The translation unit executor.
+///
+/// Decides where a single translation unit is compiled. The multi-pass pipeline calls it once per
+/// pass, so the stack-too-deep error must be returned as `Error::StackTooDeep` to trigger a retry.
+///
+pub trait Executor: Sync {
+    ///
+    /// Compiles the translation unit at `path` described by `input`.
+    ///
+    fn execute(&self, path: &str, input: &EVMInput) -> crate::Result<EVMOutput>;
+}
@@ -0,0 +1,54 @@
+//!
+//! Process for compiling a single compilation unit.
+//!
+//! The subprocess translation unit executor.
+//!
+
+use std::path::PathBuf;
+
+use crate::process::input::Input as EVMInput;
+use crate::process::output::Output as EVMOutput;
+
+use super::Executor;
+
+///
+/// The subprocess translation unit executor.
+///
+/// Runs the `solx` executable recursively with `--recursive-process` for each translation unit.
+/// LLVM crashes and aborts are isolated in the subprocess and reported as compilation errors.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Subprocess {
+    /// The `solx` executable path.
+    executable: Option<PathBuf>,
+}
+
+impl Subprocess {
+    ///
+    /// A shortcut constructor.
+    ///
+    /// If `executable` is not set, `crate::process::EXECUTABLE` is used if initialized,
+    /// and the current executable otherwise.
+    ///
+    pub fn new(executable: Option<PathBuf>) -> Self {
+        Self { executable }
+    }
+
+    ///
+    /// Returns the path to the executable to run.
+    ///
+    fn executable(&self) -> PathBuf {
+        self.executable
+            .clone()
+            .or_else(|| crate::process::EXECUTABLE.get().cloned())
+            .unwrap_or_else(|| {
+                std::env::current_exe().expect("Current executable path getting error")
+            })
+    }
+}
+
+impl Executor for Subprocess {
+    fn execute(&self, path: &str, input: &EVMInput) -> crate::Result<EVMOutput> {
+        crate::process::call(self.executable().as_path(), path, input)
+    }
+}
@@ -12,7 +12,7 @@ use crate::project::contract::ir::IR as ContractIR;
 ///
 /// The EVM input data.
 ///
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Input {
     /// The `solc` compiler version, used only for Solidity and Yul projects.
     pub solc_version: Option<solx_standard_json::Version>,
@@ -34,8 +34,14 @@ pub struct Input {
     pub optimizer_settings: solx_codegen_evm::OptimizerSettings,
     /// The extra LLVM arguments.
     pub llvm_options: Vec<String>,
+    /// The bytecode size limits.
+    pub code_size_limits: solx_utils::CodeSizeLimits,
+    /// The target EVM version.
+    pub evm_version: solx_utils::EVMVersion,
     /// The debug output config.
     pub debug_config: Option<solx_codegen_evm::DebugConfig>,
+    /// Whether the pipeline trace events are collected.
+    pub is_trace_enabled: bool,
 }
 
 impl Input {
@@ -53,7 +59,10 @@ impl Input {
         metadata_bytes: Option<Vec<u8>>,
         optimizer_settings: solx_codegen_evm::OptimizerSettings,
         llvm_options: Vec<String>,
+        code_size_limits: solx_utils::CodeSizeLimits,
+        evm_version: solx_utils::EVMVersion,
         debug_config: Option<solx_codegen_evm::DebugConfig>,
+        is_trace_enabled: bool,
     ) -> Self {
         Self {
             solc_version,
@@ -66,7 +75,10 @@ impl Input {
             metadata_bytes,
             optimizer_settings,
             llvm_options,
+            code_size_limits,
+            evm_version,
             debug_config,
+            is_trace_enabled,
         }
     }
 }
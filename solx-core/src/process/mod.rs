@@ -2,11 +2,14 @@
 //! Process for compiling a single compilation unit.
 //!
 
+pub mod cache;
+pub mod executor;
 pub mod input;
 pub mod output;
 
 use std::io::Read;
 use std::io::Write;
+use std::path::Path;
 use std::path::PathBuf;
 use std::process::Command;
 use std::sync::OnceLock;
@@ -40,36 +43,11 @@ pub fn run() -> anyhow::Result<()> {
         ciborium::de::from_reader_with_recursion_limit(buffer.as_slice(), usize::MAX)
             .map_err(|error| anyhow::anyhow!("Input deserialziing error: {error}"))?;
 
-    let source_location =
-        solx_standard_json::OutputErrorSourceLocation::new(input.contract_name.path.clone());
-
     let result = Builder::new()
         .stack_size(crate::WORKER_THREAD_STACK_SIZE)
         .spawn(move || {
-            Contract::compile_to_evm(
-                input.solc_version,
-                input.contract_name,
-                input.contract_ir,
-                input.code_segment,
-                input.identifier_paths,
-                input.output_selection,
-                input.immutables,
-                input.metadata_bytes,
-                input.optimizer_settings,
-                input.llvm_options,
-                input.debug_config,
-            )
-            .map(EVMOutput::new)
-            .map_err(|error| match error {
-                Error::Generic(error) => solx_standard_json::OutputError::new_error_with_data(
-                    None,
-                    error,
-                    Some(source_location),
-                    None,
-                )
-                .into(),
-                error => error,
-            })
+            inkwell::context::Context::install_stack_error_handler(evm_stack_error_handler);
+            compile(input)
         })
         .expect("Threading error")
         .join()
@@ -82,19 +60,53 @@ pub fn run() -> anyhow::Result<()> {
 }
 
 ///
-/// Runs this process recursively to compile a single contract.
+/// Compiles a single translation unit on the current thread.
+///
+/// The stack-too-deep error handler must be installed by the caller, as its behavior depends
+/// on the execution mode.
+///
+pub fn compile(input: EVMInput) -> crate::Result<EVMOutput> {
+    let source_location =
+        solx_standard_json::OutputErrorSourceLocation::new(input.contract_name.path.clone());
+
+    Contract::compile_to_evm(
+        input.solc_version,
+        input.contract_name,
+        input.contract_ir,
+        input.code_segment,
+        input.identifier_paths,
+        input.output_selection,
+        input.immutables,
+        input.metadata_bytes,
+        input.optimizer_settings,
+        input.llvm_options,
+        input.code_size_limits,
+        input.evm_version,
+        input.debug_config,
+        input.is_trace_enabled,
+    )
+    .map(EVMOutput::new)
+    .map_err(|error| match error {
+        Error::Generic(error) => solx_standard_json::OutputError::new_error_with_data(
+            None,
+            error,
+            Some(source_location),
+            None,
+        )
+        .into(),
+        error => error,
+    })
+}
+
+///
+/// Runs `executable` recursively to compile a single contract.
 ///
-pub fn call<I, O>(path: &str, input: &I) -> crate::Result<O>
+pub fn call<I, O>(executable: &Path, path: &str, input: &I) -> crate::Result<O>
 where
     I: serde::Serialize,
     O: serde::de::DeserializeOwned,
 {
-    let executable = EXECUTABLE
-        .get()
-        .cloned()
-        .unwrap_or_else(|| std::env::current_exe().expect("Current executable path getting error"));
-
-    let mut command = Command::new(executable.as_path());
+    let mut command = Command::new(executable);
     command.stdin(std::process::Stdio::piped());
     command.stdout(std::process::Stdio::piped());
     command.stderr(std::process::Stdio::piped());
@@ -169,7 +181,7 @@ where
 pub unsafe extern "C" fn evm_stack_error_handler(spill_area_size: u64) {
     let result: Result<EVMOutput, Error> = Err(Error::stack_too_deep(
         spill_area_size,
-        solx_codegen_evm::IS_SIZE_FALLBACK.load(std::sync::atomic::Ordering::Relaxed),
+        solx_codegen_evm::IS_SIZE_FALLBACK.get(),
     ));
     let mut buffer = Vec::with_capacity(crate::r#const::DEFAULT_SERDE_BUFFER_SIZE);
     ciborium::into_writer(&result, &mut buffer)
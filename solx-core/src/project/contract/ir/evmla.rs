@@ -5,7 +5,7 @@
 ///
 /// The contract EVM legacy assembly source code.
 ///
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct EVMLegacyAssembly {
     /// The EVM legacy assembly source code.
     pub assembly: solx_evm_assembly::Assembly,
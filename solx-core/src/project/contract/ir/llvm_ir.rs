@@ -5,7 +5,7 @@
 ///
 /// The contract LLVM IR source code.
 ///
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct LLVMIR {
     /// LLVM IR file path.
     pub path: String,
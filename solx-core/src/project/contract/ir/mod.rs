@@ -13,7 +13,7 @@ use self::yul::Yul;
 ///
 /// The contract source code.
 ///
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum IR {
     /// The Yul source code.
     Yul(Yul),
@@ -23,6 +23,27 @@ pub enum IR {
     LLVMIR(LLVMIR),
 }
 
+impl IR {
+    ///
+    /// Returns the estimated compilation cost of the IR.
+    ///
+    /// The number of source code lines or instructions is used, as it grows roughly linearly with
+    /// the amount of code to be optimized and emitted, and is available without traversing the IR.
+    ///
+    pub fn weight(&self) -> usize {
+        match self {
+            Self::Yul(inner) => inner.line_count,
+            Self::EVMLegacyAssembly(inner) => inner
+                .assembly
+                .code
+                .as_ref()
+                .map(Vec::len)
+                .unwrap_or_default(),
+            Self::LLVMIR(inner) => inner.source.lines().count(),
+        }
+    }
+}
+
 impl From<Yul> for IR {
     fn from(inner: Yul) -> Self {
         Self::Yul(inner)
@@ -2,6 +2,8 @@
 //! The contract Yul source code.
 //!
 
+use std::collections::BTreeMap;
+
 use solx_yul::yul::lexer::Lexer;
 use solx_yul::yul::parser::statement::object::Object;
 
@@ -10,7 +12,7 @@ use crate::yul::parser::wrapper::Wrap;
 ///
 /// The contract Yul source code.
 ///
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Yul {
     /// Yul AST object.
     pub object: crate::yul::parser::statement::object::Object,
@@ -18,6 +20,12 @@ pub struct Yul {
     pub dependencies: solx_yul::Dependencies,
     /// Runtime code object that is only set in deploy code.
     pub runtime_code: Option<Box<Self>>,
+    /// The `@src` annotations, mapped from the positions in the Yul source code.
+    pub source_locations: Vec<((usize, usize), solx_codegen_evm::SourceLocation)>,
+    /// The `@ast-id` annotations, mapped from the names of the Yul functions they precede.
+    pub function_ids: BTreeMap<String, usize>,
+    /// The number of source code lines of the object, excluding its runtime code object.
+    pub line_count: usize,
 }
 
 impl Yul {
@@ -37,16 +45,30 @@ impl Yul {
             debug_config.dump_yul(path, source_code)?;
         }
 
+        let source_locations = Self::source_locations(source_code);
+        let function_ids = Self::function_ids(source_code);
+
         let mut lexer = Lexer::new(source_code);
         let mut object = Object::parse(&mut lexer, None, solx_utils::CodeSegment::Deploy)
             .map_err(|error| anyhow::anyhow!("Yul parsing: {error:?}"))?;
 
+        let end_line = source_code.lines().count() + 1;
+        let runtime_code_line = object
+            .inner_object
+            .as_ref()
+            .map(|object| object.location.line)
+            .unwrap_or(end_line);
+        let line_count = runtime_code_line.saturating_sub(object.location.line);
+
         let runtime_code = object.inner_object.take().map(|object| {
             let dependencies = object.get_evm_dependencies(None);
             Self {
                 object: object.wrap(),
                 dependencies,
                 runtime_code: None,
+                source_locations: source_locations.clone(),
+                function_ids: function_ids.clone(),
+                line_count: end_line.saturating_sub(runtime_code_line),
             }
         });
         let dependencies = object.get_evm_dependencies(
@@ -59,6 +81,87 @@ impl Yul {
             object: object.wrap(),
             dependencies,
             runtime_code: runtime_code.map(Box::new),
+            source_locations,
+            function_ids,
+            line_count,
         }))
     }
+
+    ///
+    /// Extracts the `@src <source>:<begin>:<end>` annotations emitted by `solc` into comments.
+    ///
+    /// The positions are 1-based lines and columns, as in the Yul lexer.
+    ///
+    fn source_locations(
+        source_code: &str,
+    ) -> Vec<((usize, usize), solx_codegen_evm::SourceLocation)> {
+        const TAG: &str = "@src ";
+
+        let mut source_locations = Vec::new();
+        for (line_index, line) in source_code.lines().enumerate() {
+            let mut offset = 0;
+            while let Some(position) = line[offset..].find(TAG) {
+                let start = offset + position;
+                offset = start + TAG.len();
+
+                let fields = line[offset..]
+                    .split_whitespace()
+                    .next()
+                    .unwrap_or_default()
+                    .split(':')
+                    .map(|field| {
+                        field
+                            .trim_end_matches(|character: char| !character.is_ascii_digit())
+                            .parse::<isize>()
+                    })
+                    .collect::<Result<Vec<isize>, _>>();
+                if let Ok([source_index, begin, end]) = fields.as_deref() {
+                    let column = line[..start].chars().count() + 1;
+                    source_locations.push((
+                        (line_index + 1, column),
+                        solx_codegen_evm::SourceLocation::new_with_range(
+                            *begin,
+                            *end,
+                            *source_index,
+                        ),
+                    ));
+                }
+            }
+        }
+        source_locations
+    }
+
+    ///
+    /// Extracts the `@ast-id <id>` annotations that `solc` emits before function definitions.
+    ///
+    fn function_ids(source_code: &str) -> BTreeMap<String, usize> {
+        const TAG: &str = "@ast-id ";
+        const KEYWORD: &str = "function ";
+
+        let mut function_ids = BTreeMap::new();
+        let mut current_id = None;
+        for line in source_code.lines() {
+            if let Some(position) = line.find(TAG) {
+                current_id = line[position + TAG.len()..]
+                    .split_whitespace()
+                    .next()
+                    .and_then(|id| id.parse::<usize>().ok());
+                continue;
+            }
+
+            let line = line.trim_start();
+            if let Some(definition) = line.strip_prefix(KEYWORD) {
+                let name = definition
+                    .split(|character: char| character == '(' || character.is_whitespace())
+                    .next()
+                    .unwrap_or_default();
+                if let Some(id) = current_id.take() {
+                    function_ids.insert(name.to_owned(), id);
+                }
+            } else if !line.is_empty() && !line.starts_with("//") && !line.starts_with("/*") {
+                current_id = None;
+            }
+        }
+        function_ids
+    }
 }
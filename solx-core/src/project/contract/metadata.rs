@@ -5,7 +5,7 @@
 ///
 /// The contract metadata.
 ///
-/// Is used to append the metadata hash to the contract bytecode.
+/// Is inserted into the `solc` metadata, which is emitted and hashed into the contract bytecode.
 ///
 #[derive(Debug, serde::Serialize)]
 pub struct Metadata<'a> {
@@ -22,6 +22,9 @@ pub struct Metadata<'a> {
 }
 
 impl<'a> Metadata<'a> {
+    /// The key of the section inserted into the `solc` metadata.
+    pub const KEY: &'static str = "solx";
+
     ///
     /// A shortcut constructor.
     ///
@@ -46,7 +49,7 @@ impl<'a> Metadata<'a> {
         let mut object: serde_json::Value =
             serde_json::from_str(metadata_string).expect("Always valid");
         object.as_object_mut().expect("Always valid").insert(
-            env!("CARGO_PKG_NAME").to_owned(),
+            Self::KEY.to_owned(),
             serde_json::to_value(self).expect("Always valid"),
         );
         serde_json::to_string(&object).expect("Always valid")
@@ -105,7 +105,10 @@ impl Contract {
         metadata_bytes: Option<Vec<u8>>,
         mut optimizer_settings: solx_codegen_evm::OptimizerSettings,
         llvm_options: Vec<String>,
+        code_size_limits: solx_utils::CodeSizeLimits,
+        evm_version: solx_utils::EVMVersion,
         debug_config: Option<solx_codegen_evm::DebugConfig>,
+        is_trace_enabled: bool,
     ) -> Result<EVMContractObject, Error> {
         use solx_codegen_evm::WriteLLVM;
         let mut profiler = solx_codegen_evm::Profiler::default();
@@ -132,12 +135,49 @@ impl Contract {
                     optimizer,
                     debug_config.clone(),
                 );
-                inkwell::context::Context::install_stack_error_handler(
-                    crate::process::evm_stack_error_handler,
-                );
+                deploy_context.set_code_size_limits(code_size_limits);
+                deploy_context.set_evm_version(evm_version);
+                if output_selection.check_selection(
+                    contract_name.path.as_str(),
+                    contract_name.name.as_deref(),
+                    solx_standard_json::InputSelector::BytecodeSourceMap,
+                ) {
+                    deploy_context.enable_source_map();
+                }
+                if output_selection.check_selection(
+                    contract_name.path.as_str(),
+                    contract_name.name.as_deref(),
+                    solx_standard_json::InputSelector::BytecodeFunctionDebugData,
+                ) {
+                    deploy_context.enable_function_debug_data();
+                }
+                if output_selection.check_selection(
+                    contract_name.path.as_str(),
+                    contract_name.name.as_deref(),
+                    solx_standard_json::InputSelector::BytecodeLLVMIR,
+                ) {
+                    deploy_context.enable_llvm_ir();
+                }
+                if output_selection.check_selection(
+                    contract_name.path.as_str(),
+                    contract_name.name.as_deref(),
+                    solx_standard_json::InputSelector::BytecodeLLVMIROptimized,
+                ) {
+                    deploy_context.enable_llvm_ir_optimized();
+                }
+                if output_selection.check_selection(
+                    contract_name.path.as_str(),
+                    contract_name.name.as_deref(),
+                    solx_standard_json::InputSelector::BytecodeRemarks,
+                ) {
+                    deploy_context.enable_remarks();
+                }
                 deploy_context.set_solidity_data(solidity_data);
-                deploy_context
-                    .set_yul_data(solx_codegen_evm::ContextYulData::new(identifier_paths));
+                deploy_context.set_yul_data(solx_codegen_evm::ContextYulData::new(
+                    identifier_paths,
+                    yul.source_locations.into_iter().collect(),
+                    yul.function_ids,
+                ));
                 let run_yul_lowering = profiler.start_evm_translation_unit(
                     contract_name.full_path.as_str(),
                     code_segment,
@@ -163,15 +203,22 @@ impl Contract {
                     deploy_code_identifier,
                     contract_name.clone(),
                     deploy_build.assembly,
+                    deploy_build.llvm_ir,
+                    deploy_build.llvm_ir_optimized,
                     deploy_build.bytecode,
                     true,
                     code_segment,
                     None,
                     None,
+                    deploy_build.source_map,
                     yul.dependencies,
                     deploy_build.is_size_fallback,
                     deploy_build.warnings,
+                    deploy_build.remarks,
                     profiler.to_vec(),
+                    is_trace_enabled
+                        .then(|| profiler.to_trace_events())
+                        .unwrap_or_default(),
                 );
                 Ok(deploy_object)
             }
@@ -189,12 +236,48 @@ impl Contract {
                     optimizer.clone(),
                     debug_config.clone(),
                 );
-                inkwell::context::Context::install_stack_error_handler(
-                    crate::process::evm_stack_error_handler,
-                );
+                runtime_context.set_code_size_limits(code_size_limits);
+                runtime_context.set_evm_version(evm_version);
+                if output_selection.check_selection(
+                    contract_name.path.as_str(),
+                    contract_name.name.as_deref(),
+                    solx_standard_json::InputSelector::RuntimeBytecodeSourceMap,
+                ) {
+                    runtime_context.enable_source_map();
+                }
+                if output_selection.check_selection(
+                    contract_name.path.as_str(),
+                    contract_name.name.as_deref(),
+                    solx_standard_json::InputSelector::RuntimeBytecodeFunctionDebugData,
+                ) {
+                    runtime_context.enable_function_debug_data();
+                }
+                if output_selection.check_selection(
+                    contract_name.path.as_str(),
+                    contract_name.name.as_deref(),
+                    solx_standard_json::InputSelector::RuntimeBytecodeLLVMIR,
+                ) {
+                    runtime_context.enable_llvm_ir();
+                }
+                if output_selection.check_selection(
+                    contract_name.path.as_str(),
+                    contract_name.name.as_deref(),
+                    solx_standard_json::InputSelector::RuntimeBytecodeLLVMIROptimized,
+                ) {
+                    runtime_context.enable_llvm_ir_optimized();
+                }
+                if output_selection.check_selection(
+                    contract_name.path.as_str(),
+                    contract_name.name.as_deref(),
+                    solx_standard_json::InputSelector::RuntimeBytecodeRemarks,
+                ) {
+                    runtime_context.enable_remarks();
+                }
                 runtime_context.set_solidity_data(solidity_data);
                 runtime_context.set_yul_data(solx_codegen_evm::ContextYulData::new(
                     identifier_paths.clone(),
+                    yul.source_locations.into_iter().collect(),
+                    yul.function_ids,
                 ));
                 let run_yul_lowering = profiler.start_evm_translation_unit(
                     contract_name.full_path.as_str(),
@@ -224,15 +307,22 @@ impl Contract {
                     runtime_code_identifier,
                     contract_name.clone(),
                     runtime_build.assembly,
+                    runtime_build.llvm_ir,
+                    runtime_build.llvm_ir_optimized,
                     runtime_build.bytecode,
                     true,
                     code_segment,
                     Some(immutables),
                     metadata_bytes,
+                    runtime_build.source_map,
                     yul.dependencies,
                     runtime_build.is_size_fallback,
                     runtime_build.warnings,
+                    runtime_build.remarks,
                     profiler.to_vec(),
+                    is_trace_enabled
+                        .then(|| profiler.to_trace_events())
+                        .unwrap_or_default(),
                 );
                 Ok(runtime_object)
             }
@@ -257,9 +347,43 @@ impl Contract {
                     optimizer,
                     debug_config.clone(),
                 );
-                inkwell::context::Context::install_stack_error_handler(
-                    crate::process::evm_stack_error_handler,
-                );
+                deploy_context.set_code_size_limits(code_size_limits);
+                deploy_context.set_evm_version(evm_version);
+                if output_selection.check_selection(
+                    contract_name.path.as_str(),
+                    contract_name.name.as_deref(),
+                    solx_standard_json::InputSelector::BytecodeSourceMap,
+                ) {
+                    deploy_context.enable_source_map();
+                }
+                if output_selection.check_selection(
+                    contract_name.path.as_str(),
+                    contract_name.name.as_deref(),
+                    solx_standard_json::InputSelector::BytecodeFunctionDebugData,
+                ) {
+                    deploy_context.enable_function_debug_data();
+                }
+                if output_selection.check_selection(
+                    contract_name.path.as_str(),
+                    contract_name.name.as_deref(),
+                    solx_standard_json::InputSelector::BytecodeLLVMIR,
+                ) {
+                    deploy_context.enable_llvm_ir();
+                }
+                if output_selection.check_selection(
+                    contract_name.path.as_str(),
+                    contract_name.name.as_deref(),
+                    solx_standard_json::InputSelector::BytecodeLLVMIROptimized,
+                ) {
+                    deploy_context.enable_llvm_ir_optimized();
+                }
+                if output_selection.check_selection(
+                    contract_name.path.as_str(),
+                    contract_name.name.as_deref(),
+                    solx_standard_json::InputSelector::BytecodeRemarks,
+                ) {
+                    deploy_context.enable_remarks();
+                }
                 deploy_context.set_solidity_data(solidity_data);
                 deploy_context.set_evmla_data(evmla_data);
                 let run_evm_assembly_lowering = profiler.start_evm_translation_unit(
@@ -290,15 +414,22 @@ impl Contract {
                     deploy_code_identifier,
                     contract_name.clone(),
                     deploy_build.assembly,
+                    deploy_build.llvm_ir,
+                    deploy_build.llvm_ir_optimized,
                     deploy_build.bytecode,
                     false,
                     code_segment,
                     None,
                     None,
+                    deploy_build.source_map,
                     deploy_code_dependencies,
                     deploy_build.is_size_fallback,
                     deploy_build.warnings,
+                    deploy_build.remarks,
                     profiler.to_vec(),
+                    is_trace_enabled
+                        .then(|| profiler.to_trace_events())
+                        .unwrap_or_default(),
                 );
                 Ok(deploy_object)
             }
@@ -318,9 +449,43 @@ impl Contract {
                     optimizer.clone(),
                     debug_config.clone(),
                 );
-                inkwell::context::Context::install_stack_error_handler(
-                    crate::process::evm_stack_error_handler,
-                );
+                runtime_context.set_code_size_limits(code_size_limits);
+                runtime_context.set_evm_version(evm_version);
+                if output_selection.check_selection(
+                    contract_name.path.as_str(),
+                    contract_name.name.as_deref(),
+                    solx_standard_json::InputSelector::RuntimeBytecodeSourceMap,
+                ) {
+                    runtime_context.enable_source_map();
+                }
+                if output_selection.check_selection(
+                    contract_name.path.as_str(),
+                    contract_name.name.as_deref(),
+                    solx_standard_json::InputSelector::RuntimeBytecodeFunctionDebugData,
+                ) {
+                    runtime_context.enable_function_debug_data();
+                }
+                if output_selection.check_selection(
+                    contract_name.path.as_str(),
+                    contract_name.name.as_deref(),
+                    solx_standard_json::InputSelector::RuntimeBytecodeLLVMIR,
+                ) {
+                    runtime_context.enable_llvm_ir();
+                }
+                if output_selection.check_selection(
+                    contract_name.path.as_str(),
+                    contract_name.name.as_deref(),
+                    solx_standard_json::InputSelector::RuntimeBytecodeLLVMIROptimized,
+                ) {
+                    runtime_context.enable_llvm_ir_optimized();
+                }
+                if output_selection.check_selection(
+                    contract_name.path.as_str(),
+                    contract_name.name.as_deref(),
+                    solx_standard_json::InputSelector::RuntimeBytecodeRemarks,
+                ) {
+                    runtime_context.enable_remarks();
+                }
                 runtime_context.set_solidity_data(solidity_data);
                 runtime_context.set_evmla_data(evmla_data.clone());
                 let run_evm_assembly_lowering = profiler.start_evm_translation_unit(
@@ -352,15 +517,22 @@ impl Contract {
                     runtime_code_identifier,
                     contract_name.clone(),
                     runtime_build.assembly,
+                    runtime_build.llvm_ir,
+                    runtime_build.llvm_ir_optimized,
                     runtime_build.bytecode,
                     false,
                     code_segment,
                     Some(immutables),
                     metadata_bytes,
+                    runtime_build.source_map,
                     runtime_code.dependencies,
                     runtime_build.is_size_fallback,
                     runtime_build.warnings,
+                    runtime_build.remarks,
                     profiler.to_vec(),
+                    is_trace_enabled
+                        .then(|| profiler.to_trace_events())
+                        .unwrap_or_default(),
                 );
                 Ok(runtime_object)
             }
@@ -385,9 +557,29 @@ impl Contract {
                     optimizer,
                     debug_config,
                 );
-                inkwell::context::Context::install_stack_error_handler(
-                    crate::process::evm_stack_error_handler,
-                );
+                deploy_context.set_code_size_limits(code_size_limits);
+                deploy_context.set_evm_version(evm_version);
+                if output_selection.check_selection(
+                    contract_name.path.as_str(),
+                    contract_name.name.as_deref(),
+                    solx_standard_json::InputSelector::BytecodeLLVMIR,
+                ) {
+                    deploy_context.enable_llvm_ir();
+                }
+                if output_selection.check_selection(
+                    contract_name.path.as_str(),
+                    contract_name.name.as_deref(),
+                    solx_standard_json::InputSelector::BytecodeLLVMIROptimized,
+                ) {
+                    deploy_context.enable_llvm_ir_optimized();
+                }
+                if output_selection.check_selection(
+                    contract_name.path.as_str(),
+                    contract_name.name.as_deref(),
+                    solx_standard_json::InputSelector::BytecodeRemarks,
+                ) {
+                    deploy_context.enable_remarks();
+                }
                 deploy_context.set_solidity_data(solidity_data);
                 let deploy_build = deploy_context.build(
                     output_selection.check_selection(
@@ -403,15 +595,22 @@ impl Contract {
                     deploy_code_identifier,
                     contract_name.clone(),
                     deploy_build.assembly,
+                    deploy_build.llvm_ir,
+                    deploy_build.llvm_ir_optimized,
                     deploy_build.bytecode,
                     false,
                     code_segment,
                     None,
                     None,
+                    deploy_build.source_map,
                     deploy_llvm_ir.dependencies,
                     deploy_build.is_size_fallback,
                     deploy_build.warnings,
+                    deploy_build.remarks,
                     profiler.to_vec(),
+                    is_trace_enabled
+                        .then(|| profiler.to_trace_events())
+                        .unwrap_or_default(),
                 );
                 Ok(deploy_object)
             }
@@ -436,9 +635,29 @@ impl Contract {
                     optimizer,
                     debug_config.clone(),
                 );
-                inkwell::context::Context::install_stack_error_handler(
-                    crate::process::evm_stack_error_handler,
-                );
+                runtime_context.set_code_size_limits(code_size_limits);
+                runtime_context.set_evm_version(evm_version);
+                if output_selection.check_selection(
+                    contract_name.path.as_str(),
+                    contract_name.name.as_deref(),
+                    solx_standard_json::InputSelector::RuntimeBytecodeLLVMIR,
+                ) {
+                    runtime_context.enable_llvm_ir();
+                }
+                if output_selection.check_selection(
+                    contract_name.path.as_str(),
+                    contract_name.name.as_deref(),
+                    solx_standard_json::InputSelector::RuntimeBytecodeLLVMIROptimized,
+                ) {
+                    runtime_context.enable_llvm_ir_optimized();
+                }
+                if output_selection.check_selection(
+                    contract_name.path.as_str(),
+                    contract_name.name.as_deref(),
+                    solx_standard_json::InputSelector::RuntimeBytecodeRemarks,
+                ) {
+                    runtime_context.enable_remarks();
+                }
                 runtime_context.set_solidity_data(solidity_data);
                 let runtime_build = runtime_context.build(
                     output_selection.check_selection(
@@ -454,15 +673,22 @@ impl Contract {
                     runtime_code_identifier,
                     contract_name.clone(),
                     runtime_build.assembly,
+                    runtime_build.llvm_ir,
+                    runtime_build.llvm_ir_optimized,
                     runtime_build.bytecode,
                     false,
                     code_segment,
                     Some(BTreeMap::new()),
                     metadata_bytes,
+                    runtime_build.source_map,
                     runtime_llvm_ir.dependencies,
                     runtime_build.is_size_fallback,
                     runtime_build.warnings,
+                    runtime_build.remarks,
                     profiler.to_vec(),
+                    is_trace_enabled
+                        .then(|| profiler.to_trace_events())
+                        .unwrap_or_default(),
                 );
                 Ok(runtime_object)
             }
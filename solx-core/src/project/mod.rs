@@ -3,8 +3,10 @@
 //!
 
 pub mod contract;
+pub mod scheduler;
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::Mutex;
@@ -12,9 +14,12 @@ use std::sync::Mutex;
 use rayon::iter::IntoParallelIterator;
 use rayon::iter::ParallelIterator;
 
+use crate::build::contract::diagnostic::Diagnostic as EVMContractDiagnostic;
 use crate::build::contract::Contract as EVMContractBuild;
 use crate::build::Build as EVMBuild;
 use crate::error::Error;
+use crate::process::cache::Cache as EVMProcessCache;
+use crate::process::executor::Executor as EVMProcessExecutor;
 use crate::process::input::Input as EVMProcessInput;
 use crate::process::output::Output as EVMProcessOutput;
 
@@ -24,6 +29,8 @@ use self::contract::ir::yul::Yul as ContractYul;
 use self::contract::ir::IR as ContractIR;
 use self::contract::metadata::Metadata as ContractMetadata;
 use self::contract::Contract;
+use self::scheduler::job::Job;
+use self::scheduler::Scheduler;
 
 ///
 /// The project representation.
@@ -33,7 +40,7 @@ pub struct Project {
     /// The project language.
     pub language: solx_standard_json::InputLanguage,
     /// The `solc` compiler version.
-    /// Used only for Solidity and Yul input languages.
+    /// Used only for Solidity, Yul, and EVM assembly input languages.
     pub solc_version: Option<solx_standard_json::Version>,
     /// The project build results.
     pub contracts: BTreeMap<String, Contract>,
@@ -63,9 +70,10 @@ impl Project {
 
         let solc_version = match language {
             solx_standard_json::InputLanguage::Solidity
-            | solx_standard_json::InputLanguage::Yul => Some(
-                solc_version.expect("`solc` version is mandatory for Solidity and Yul projects"),
-            ),
+            | solx_standard_json::InputLanguage::Yul
+            | solx_standard_json::InputLanguage::EVMAssembly => Some(solc_version.expect(
+                "`solc` version is mandatory for Solidity, Yul, and EVM assembly projects",
+            )),
             solx_standard_json::InputLanguage::LLVMIR => None,
         };
 
@@ -437,6 +445,183 @@ impl Project {
         ))
     }
 
+    ///
+    /// Reads the EVM legacy assembly JSON `paths` and returns an EVM legacy assembly project.
+    ///
+    pub fn try_from_evmla_paths(
+        solc_version: &solx_standard_json::Version,
+        paths: &[PathBuf],
+        libraries: solx_utils::Libraries,
+        output_selection: &solx_standard_json::InputSelection,
+        solc_output: Option<&mut solx_standard_json::Output>,
+    ) -> anyhow::Result<Self> {
+        let sources = paths
+            .iter()
+            .map(|path| {
+                let source = solx_standard_json::InputSource::try_from_path(path.as_path())?;
+                let path = if path.to_string_lossy()
+                    == solx_standard_json::InputSource::STDIN_INPUT_IDENTIFIER
+                {
+                    solx_standard_json::InputSource::STDIN_OUTPUT_IDENTIFIER.to_owned()
+                } else {
+                    path.to_string_lossy().to_string()
+                };
+                Ok((path, source))
+            })
+            .collect::<anyhow::Result<BTreeMap<String, solx_standard_json::InputSource>>>()?;
+
+        Self::try_from_evmla_sources(
+            solc_version,
+            sources,
+            libraries,
+            output_selection,
+            solc_output,
+        )
+    }
+
+    ///
+    /// Parses the EVM legacy assembly JSON `sources` and returns an EVM legacy assembly project.
+    ///
+    /// Each source is a deploy code assembly, as emitted by `solc --asm-json`, with the optional
+    /// `extraMetadata` object. The contracts it creates are compiled as well, and if they are not
+    /// among the sources, their assemblies are taken from its `.data` sections.
+    ///
+    pub fn try_from_evmla_sources(
+        solc_version: &solx_standard_json::Version,
+        sources: BTreeMap<String, solx_standard_json::InputSource>,
+        libraries: solx_utils::Libraries,
+        output_selection: &solx_standard_json::InputSelection,
+        mut solc_output: Option<&mut solx_standard_json::Output>,
+    ) -> anyhow::Result<Self> {
+        let results = sources
+            .into_par_iter()
+            .map(|(path, mut source)| {
+                let source_code = match source.try_resolve() {
+                    Ok(()) => source.take_content().expect("Always exists"),
+                    Err(error) => return (path, Err(error)),
+                };
+
+                let metadata = if output_selection.check_selection(
+                    path.as_str(),
+                    None,
+                    solx_standard_json::InputSelector::Metadata,
+                ) {
+                    let source_hash = solx_utils::Keccak256Hash::from_slice(source_code.as_bytes());
+                    let metadata_json = serde_json::json!({
+                        "source_hash": source_hash.to_string(),
+                        "solc_version": solc_version,
+                    });
+                    Some(serde_json::to_string(&metadata_json).expect("Always valid"))
+                } else {
+                    None
+                };
+
+                let assembly = match solx_utils::deserialize_from_str::<solx_evm_assembly::Assembly>(
+                    source_code.as_str(),
+                ) {
+                    Ok(assembly) => assembly,
+                    Err(error) => {
+                        return (
+                            path,
+                            Err(anyhow::anyhow!("EVM legacy assembly parsing: {error}")),
+                        )
+                    }
+                };
+                if let Err(error) = assembly.runtime_code() {
+                    return (path, Err(error));
+                }
+
+                (path, Ok((assembly, metadata)))
+            })
+            .collect::<BTreeMap<String, anyhow::Result<(solx_evm_assembly::Assembly, Option<String>)>>>();
+
+        let mut assemblies = Vec::with_capacity(results.len());
+        let mut source_data = BTreeMap::new();
+        for (path, result) in results.into_iter() {
+            match result {
+                Ok((mut assembly, metadata)) => {
+                    let name = solx_utils::ContractName::new(path, None);
+                    source_data.insert(
+                        name.full_path.clone(),
+                        (assembly.extra_metadata.take(), metadata),
+                    );
+                    assemblies.push((name, assembly));
+                }
+                Err(error) => match solc_output {
+                    Some(ref mut solc_output) => solc_output.push_error(Some(path), error),
+                    None => anyhow::bail!(error),
+                },
+            }
+        }
+
+        let mut hashes = BTreeSet::new();
+        for (_, assembly) in assemblies.iter() {
+            hashes.insert(assembly.hash());
+            hashes.insert(assembly.runtime_code().expect("Always exists").hash());
+        }
+        let mut index = 0;
+        while index < assemblies.len() {
+            let (ref name, ref assembly) = assemblies[index];
+            let mut sub_assemblies = Vec::new();
+            for (sub_name, sub_assembly) in assembly.sub_assemblies().into_iter() {
+                let runtime_code = match sub_assembly.runtime_code() {
+                    Ok(runtime_code) => runtime_code,
+                    Err(_) => continue,
+                };
+                if !hashes.insert(sub_assembly.hash()) {
+                    continue;
+                }
+                hashes.insert(runtime_code.hash());
+
+                let sub_name = match name.name {
+                    Some(ref name) => format!("{name}.{sub_name}"),
+                    None => sub_name,
+                };
+                sub_assemblies.push((
+                    solx_utils::ContractName::new(name.path.clone(), Some(sub_name)),
+                    sub_assembly.to_owned(),
+                ));
+            }
+            assemblies.extend(sub_assemblies);
+            index += 1;
+        }
+
+        solx_evm_assembly::Assembly::preprocess_full_path_dependencies(
+            assemblies
+                .iter_mut()
+                .map(|(name, assembly)| (name.full_path.clone(), assembly))
+                .collect(),
+        )?;
+
+        let mut contracts = BTreeMap::new();
+        for (name, assembly) in assemblies.into_iter() {
+            let (extra_metadata, metadata) = source_data
+                .remove(name.full_path.as_str())
+                .unwrap_or_default();
+            let contract = Contract::new(
+                name.clone(),
+                Some(ContractEVMLegacyAssembly::from_contract(assembly, extra_metadata).into()),
+                metadata,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            );
+            contracts.insert(name.full_path, contract);
+        }
+        Ok(Self::new(
+            solx_standard_json::InputLanguage::EVMAssembly,
+            Some(solc_version.to_owned()),
+            contracts,
+            None,
+            libraries,
+        ))
+    }
+
     ///
     /// Compiles all contracts to EVM, returning their build artifacts.
     ///
@@ -448,143 +633,203 @@ impl Project {
         append_cbor: bool,
         optimizer_settings: solx_codegen_evm::OptimizerSettings,
         llvm_options: Vec<String>,
+        contract_overrides: &BTreeMap<String, solx_standard_json::InputContractOverride>,
+        code_size_limits: solx_utils::CodeSizeLimits,
+        evm_version: solx_utils::EVMVersion,
         debug_config: Option<solx_codegen_evm::DebugConfig>,
+        is_trace_enabled: bool,
+        executor: &dyn EVMProcessExecutor,
+        cache: Option<&EVMProcessCache>,
     ) -> anyhow::Result<EVMBuild> {
-        let results = self
-            .contracts
-            .into_par_iter()
-            .map(|(path, mut contract)| {
-                let contract_name = contract.name.clone();
-
-                let metadata = contract.metadata.take();
-                let abi = contract.abi.take();
-                let method_identifiers = contract.method_identifiers.take();
-                let userdoc = contract.userdoc.take();
-                let devdoc = contract.devdoc.take();
-                let storage_layout = contract.storage_layout.take();
-                let transient_storage_layout = contract.transient_storage_layout.take();
-                let legacy_assembly = contract.legacy_assembly.take();
-                let yul = contract.yul.take();
-
-                let (deploy_code_ir, runtime_code_ir): (ContractIR, ContractIR) = match contract.ir
-                {
-                    Some(ContractIR::Yul(mut deploy_code)) => {
-                        let runtime_code: ContractYul =
-                            *deploy_code.runtime_code.take().expect("Always exists");
-                        (deploy_code.into(), runtime_code.into())
-                    }
-                    Some(ContractIR::EVMLegacyAssembly(mut deploy_code)) => {
-                        let runtime_code: ContractEVMLegacyAssembly =
-                            *deploy_code.runtime_code.take().expect("Always exists");
-                        (deploy_code.into(), runtime_code.into())
-                    }
-                    Some(ContractIR::LLVMIR(runtime_code)) => {
-                        let deploy_code_identifier = contract.name.full_path.to_owned();
-                        let runtime_code_identifier = format!(
-                            "{deploy_code_identifier}.{}",
-                            solx_utils::CodeSegment::Runtime
-                        );
-
-                        let deploy_code = ContractLLVMIR::new(
-                            deploy_code_identifier.clone(),
-                            solx_utils::CodeSegment::Deploy,
-                            solx_codegen_evm::minimal_deploy_code(
-                                deploy_code_identifier.as_str(),
-                                runtime_code_identifier.as_str(),
-                            ),
-                        );
-                        (deploy_code.into(), runtime_code.into())
-                    }
-                    None => {
-                        let build = EVMContractBuild::new(
-                            contract_name,
-                            None,
-                            None,
-                            metadata,
-                            abi,
-                            method_identifiers,
-                            userdoc,
-                            devdoc,
-                            storage_layout,
-                            transient_storage_layout,
-                            legacy_assembly,
-                            yul,
-                        );
-                        return (path, build);
-                    }
-                };
+        for (key, contract_override) in contract_overrides.iter() {
+            if let Some(mode) = contract_override.mode {
+                solx_codegen_evm::OptimizerSettings::try_from_cli(mode).map_err(|error| {
+                    anyhow::anyhow!("Contract `{key}` optimizer settings override: {error}")
+                })?;
+            }
+        }
 
-                let (runtime_object_result, metadata) = {
-                    let metadata_bytes = Self::cbor_metadata(
-                        metadata.as_deref(),
-                        self.solc_version.as_ref(),
-                        &optimizer_settings,
-                        llvm_options.as_slice(),
-                        metadata_hash_type,
-                        append_cbor,
+        let mut builds = BTreeMap::new();
+        let mut jobs = Vec::with_capacity(self.contracts.len());
+        for (path, mut contract) in self.contracts.into_iter() {
+            let contract_name = contract.name.clone();
+            let (optimizer_settings, llvm_options) = Self::contract_settings(
+                &contract_name,
+                &optimizer_settings,
+                llvm_options.as_slice(),
+                contract_overrides,
+            );
+
+            let metadata = contract.metadata.take().map(|metadata| {
+                ContractMetadata::new(
+                    self.solc_version.as_ref(),
+                    optimizer_settings.clone(),
+                    llvm_options.as_slice(),
+                )
+                .insert_into(metadata.as_str())
+            });
+            let abi = contract.abi.take();
+            let method_identifiers = contract.method_identifiers.take();
+            let userdoc = contract.userdoc.take();
+            let devdoc = contract.devdoc.take();
+            let storage_layout = contract.storage_layout.take();
+            let transient_storage_layout = contract.transient_storage_layout.take();
+            let legacy_assembly = contract.legacy_assembly.take();
+            let yul = contract.yul.take();
+
+            let build = EVMContractBuild::new(
+                contract_name.clone(),
+                None,
+                None,
+                metadata,
+                abi,
+                method_identifiers,
+                userdoc,
+                devdoc,
+                storage_layout,
+                transient_storage_layout,
+                legacy_assembly,
+                yul,
+            );
+
+            let (deploy_code_ir, runtime_code_ir): (ContractIR, ContractIR) = match contract.ir {
+                Some(ContractIR::Yul(mut deploy_code)) => {
+                    let runtime_code: ContractYul =
+                        *deploy_code.runtime_code.take().expect("Always exists");
+                    (deploy_code.into(), runtime_code.into())
+                }
+                Some(ContractIR::EVMLegacyAssembly(mut deploy_code)) => {
+                    let runtime_code: ContractEVMLegacyAssembly =
+                        *deploy_code.runtime_code.take().expect("Always exists");
+                    (deploy_code.into(), runtime_code.into())
+                }
+                Some(ContractIR::LLVMIR(runtime_code)) => {
+                    let deploy_code_identifier = contract.name.full_path.to_owned();
+                    let runtime_code_identifier = format!(
+                        "{deploy_code_identifier}.{}",
+                        solx_utils::CodeSegment::Runtime
                     );
 
-                    let mut input = EVMProcessInput::new(
-                        self.solc_version.clone(),
-                        contract_name.clone(),
-                        runtime_code_ir,
-                        solx_utils::CodeSegment::Runtime,
-                        self.identifier_paths.clone(),
-                        output_selection.to_owned(),
-                        None,
-                        metadata_bytes,
-                        optimizer_settings.clone(),
-                        llvm_options.clone(),
-                        debug_config.clone(),
+                    let deploy_code = ContractLLVMIR::new(
+                        deploy_code_identifier.clone(),
+                        solx_utils::CodeSegment::Deploy,
+                        solx_codegen_evm::minimal_deploy_code(
+                            deploy_code_identifier.as_str(),
+                            runtime_code_identifier.as_str(),
+                        ),
                     );
+                    (deploy_code.into(), runtime_code.into())
+                }
+                None => {
+                    builds.insert(path, build);
+                    continue;
+                }
+            };
+
+            let metadata_bytes = Self::cbor_metadata(
+                build.metadata.as_deref(),
+                self.solc_version.as_ref(),
+                metadata_hash_type,
+                append_cbor,
+            );
+            let runtime_input = EVMProcessInput::new(
+                self.solc_version.clone(),
+                contract_name.clone(),
+                runtime_code_ir,
+                solx_utils::CodeSegment::Runtime,
+                self.identifier_paths.clone(),
+                output_selection.to_owned(),
+                None,
+                metadata_bytes,
+                optimizer_settings.clone(),
+                llvm_options.clone(),
+                code_size_limits,
+                evm_version,
+                debug_config.clone(),
+                is_trace_enabled,
+            );
+            let deploy_input = EVMProcessInput::new(
+                self.solc_version.clone(),
+                contract_name,
+                deploy_code_ir,
+                solx_utils::CodeSegment::Deploy,
+                self.identifier_paths.clone(),
+                output_selection.to_owned(),
+                None,
+                None,
+                optimizer_settings,
+                llvm_options,
+                code_size_limits,
+                evm_version,
+                debug_config.clone(),
+                is_trace_enabled,
+            );
+            jobs.push(Job::new(
+                path.clone(),
+                runtime_input,
+                Some(Job::new(path.clone(), deploy_input, None)),
+            ));
+            builds.insert(path, build);
+        }
 
-                    let result = Self::run_multi_pass_pipeline(path.as_str(), &mut input);
-                    (result, metadata)
-                };
+        let trace_events = Mutex::new(Vec::new());
+        let results = Scheduler::run(jobs, |path, input| {
+            Self::run_multi_pass_pipeline(path, input, executor, cache, &trace_events)
+        });
+        for ((path, code_segment), result) in results.into_iter() {
+            let build = builds.get_mut(path.as_str()).expect("Always exists");
+            let object_result = Some(result.map(|output| output.object));
+            match code_segment {
+                solx_utils::CodeSegment::Deploy => build.deploy_object_result = object_result,
+                solx_utils::CodeSegment::Runtime => build.runtime_object_result = object_result,
+            }
+        }
 
-                let immutables = runtime_object_result
-                    .as_ref()
-                    .ok()
-                    .and_then(|output| output.object.immutables.to_owned());
-                let deploy_object_result: crate::Result<EVMProcessOutput> = {
-                    let mut input = EVMProcessInput::new(
-                        self.solc_version.clone(),
-                        contract_name.clone(),
-                        deploy_code_ir,
-                        solx_utils::CodeSegment::Deploy,
-                        self.identifier_paths.clone(),
-                        output_selection.to_owned(),
-                        immutables,
-                        None,
-                        optimizer_settings.clone(),
-                        llvm_options.clone(),
-                        debug_config.clone(),
-                    );
+        let mut build = EVMBuild::new(builds, self.ast_jsons, messages);
+        build.trace_events = trace_events.into_inner().expect("Sync");
+        Ok(build)
+    }
 
-                    Self::run_multi_pass_pipeline(path.as_str(), &mut input)
-                };
+    ///
+    /// Returns the optimizer settings and LLVM options of the contract, with its overrides applied.
+    ///
+    /// The full contract path override takes precedence over the source path one.
+    ///
+    fn contract_settings(
+        contract_name: &solx_utils::ContractName,
+        optimizer_settings: &solx_codegen_evm::OptimizerSettings,
+        llvm_options: &[String],
+        contract_overrides: &BTreeMap<String, solx_standard_json::InputContractOverride>,
+    ) -> (solx_codegen_evm::OptimizerSettings, Vec<String>) {
+        let contract_override = match contract_overrides
+            .get(contract_name.full_path.as_str())
+            .or_else(|| contract_overrides.get(contract_name.path.as_str()))
+        {
+            Some(contract_override) => contract_override,
+            None => return (optimizer_settings.to_owned(), llvm_options.to_owned()),
+        };
 
-                let build = EVMContractBuild::new(
-                    contract_name,
-                    Some(deploy_object_result.map(|deploy_code_output| deploy_code_output.object)),
-                    Some(
-                        runtime_object_result.map(|runtime_code_output| runtime_code_output.object),
-                    ),
-                    metadata,
-                    abi,
-                    method_identifiers,
-                    userdoc,
-                    devdoc,
-                    storage_layout,
-                    transient_storage_layout,
-                    legacy_assembly,
-                    yul,
-                );
-                (path, build)
-            })
-            .collect::<BTreeMap<String, EVMContractBuild>>();
+        let mut contract_optimizer_settings = match contract_override.mode {
+            Some(mode) => {
+                let mut settings =
+                    solx_codegen_evm::OptimizerSettings::try_from_cli(mode).expect("Always valid");
+                settings.is_fallback_to_size_enabled =
+                    optimizer_settings.is_fallback_to_size_enabled;
+                settings.is_verify_each_enabled = optimizer_settings.is_verify_each_enabled;
+                settings.is_debug_logging_enabled = optimizer_settings.is_debug_logging_enabled;
+                settings
+            }
+            None => optimizer_settings.to_owned(),
+        };
+        if let Some(size_fallback) = contract_override.size_fallback {
+            contract_optimizer_settings.is_fallback_to_size_enabled = size_fallback;
+        }
+        let contract_llvm_options = contract_override
+            .llvm_options
+            .clone()
+            .unwrap_or_else(|| llvm_options.to_owned());
 
-        Ok(EVMBuild::new(results, self.ast_jsons, messages))
+        (contract_optimizer_settings, contract_llvm_options)
     }
 
     ///
@@ -593,8 +838,6 @@ impl Project {
     fn cbor_metadata(
         metadata: Option<&str>,
         solc_version: Option<&solx_standard_json::Version>,
-        optimizer_settings: &solx_codegen_evm::OptimizerSettings,
-        llvm_options: &[String],
         metadata_hash_type: solx_utils::MetadataHashType,
         append_cbor: bool,
     ) -> Option<Vec<u8>> {
@@ -602,18 +845,15 @@ impl Project {
             return None;
         }
 
-        let metadata = metadata.map(|metadata| {
-            ContractMetadata::new(solc_version, optimizer_settings.clone(), llvm_options)
-                .insert_into(metadata)
+        let metadata_hash = metadata.and_then(|metadata| match metadata_hash_type {
+            solx_utils::MetadataHashType::None => None,
+            solx_utils::MetadataHashType::IPFS => {
+                Some(solx_utils::IPFSHash::from_slice(metadata.as_bytes()).to_vec())
+            }
+            solx_utils::MetadataHashType::Swarm => {
+                Some(solx_utils::SwarmHash::from_slice(metadata.as_bytes()).to_vec())
+            }
         });
-        let metadata_hash = metadata
-            .as_ref()
-            .and_then(|metadata| match metadata_hash_type {
-                solx_utils::MetadataHashType::None => None,
-                solx_utils::MetadataHashType::IPFS => {
-                    Some(solx_utils::IPFSHash::from_slice(metadata.as_bytes()).to_vec())
-                }
-            });
 
         let mut cbor_version_parts = Vec::with_capacity(3);
         cbor_version_parts.push((
@@ -638,7 +878,7 @@ impl Project {
         match metadata_hash {
             Some(hash) => {
                 let cbor = solx_utils::CBOR::new(
-                    Some((solx_utils::MetadataHashType::IPFS, hash.as_slice())),
+                    Some((metadata_hash_type, hash.as_slice())),
                     cbor_data.0,
                     cbor_data.1,
                 );
@@ -657,14 +897,47 @@ impl Project {
     /// It is expected to run up to 4 passes in the process of handling stack too deep errors
     /// and turning on the size fallback to overcome the EVM bytecode size limit.
     ///
+    /// The spill area allocations and size fallbacks are recorded as the object diagnostics,
+    /// and the configured size limit is recorded for the size breakdown.
+    ///
+    /// Each pass is run by `executor`, either in a subprocess or on an in-process worker thread.
+    ///
+    /// If `cache` is set, the output is looked up by the initial input, and the executor is
+    /// skipped on a hit. Only successful outputs are cached, and a cache writing failure is only
+    /// reported as a warning. The trace events are never cached, as they belong to the build that
+    /// has produced the output.
+    ///
+    /// If tracing is enabled, each pass is recorded to `trace_events`, including the failed ones,
+    /// whose translation unit events are not returned by the executor.
+    ///
     fn run_multi_pass_pipeline(
         path: &str,
         input: &mut EVMProcessInput,
+        executor: &dyn EVMProcessExecutor,
+        cache: Option<&EVMProcessCache>,
+        trace_events: &Mutex<Vec<solx_codegen_evm::TraceEvent>>,
     ) -> crate::Result<EVMProcessOutput> {
+        let cache_key = cache.and_then(|_| EVMProcessCache::key(input));
+        if let (Some(cache), Some(cache_key)) = (cache, cache_key.as_deref()) {
+            if let Some(mut output) = cache.get(cache_key) {
+                // The cached trace events belong to the build that populated the cache.
+                output.object.trace_events.clear();
+                return Ok(output);
+            }
+        }
+
+        let mut profiler = solx_codegen_evm::Profiler::default();
         let mut result: crate::Result<EVMProcessOutput>;
         let mut pass_count = 0;
         loop {
-            result = crate::process::call(path, input);
+            let run_pass = profiler.start_evm_translation_unit(
+                input.contract_name.full_path.as_str(),
+                input.code_segment,
+                format!("Pass{}", pass_count + 1).as_str(),
+                &input.optimizer_settings,
+            );
+            result = executor.execute(path, input);
+            run_pass.borrow_mut().finish();
             pass_count += 1;
             match result {
                 Err(Error::StackTooDeep(ref stack_too_deep)) => {
@@ -682,6 +955,54 @@ impl Project {
                 _ => break,
             }
         }
+        if input.is_trace_enabled {
+            trace_events
+                .lock()
+                .expect("Sync")
+                .extend(profiler.to_trace_events());
+        }
+
+        if let Ok(output) = result.as_mut() {
+            let code_segment = output.object.code_segment;
+            output.object.size_limit = input.code_size_limits.get(code_segment);
+            if let Some(spill_area_size) = input.optimizer_settings.spill_area_size() {
+                output
+                    .object
+                    .diagnostics
+                    .push(EVMContractDiagnostic::SpillArea {
+                        code_segment,
+                        spill_area_size,
+                        pass_count,
+                    });
+            }
+            if output.object.is_size_fallback {
+                output
+                    .object
+                    .diagnostics
+                    .push(EVMContractDiagnostic::SizeFallback {
+                        code_segment,
+                        pass_count,
+                    });
+            }
+        }
+
+        if let (Some(cache), Some(cache_key), Ok(output)) =
+            (cache, cache_key.as_deref(), result.as_mut())
+        {
+            let output_trace_events = std::mem::take(&mut output.object.trace_events);
+            let cache_result = cache.put(cache_key, output);
+            output.object.trace_events = output_trace_events;
+            if let Err(error) = cache_result {
+                let code_segment = output.object.code_segment;
+                output
+                    .object
+                    .diagnostics
+                    .push(EVMContractDiagnostic::CacheWrite {
+                        code_segment,
+                        message: error.to_string(),
+                    });
+            }
+        }
         result
     }
 }
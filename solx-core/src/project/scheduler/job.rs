@@ -0,0 +1,89 @@
+//!
+//! The compilation job.
+//!
+
+use std::cmp::Ordering;
+
+use crate::process::input::Input as EVMProcessInput;
+
+///
+/// The compilation job.
+///
+/// Compiles a single code segment of a contract.
+///
+#[derive(Debug)]
+pub struct Job {
+    /// The contract path.
+    pub path: String,
+    /// The translation unit input.
+    pub input: EVMProcessInput,
+    /// The estimated compilation cost.
+    pub weight: usize,
+    /// The job released once this one is finished.
+    /// The deploy code depends on the runtime code, as it needs the runtime code immutables.
+    pub dependent: Option<Box<Job>>,
+}
+
+impl Job {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(path: String, input: EVMProcessInput, dependent: Option<Job>) -> Self {
+        let weight = input.contract_ir.weight();
+        Self {
+            path,
+            input,
+            weight,
+            dependent: dependent.map(Box::new),
+        }
+    }
+
+    ///
+    /// Returns the number of jobs in the chain starting with this one.
+    ///
+    pub fn chain_length(&self) -> usize {
+        1 + self
+            .dependent
+            .as_ref()
+            .map(|dependent| dependent.chain_length())
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Returns the estimated compilation cost of the chain starting with this one.
+    ///
+    /// The chains with the highest cost are started first, so the longest ones do not end up
+    /// running alone at the end of the build.
+    ///
+    pub fn chain_weight(&self) -> usize {
+        self.weight
+            + self
+                .dependent
+                .as_ref()
+                .map(|dependent| dependent.chain_weight())
+                .unwrap_or_default()
+    }
+}
+
+impl PartialEq for Job {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Job {}
+
+impl PartialOrd for Job {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Job {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.chain_weight()
+            .cmp(&other.chain_weight())
+            .then_with(|| other.path.cmp(&self.path))
+            .then_with(|| other.input.code_segment.cmp(&self.input.code_segment))
+    }
+}
@@ -0,0 +1,316 @@
+//!
+//! The dependency-aware compilation job scheduler.
+//!
+
+pub mod job;
+pub mod progress;
+pub mod state;
+
+use std::collections::BTreeMap;
+use std::panic::AssertUnwindSafe;
+use std::sync::Condvar;
+use std::sync::Mutex;
+
+use crate::process::input::Input as EVMProcessInput;
+use crate::process::output::Output as EVMProcessOutput;
+
+use self::job::Job;
+use self::state::State;
+
+///
+/// The dependency-aware compilation job scheduler.
+///
+/// Runs the jobs on the global thread pool, whose size is set with `--threads`.
+/// The ready jobs with the highest estimated cost are started first, and the dependent jobs are
+/// released as soon as their dependency is finished, so large contracts do not keep a single
+/// worker busy after the others have run out of work. The results are collected by their
+/// contract path and code segment, so they do not depend on the order of completion.
+///
+#[derive(Debug)]
+pub struct Scheduler {
+    /// The scheduler state shared by the workers.
+    state: Mutex<State>,
+    /// Notifies the idle workers that a job has been released or finished.
+    condvar: Condvar,
+}
+
+impl Scheduler {
+    ///
+    /// Runs `jobs` and their dependents with `execute`, returning the results by contract path and code segment.
+    ///
+    pub fn run<F>(
+        jobs: Vec<Job>,
+        execute: F,
+    ) -> BTreeMap<(String, solx_utils::CodeSegment), crate::Result<EVMProcessOutput>>
+    where
+        F: Fn(&str, &mut EVMProcessInput) -> crate::Result<EVMProcessOutput> + Sync,
+    {
+        let workers = rayon::current_num_threads()
+            .min(jobs.iter().map(Job::chain_length).sum())
+            .max(1);
+        let scheduler = Self {
+            state: Mutex::new(State::new(jobs)),
+            condvar: Condvar::new(),
+        };
+
+        rayon::scope(|scope| {
+            for _ in 0..workers {
+                scope.spawn(|_| scheduler.work(&execute));
+            }
+        });
+
+        let state = scheduler.state.into_inner().expect("Sync");
+        state.progress.finish();
+        state.results
+    }
+
+    ///
+    /// Runs the jobs until there are none left.
+    ///
+    fn work<F>(&self, execute: &F)
+    where
+        F: Fn(&str, &mut EVMProcessInput) -> crate::Result<EVMProcessOutput> + Sync,
+    {
+        while let Some(mut job) = self.next() {
+            let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+                execute(job.path.as_str(), &mut job.input)
+            }));
+            match result {
+                Ok(result) => self.finish(job, result),
+                Err(payload) => {
+                    self.abort();
+                    std::panic::resume_unwind(payload);
+                }
+            }
+        }
+    }
+
+    ///
+    /// Takes the next job to run.
+    ///
+    /// Blocks while the queue is empty, but some jobs are still being run and may release their dependents.
+    /// Returns `None` once all jobs are finished.
+    ///
+    fn next(&self) -> Option<Job> {
+        let mut state = self.state.lock().expect("Sync");
+        loop {
+            if let Some(job) = state.queue.pop() {
+                state.running += 1;
+                return Some(job);
+            }
+            if state.running == 0 {
+                return None;
+            }
+            state = self.condvar.wait(state).expect("Sync");
+        }
+    }
+
+    ///
+    /// Records the result of `job`, and releases its dependent.
+    ///
+    /// The deploy code dependent receives the immutables of the runtime code.
+    /// The dependent is dropped if the scheduler has been aborted in the meantime.
+    ///
+    fn finish(&self, job: Job, result: crate::Result<EVMProcessOutput>) {
+        let Job {
+            path,
+            input,
+            weight,
+            dependent,
+        } = job;
+
+        let mut state = self.state.lock().expect("Sync");
+        if let Some(mut dependent) = dependent.filter(|_| !state.is_aborted) {
+            dependent.input.immutables = result
+                .as_ref()
+                .ok()
+                .and_then(|output| output.object.immutables.to_owned());
+            state.queue.push(*dependent);
+        }
+        state.running -= 1;
+        state.progress.finish_job(weight);
+        state.results.insert((path, input.code_segment), result);
+        drop(state);
+
+        self.condvar.notify_all();
+    }
+
+    ///
+    /// Drops the remaining jobs after a worker has panicked, so the other workers can exit.
+    ///
+    fn abort(&self) {
+        let mut state = self.state.lock().expect("Sync");
+        state.queue.clear();
+        state.is_aborted = true;
+        state.running -= 1;
+        drop(state);
+
+        self.condvar.notify_all();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::sync::Condvar;
+    use std::sync::Mutex;
+
+    use crate::process::input::Input as EVMProcessInput;
+    use crate::project::contract::ir::llvm_ir::LLVMIR;
+    use crate::project::scheduler::job::Job;
+    use crate::project::scheduler::state::State;
+    use crate::project::scheduler::Scheduler;
+
+    ///
+    /// Creates a job for `code_segment` of contract `path` with `line_count` lines of LLVM IR.
+    ///
+    fn job(
+        path: &str,
+        code_segment: solx_utils::CodeSegment,
+        line_count: usize,
+        dependent: Option<Job>,
+    ) -> Job {
+        let source = "\n".repeat(line_count);
+        let input = EVMProcessInput::new(
+            None,
+            solx_utils::ContractName::new(path.to_owned(), None),
+            LLVMIR::new(path.to_owned(), code_segment, source).into(),
+            code_segment,
+            BTreeMap::new(),
+            solx_standard_json::InputSelection::default(),
+            None,
+            None,
+            solx_codegen_evm::OptimizerSettings::none(),
+            vec![],
+            solx_utils::CodeSizeLimits::default(),
+            solx_utils::EVMVersion::default(),
+            None,
+            false,
+        );
+        Job::new(path.to_owned(), input, dependent)
+    }
+
+    ///
+    /// Creates a runtime code job with `line_count` lines, and its deploy code dependent.
+    ///
+    fn contract(path: &str, line_count: usize) -> Job {
+        job(
+            path,
+            solx_utils::CodeSegment::Runtime,
+            line_count,
+            Some(job(path, solx_utils::CodeSegment::Deploy, 1, None)),
+        )
+    }
+
+    ///
+    /// Creates a scheduler without running it.
+    ///
+    fn scheduler(jobs: Vec<Job>) -> Scheduler {
+        Scheduler {
+            state: Mutex::new(State::new(jobs)),
+            condvar: Condvar::new(),
+        }
+    }
+
+    ///
+    /// Returns a failed compilation result.
+    ///
+    fn failure() -> crate::Result<crate::process::output::Output> {
+        Err(anyhow::anyhow!("Test failure").into())
+    }
+
+    #[test]
+    fn priority_order() {
+        let scheduler = scheduler(vec![
+            contract("Small.sol:Small", 10),
+            contract("Large.sol:Large", 1000),
+            contract("Medium.sol:Medium", 100),
+        ]);
+
+        let paths: Vec<String> =
+            std::iter::from_fn(|| scheduler.state.lock().expect("Sync").queue.pop())
+                .map(|job| job.path)
+                .collect();
+        assert_eq!(
+            paths,
+            vec!["Large.sol:Large", "Medium.sol:Medium", "Small.sol:Small"],
+        );
+    }
+
+    #[test]
+    fn dependency_release() {
+        let scheduler = scheduler(vec![contract("Test.sol:Test", 10)]);
+
+        let runtime_job = scheduler.next().expect("Always exists");
+        assert_eq!(
+            runtime_job.input.code_segment,
+            solx_utils::CodeSegment::Runtime
+        );
+        assert!(scheduler.state.lock().expect("Sync").queue.is_empty());
+
+        scheduler.finish(runtime_job, failure());
+        let deploy_job = scheduler.next().expect("Always exists");
+        assert_eq!(
+            deploy_job.input.code_segment,
+            solx_utils::CodeSegment::Deploy
+        );
+
+        scheduler.finish(deploy_job, failure());
+        assert!(scheduler.next().is_none());
+        assert_eq!(scheduler.state.lock().expect("Sync").results.len(), 2);
+    }
+
+    #[test]
+    fn abort() {
+        let scheduler = scheduler(vec![
+            contract("First.sol:First", 20),
+            contract("Second.sol:Second", 10),
+            contract("Third.sol:Third", 1),
+        ]);
+
+        let _panicked_job = scheduler.next().expect("Always exists");
+        let running_job = scheduler.next().expect("Always exists");
+        scheduler.abort();
+        scheduler.finish(running_job, failure());
+
+        assert!(scheduler.next().is_none());
+        let state = scheduler.state.lock().expect("Sync");
+        assert!(state.queue.is_empty());
+        assert_eq!(state.running, 0);
+        assert_eq!(state.results.len(), 1);
+    }
+
+    #[test]
+    fn run() {
+        let executed = Mutex::new(Vec::new());
+        let results = Scheduler::run(
+            vec![
+                contract("First.sol:First", 20),
+                contract("Second.sol:Second", 10),
+            ],
+            |path, input| {
+                executed
+                    .lock()
+                    .expect("Sync")
+                    .push((path.to_owned(), input.code_segment));
+                failure()
+            },
+        );
+
+        let executed = executed.into_inner().expect("Sync");
+        assert_eq!(executed.len(), 4);
+        assert_eq!(results.len(), 4);
+        for path in ["First.sol:First", "Second.sol:Second"] {
+            let position = |code_segment| {
+                executed
+                    .iter()
+                    .position(|executed| executed == &(path.to_owned(), code_segment))
+                    .expect("Always exists")
+            };
+            assert!(
+                position(solx_utils::CodeSegment::Runtime)
+                    < position(solx_utils::CodeSegment::Deploy)
+            );
+        }
+    }
+}
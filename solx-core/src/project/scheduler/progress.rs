@@ -0,0 +1,126 @@
+//!
+//! The compilation progress report.
+//!
+
+use std::io::IsTerminal;
+use std::io::Write;
+use std::time::Duration;
+use std::time::Instant;
+
+///
+/// The compilation progress report.
+///
+/// Printed to stderr only if it is a terminal and the build takes long enough to be worth
+/// reporting, so neither short builds nor the tooling reading the output are affected.
+///
+#[derive(Debug)]
+pub struct Progress {
+    /// Whether the report is printed.
+    is_enabled: bool,
+    /// The build start time.
+    start: Instant,
+    /// The time of the last report, if any.
+    last_report: Option<Instant>,
+    /// The total number of jobs.
+    total_jobs: usize,
+    /// The total estimated cost of the jobs.
+    total_weight: usize,
+    /// The number of finished jobs.
+    finished_jobs: usize,
+    /// The estimated cost of the finished jobs.
+    finished_weight: usize,
+}
+
+impl Progress {
+    /// The time after which the report is started.
+    pub const DELAY: Duration = Duration::from_secs(2);
+
+    /// The minimal interval between two reports.
+    pub const INTERVAL: Duration = Duration::from_millis(500);
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(total_jobs: usize, total_weight: usize) -> Self {
+        Self {
+            is_enabled: std::io::stderr().is_terminal(),
+            start: Instant::now(),
+            last_report: None,
+            total_jobs,
+            total_weight,
+            finished_jobs: 0,
+            finished_weight: 0,
+        }
+    }
+
+    ///
+    /// Records a finished job with estimated cost `weight`, and reports the progress if it is due.
+    ///
+    pub fn finish_job(&mut self, weight: usize) {
+        self.finished_jobs += 1;
+        self.finished_weight += weight;
+
+        if !self.is_enabled {
+            return;
+        }
+        let now = Instant::now();
+        if now.duration_since(self.start) < Self::DELAY {
+            return;
+        }
+        if let Some(last_report) = self.last_report {
+            if now.duration_since(last_report) < Self::INTERVAL {
+                return;
+            }
+        }
+        self.last_report = Some(now);
+
+        let _ = write!(std::io::stderr(), "\r\x1b[2K{self}");
+    }
+
+    ///
+    /// Terminates the report line, if anything has been reported.
+    ///
+    pub fn finish(&self) {
+        if self.last_report.is_some() {
+            let _ = writeln!(std::io::stderr(), "\r\x1b[2K{self}");
+        }
+    }
+
+    ///
+    /// Returns the estimated time remaining, extrapolated from the cost of the finished jobs.
+    ///
+    pub fn eta(&self) -> Option<Duration> {
+        if self.finished_weight == 0 {
+            return None;
+        }
+        let remaining_weight = self.total_weight.saturating_sub(self.finished_weight);
+        Some(
+            self.start
+                .elapsed()
+                .mul_f64(remaining_weight as f64 / self.finished_weight as f64),
+        )
+    }
+}
+
+impl std::fmt::Display for Progress {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let percentage = if self.total_weight == 0 {
+            100
+        } else {
+            self.finished_weight * 100 / self.total_weight
+        };
+        write!(
+            f,
+            "Compiling: {}/{} translation units ({percentage}%), elapsed {}s",
+            self.finished_jobs,
+            self.total_jobs,
+            self.start.elapsed().as_secs(),
+        )?;
+        match self.eta() {
+            Some(eta) if self.finished_jobs < self.total_jobs => {
+                write!(f, ", ETA {}s", eta.as_secs())
+            }
+            _ => Ok(()),
+        }
+    }
+}
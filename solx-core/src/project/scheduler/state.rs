@@ -0,0 +1,45 @@
+//!
+//! The compilation job scheduler state.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BinaryHeap;
+
+use crate::process::output::Output as EVMProcessOutput;
+
+use super::job::Job;
+use super::progress::Progress;
+
+///
+/// The compilation job scheduler state.
+///
+#[derive(Debug)]
+pub struct State {
+    /// The jobs ready to be started.
+    pub queue: BinaryHeap<Job>,
+    /// The number of jobs being run.
+    pub running: usize,
+    /// Whether the remaining jobs have been dropped after a worker has panicked.
+    pub is_aborted: bool,
+    /// The results of the finished jobs.
+    pub results: BTreeMap<(String, solx_utils::CodeSegment), crate::Result<EVMProcessOutput>>,
+    /// The progress report.
+    pub progress: Progress,
+}
+
+impl State {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(jobs: Vec<Job>) -> Self {
+        let total_jobs = jobs.iter().map(Job::chain_length).sum();
+        let total_weight = jobs.iter().map(Job::chain_weight).sum();
+        Self {
+            queue: BinaryHeap::from(jobs),
+            running: 0,
+            is_aborted: false,
+            results: BTreeMap::new(),
+            progress: Progress::new(total_jobs, total_weight),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+//!
+//! The bytecode comparison difference.
+//!
+
+///
+/// The bytecode comparison difference.
+///
+/// A contiguous range of bytes that differ between the recompiled and the given bytecode.
+/// Either side is shorter than the range if the bytecode ends within it.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Difference {
+    /// The offset of the range.
+    pub offset: usize,
+    /// The recompiled bytes.
+    pub expected: Vec<u8>,
+    /// The given bytes.
+    pub actual: Vec<u8>,
+}
+
+impl Difference {
+    /// The maximum number of bytes printed for each side of the difference.
+    pub const MAX_PRINTED_BYTES: usize = 32;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(offset: usize, expected: Vec<u8>, actual: Vec<u8>) -> Self {
+        Self {
+            offset,
+            expected,
+            actual,
+        }
+    }
+
+    ///
+    /// Returns the size of the range in bytes.
+    ///
+    pub fn size(&self) -> usize {
+        self.expected.len().max(self.actual.len())
+    }
+
+    ///
+    /// Formats `bytes` as hexadecimal, truncating them to the maximum printed length.
+    ///
+    fn format_bytes(bytes: &[u8]) -> String {
+        if bytes.is_empty() {
+            return "nothing".to_owned();
+        }
+        if bytes.len() > Self::MAX_PRINTED_BYTES {
+            return format!("0x{}...", hex::encode(&bytes[..Self::MAX_PRINTED_BYTES]));
+        }
+        format!("0x{}", hex::encode(bytes))
+    }
+}
+
+impl std::fmt::Display for Difference {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "0x{:04x}..0x{:04x} ({} bytes): expected {}, found {}",
+            self.offset,
+            self.offset + self.size(),
+            self.size(),
+            Self::format_bytes(self.expected.as_slice()),
+            Self::format_bytes(self.actual.as_slice()),
+        )
+    }
+}
@@ -0,0 +1,228 @@
+//!
+//! The bytecode comparison.
+//!
+
+pub mod difference;
+
+use std::ops::Range;
+
+use self::difference::Difference;
+
+///
+/// The bytecode comparison.
+///
+/// Compares the recompiled bytecode with the given one, ignoring the immutable values and library
+/// addresses, as they are not known at compile time.
+///
+#[derive(Debug)]
+pub struct Comparison {
+    /// The full path of the contract.
+    pub contract_path: String,
+    /// The compared code segment.
+    pub code_segment: solx_utils::CodeSegment,
+    /// The recompiled bytecode size.
+    pub expected_size: usize,
+    /// The given bytecode size, excluding the constructor arguments.
+    pub actual_size: usize,
+    /// The size of the constructor arguments appended to the given deploy code.
+    pub constructor_arguments_size: usize,
+    /// The number of masked immutable references.
+    pub immutables_count: usize,
+    /// The number of masked library placeholders.
+    pub libraries_count: usize,
+    /// The recompiled CBOR metadata description.
+    pub expected_metadata: Option<String>,
+    /// The given CBOR metadata description.
+    pub actual_metadata: Option<String>,
+    /// Whether the code is identical, and only the CBOR metadata differs.
+    pub is_metadata_only: bool,
+    /// The differing byte ranges.
+    pub differences: Vec<Difference>,
+}
+
+impl Comparison {
+    /// The maximum number of differences printed.
+    pub const MAX_PRINTED_DIFFERENCES: usize = 16;
+
+    ///
+    /// Compares the `expected` recompiled bytecode with the `actual` given one.
+    ///
+    /// The `immutables` and `libraries` ranges are zeroed in both. The bytes of the given deploy code
+    /// beyond the size of the recompiled one are treated as constructor arguments.
+    ///
+    pub fn new(
+        contract_path: String,
+        code_segment: solx_utils::CodeSegment,
+        mut expected: Vec<u8>,
+        mut actual: Vec<u8>,
+        immutables: &[Range<usize>],
+        libraries: &[Range<usize>],
+    ) -> Self {
+        let constructor_arguments_size = match code_segment {
+            solx_utils::CodeSegment::Deploy => actual.len().saturating_sub(expected.len()),
+            solx_utils::CodeSegment::Runtime => 0,
+        };
+        actual.truncate(actual.len() - constructor_arguments_size);
+
+        for range in immutables.iter().chain(libraries.iter()) {
+            for bytecode in [&mut expected, &mut actual] {
+                let end = range.end.min(bytecode.len());
+                if range.start < end {
+                    bytecode[range.start..end].fill(0);
+                }
+            }
+        }
+
+        let differences = Self::differences(expected.as_slice(), actual.as_slice());
+        let (expected_metadata_size, expected_metadata) = Self::metadata(expected.as_slice());
+        let (actual_metadata_size, actual_metadata) = Self::metadata(actual.as_slice());
+        let expected_code = &expected[..expected.len() - expected_metadata_size];
+        let actual_code = &actual[..actual.len() - actual_metadata_size];
+        let is_metadata_only = !differences.is_empty() && expected_code == actual_code;
+
+        Self {
+            contract_path,
+            code_segment,
+            expected_size: expected.len(),
+            actual_size: actual.len(),
+            constructor_arguments_size,
+            immutables_count: immutables.len(),
+            libraries_count: libraries.len(),
+            expected_metadata,
+            actual_metadata,
+            is_metadata_only,
+            differences,
+        }
+    }
+
+    ///
+    /// Whether the bytecode matches.
+    ///
+    pub fn is_match(&self) -> bool {
+        self.differences.is_empty()
+    }
+
+    ///
+    /// Returns the contiguous ranges of bytes that differ between `expected` and `actual`.
+    ///
+    fn differences(expected: &[u8], actual: &[u8]) -> Vec<Difference> {
+        let mut differences = Vec::new();
+        let mut start: Option<usize> = None;
+        for offset in 0..=expected.len().max(actual.len()) {
+            let is_different = match (expected.get(offset), actual.get(offset)) {
+                (None, None) => false,
+                (expected, actual) => expected != actual,
+            };
+            match (start, is_different) {
+                (None, true) => start = Some(offset),
+                (Some(range_start), false) => {
+                    differences.push(Difference::new(
+                        range_start,
+                        expected[range_start.min(expected.len())..offset.min(expected.len())]
+                            .to_vec(),
+                        actual[range_start.min(actual.len())..offset.min(actual.len())].to_vec(),
+                    ));
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+        differences
+    }
+
+    ///
+    /// Returns the size and the description of the CBOR metadata at the end of `bytecode`.
+    ///
+    /// The size is zero if there is no valid CBOR metadata.
+    ///
+    fn metadata(bytecode: &[u8]) -> (usize, Option<String>) {
+        let cbor = match solx_utils::CBOR::from_bytecode(bytecode) {
+            Ok(cbor) => cbor,
+            Err(_) => return (0, None),
+        };
+        let size = u16::from_be_bytes(
+            bytecode[bytecode.len() - solx_utils::CBOR::<String>::LENGTH_SUFFIX_SIZE..]
+                .try_into()
+                .expect("Always valid"),
+        ) as usize
+            + solx_utils::CBOR::<String>::LENGTH_SUFFIX_SIZE;
+
+        let mut description = cbor
+            .version_data
+            .iter()
+            .map(|(name, version)| format!("{name}:{version}"))
+            .collect::<Vec<String>>()
+            .join(";");
+        if let Some((r#type, hash)) = cbor.hash {
+            description.push_str(format!(", {type} 0x{}", hex::encode(hash)).as_str());
+        }
+        (size, Some(description))
+    }
+}
+
+impl std::fmt::Display for Comparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.is_match() {
+            writeln!(
+                f,
+                "Contract `{}` {} code matches.",
+                self.contract_path, self.code_segment
+            )?;
+        } else {
+            writeln!(
+                f,
+                "Contract `{}` {} code does not match.",
+                self.contract_path, self.code_segment
+            )?;
+        }
+        if self.immutables_count > 0 || self.libraries_count > 0 {
+            writeln!(
+                f,
+                "Masked {} immutable references and {} library placeholders.",
+                self.immutables_count, self.libraries_count
+            )?;
+        }
+        if self.constructor_arguments_size > 0 {
+            writeln!(
+                f,
+                "Ignored {} trailing bytes as constructor arguments.",
+                self.constructor_arguments_size
+            )?;
+        }
+        if self.is_match() {
+            return Ok(());
+        }
+
+        if self.expected_size != self.actual_size {
+            writeln!(
+                f,
+                "Size: expected {} bytes, found {} bytes.",
+                self.expected_size, self.actual_size
+            )?;
+        }
+        if self.expected_metadata != self.actual_metadata {
+            writeln!(
+                f,
+                "CBOR metadata: expected {}, found {}.",
+                self.expected_metadata.as_deref().unwrap_or("none"),
+                self.actual_metadata.as_deref().unwrap_or("none"),
+            )?;
+        }
+        if self.is_metadata_only {
+            writeln!(f, "The code is identical, but the CBOR metadata differs, so the metadata file or the metadata hash type is not the one the bytecode was compiled with.")?;
+        }
+        writeln!(f, "Differences:")?;
+        for difference in self.differences.iter().take(Self::MAX_PRINTED_DIFFERENCES) {
+            writeln!(f, "  {difference}")?;
+        }
+        if self.differences.len() > Self::MAX_PRINTED_DIFFERENCES {
+            writeln!(
+                f,
+                "  ... and {} more.",
+                self.differences.len() - Self::MAX_PRINTED_DIFFERENCES
+            )?;
+        }
+
+        Ok(())
+    }
+}
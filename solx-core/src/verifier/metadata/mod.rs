@@ -0,0 +1,27 @@
+//!
+//! The contract metadata.
+//!
+
+pub mod settings;
+pub mod solx;
+pub mod source;
+
+use std::collections::BTreeMap;
+
+use self::settings::Settings;
+use self::source::Source;
+
+///
+/// The contract metadata.
+///
+/// Only includes the `solc` metadata fields required for reproducing the contract bytecode.
+///
+#[derive(Debug, serde::Deserialize)]
+pub struct Metadata {
+    /// The source code language.
+    pub language: solx_standard_json::InputLanguage,
+    /// The sources, mapped from their paths.
+    pub sources: BTreeMap<String, Source>,
+    /// The compiler settings.
+    pub settings: Settings,
+}
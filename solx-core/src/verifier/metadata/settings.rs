@@ -0,0 +1,33 @@
+//!
+//! The contract metadata settings.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+///
+/// The contract metadata settings.
+///
+/// Only includes the settings required for reproducing the contract bytecode.
+///
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Settings {
+    /// The contract the metadata belongs to, mapped from its source path.
+    pub compilation_target: BTreeMap<String, String>,
+    /// The linked library addresses, mapped from the full library paths.
+    #[serde(default)]
+    pub libraries: BTreeMap<String, String>,
+    /// The remappings.
+    #[serde(default)]
+    pub remappings: BTreeSet<String>,
+    /// The target EVM version.
+    #[serde(default)]
+    pub evm_version: Option<solx_utils::EVMVersion>,
+    /// Whether the contract was compiled via IR.
+    #[serde(default, rename = "viaIR")]
+    pub via_ir: bool,
+    /// The metadata settings.
+    #[serde(default)]
+    pub metadata: solx_standard_json::InputMetadata,
+}
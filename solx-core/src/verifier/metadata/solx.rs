@@ -0,0 +1,22 @@
+//!
+//! The contract metadata `solx` section.
+//!
+
+///
+/// The contract metadata `solx` section.
+///
+/// The owned counterpart of the section inserted by `solx`.
+///
+#[derive(Debug, serde::Deserialize)]
+pub struct Solx {
+    /// The `solc` version.
+    pub solc_version: Option<semver::Version>,
+    /// The LLVM `solc` revision.
+    pub solc_llvm_revision: Option<semver::Version>,
+    /// The `solx` compiler version.
+    pub solx_version: semver::Version,
+    /// The LLVM compiler optimizer settings.
+    pub optimizer_settings: solx_codegen_evm::OptimizerSettings,
+    /// The LLVM extra arguments.
+    pub llvm_options: Vec<String>,
+}
@@ -0,0 +1,19 @@
+//!
+//! The contract metadata source.
+//!
+
+///
+/// The contract metadata source.
+///
+/// The content is only embedded if the contract was compiled with the literal content flag.
+/// Otherwise, the source is identified by its hash.
+///
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Source {
+    /// The `keccak256` hash of the content.
+    pub keccak256: String,
+    /// The literal content.
+    #[serde(default)]
+    pub content: Option<String>,
+}
@@ -0,0 +1,298 @@
+//!
+//! The reproducible build verifier.
+//!
+
+pub mod comparison;
+pub mod metadata;
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::ops::Range;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::project::contract::metadata::Metadata as ContractMetadata;
+
+use self::comparison::Comparison;
+use self::metadata::solx::Solx as MetadataSolx;
+use self::metadata::Metadata;
+
+///
+/// The reproducible build verifier.
+///
+/// Reconstructs the standard JSON input from the contract metadata, and compares the bytecode
+/// recompiled from it with the given one.
+///
+#[derive(Debug)]
+pub struct Verifier {
+    /// The `solc` part of the contract metadata.
+    metadata: Metadata,
+    /// The `solx` section of the contract metadata.
+    solx: MetadataSolx,
+}
+
+impl Verifier {
+    ///
+    /// Reads the contract metadata JSON at `path`.
+    ///
+    pub fn try_from_path(path: &Path) -> anyhow::Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|error| anyhow::anyhow!("File {path:?} reading: {error}"))?;
+        let mut metadata: serde_json::Value = solx_utils::deserialize_from_str(content.as_str())
+            .map_err(|error| anyhow::anyhow!("File {path:?} parsing: {error}"))?;
+
+        let solx = metadata
+            .as_object_mut()
+            .and_then(|metadata| metadata.remove(ContractMetadata::KEY))
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "File {path:?} does not contain the `{}` section, so the contract was not compiled by {}.",
+                    ContractMetadata::KEY,
+                    crate::r#const::DEFAULT_EXECUTABLE_NAME,
+                )
+            })?;
+        let solx = serde_json::from_value(solx).map_err(|error| {
+            anyhow::anyhow!(
+                "File {path:?} `{}` section parsing: {error}",
+                ContractMetadata::KEY
+            )
+        })?;
+        let metadata = serde_json::from_value(metadata)
+            .map_err(|error| anyhow::anyhow!("File {path:?} parsing: {error}"))?;
+
+        Ok(Self { metadata, solx })
+    }
+
+    ///
+    /// Returns the source path and the name of the contract the metadata belongs to.
+    ///
+    pub fn compilation_target(&self) -> anyhow::Result<(&str, &str)> {
+        let mut compilation_target = self.metadata.settings.compilation_target.iter();
+        match (compilation_target.next(), compilation_target.next()) {
+            (Some((path, name)), None) => Ok((path.as_str(), name.as_str())),
+            _ => {
+                anyhow::bail!("The metadata compilation target must contain exactly one contract.")
+            }
+        }
+    }
+
+    ///
+    /// Returns the warnings about the compiler versions that differ from the ones in the metadata.
+    ///
+    pub fn version_warnings(&self, solc_version: &solx_standard_json::Version) -> Vec<String> {
+        let mut warnings = Vec::new();
+        let solx_version: semver::Version =
+            crate::r#const::version().parse().expect("Always valid");
+        if self.solx.solx_version != solx_version {
+            warnings.push(format!(
+                "The contract was compiled by {} v{}, whereas this is v{solx_version}.",
+                crate::r#const::DEFAULT_EXECUTABLE_NAME,
+                self.solx.solx_version,
+            ));
+        }
+        if let Some(ref version) = self.solx.solc_version {
+            if version != &solc_version.default {
+                warnings.push(format!(
+                    "The contract was compiled with solc v{version}, whereas this is v{}.",
+                    solc_version.default
+                ));
+            }
+        }
+        if let Some(ref revision) = self.solx.solc_llvm_revision {
+            if revision != &solc_version.llvm_revision {
+                warnings.push(format!(
+                    "The contract was compiled with solc LLVM revision v{revision}, whereas this is v{}.",
+                    solc_version.llvm_revision
+                ));
+            }
+        }
+        warnings
+    }
+
+    ///
+    /// Reconstructs the standard JSON input from the metadata.
+    ///
+    /// The sources without literal content are read from `source_roots`, and checked against their hashes.
+    /// The metadata hash type and the CBOR flag are not recorded in the metadata, so they must be provided.
+    ///
+    pub fn to_standard_json_input(
+        &self,
+        source_roots: &[PathBuf],
+        metadata_hash_type: solx_utils::MetadataHashType,
+        append_cbor: bool,
+    ) -> anyhow::Result<solx_standard_json::Input> {
+        let mut sources = BTreeMap::new();
+        for (path, source) in self.metadata.sources.iter() {
+            let content = match source.content {
+                Some(ref content) => content.to_owned(),
+                None => Self::read_source(path.as_str(), source_roots)?,
+            };
+            let hash = solx_utils::Keccak256Hash::from_slice(content.as_bytes());
+            if hash.as_str() != source.keccak256 {
+                anyhow::bail!(
+                    "Source `{path}` does not match the metadata: expected keccak256 {}, found {}.",
+                    source.keccak256,
+                    hash.as_str()
+                );
+            }
+            sources.insert(
+                path.to_owned(),
+                solx_standard_json::InputSource {
+                    content: Some(content),
+                    urls: None,
+                },
+            );
+        }
+
+        let mut libraries = BTreeMap::new();
+        for (full_path, address) in self.metadata.settings.libraries.iter() {
+            let (path, name) = full_path.rsplit_once(':').ok_or_else(|| {
+                anyhow::anyhow!("Library `{full_path}` contract name is missing.")
+            })?;
+            libraries
+                .entry(path.to_owned())
+                .or_insert_with(BTreeMap::new)
+                .insert(name.to_owned(), address.to_owned());
+        }
+
+        let optimizer = solx_standard_json::InputOptimizer::new(
+            self.solx.optimizer_settings.middle_end_as_char(),
+            self.solx.optimizer_settings.is_fallback_to_size_enabled,
+        );
+        let output_selection = solx_standard_json::InputSelection::new(BTreeSet::from([
+            solx_standard_json::InputSelector::BytecodeObject,
+            solx_standard_json::InputSelector::BytecodeLinkReferences,
+            solx_standard_json::InputSelector::RuntimeBytecodeObject,
+            solx_standard_json::InputSelector::RuntimeBytecodeLinkReferences,
+            solx_standard_json::InputSelector::RuntimeBytecodeImmutableReferences,
+        ]));
+        let metadata = solx_standard_json::InputMetadata::new(
+            self.metadata.settings.metadata.use_literal_content,
+            append_cbor,
+            metadata_hash_type,
+        );
+
+        Ok(solx_standard_json::Input {
+            language: self.metadata.language,
+            sources,
+            settings: solx_standard_json::InputSettings::new(
+                optimizer,
+                solx_utils::Libraries::from(libraries),
+                self.metadata.settings.remappings.clone(),
+                self.metadata.settings.evm_version,
+                self.metadata.settings.via_ir,
+                output_selection,
+                metadata,
+                self.solx.llvm_options.clone(),
+            ),
+        })
+    }
+
+    ///
+    /// Compares the recompiled contract in `output` with the given `bytecode`.
+    ///
+    /// The bytecode is compared with the runtime code if their sizes are equal, or if it is too
+    /// short to be the deploy code. Otherwise, it is compared with the deploy code, and the trailing
+    /// bytes are treated as constructor arguments.
+    ///
+    pub fn compare(
+        &self,
+        output: &solx_standard_json::Output,
+        bytecode: Vec<u8>,
+    ) -> anyhow::Result<Comparison> {
+        let (path, name) = self.compilation_target()?;
+        let contract_path = format!("{path}:{name}");
+        let evm = output
+            .contracts
+            .get(path)
+            .and_then(|contracts| contracts.get(name))
+            .and_then(|contract| contract.evm.as_ref())
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Contract `{contract_path}` is missing in the recompilation output."
+                )
+            })?;
+        let (deploy_code, runtime_code) =
+            match (evm.bytecode.as_ref(), evm.deployed_bytecode.as_ref()) {
+                (Some(deploy_code), Some(runtime_code)) => (deploy_code, runtime_code),
+                _ => anyhow::bail!(
+                    "Contract `{contract_path}` bytecode is missing in the recompilation output."
+                ),
+            };
+
+        let deploy_size = deploy_code.object.as_deref().unwrap_or_default().len() / 2;
+        let runtime_size = runtime_code.object.as_deref().unwrap_or_default().len() / 2;
+        let (code_segment, expected) =
+            if bytecode.len() != runtime_size && bytecode.len() >= deploy_size {
+                (solx_utils::CodeSegment::Deploy, deploy_code)
+            } else {
+                (solx_utils::CodeSegment::Runtime, runtime_code)
+            };
+
+        let immutables: Vec<Range<usize>> = expected
+            .immutable_references
+            .iter()
+            .flat_map(|references| references.values().flatten())
+            .map(|reference| reference.start as usize..reference.start as usize + reference.length)
+            .collect();
+        let libraries: Vec<Range<usize>> = expected
+            .link_references
+            .iter()
+            .flat_map(|references| {
+                references
+                    .values()
+                    .flat_map(|names| names.values().flatten())
+            })
+            .map(|reference| reference.start as usize..reference.start as usize + reference.length)
+            .collect();
+
+        let mut expected = expected
+            .object
+            .as_deref()
+            .unwrap_or_default()
+            .as_bytes()
+            .to_vec();
+        for range in libraries.iter() {
+            for character in expected
+                .iter_mut()
+                .take(range.end * 2)
+                .skip(range.start * 2)
+            {
+                *character = b'0';
+            }
+        }
+        let expected = hex::decode(expected).map_err(|error| {
+            anyhow::anyhow!("Contract `{contract_path}` recompiled bytecode decoding: {error}")
+        })?;
+
+        Ok(Comparison::new(
+            contract_path,
+            code_segment,
+            expected,
+            bytecode,
+            immutables.as_slice(),
+            libraries.as_slice(),
+        ))
+    }
+
+    ///
+    /// Reads the source at `path` from the first of `source_roots` that contains it.
+    ///
+    fn read_source(path: &str, source_roots: &[PathBuf]) -> anyhow::Result<String> {
+        for source_root in source_roots.iter() {
+            let full_path = source_root.join(path);
+            if full_path.is_file() {
+                return std::fs::read_to_string(full_path.as_path())
+                    .map_err(|error| anyhow::anyhow!("File {full_path:?} reading: {error}"));
+            }
+        }
+        anyhow::bail!(
+            "Source `{path}` content is not embedded in the metadata, and the file is not found in {}.",
+            source_roots
+                .iter()
+                .map(|source_root| format!("{source_root:?}"))
+                .collect::<Vec<String>>()
+                .join(", ")
+        );
+    }
+}
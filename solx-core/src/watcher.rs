@@ -0,0 +1,98 @@
+//!
+//! The source file watcher.
+//!
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::SystemTime;
+
+///
+/// The source file watcher.
+///
+/// Polls the modification times of the watched files, which is portable and cheap enough
+/// for the number of sources in a typical project.
+///
+#[derive(Debug, Default)]
+pub struct Watcher {
+    /// The watched files and their last observed modification times.
+    files: BTreeMap<PathBuf, Option<SystemTime>>,
+}
+
+impl Watcher {
+    /// The interval between two polls of the watched files.
+    pub const POLLING_INTERVAL: Duration = Duration::from_millis(500);
+
+    ///
+    /// A shortcut constructor.
+    ///
+    /// The modification times are recorded immediately, so the changes made afterwards are detected.
+    ///
+    pub fn new(paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        let files = paths
+            .into_iter()
+            .map(|path| {
+                let modified = Self::modified(path.as_path());
+                (path, modified)
+            })
+            .collect();
+        Self { files }
+    }
+
+    ///
+    /// Replaces the watched files with `paths`.
+    ///
+    /// The modification times of the files watched before are kept, so the changes made since
+    /// they were recorded are still detected. The new files modified after `since` are reported
+    /// as changed by the next `wait`.
+    ///
+    pub fn update(&mut self, paths: impl IntoIterator<Item = PathBuf>, since: SystemTime) {
+        let mut files = BTreeMap::new();
+        for path in paths.into_iter() {
+            let modified = match self.files.remove(path.as_path()) {
+                Some(modified) => modified,
+                None => Self::modified(path.as_path()).filter(|modified| *modified <= since),
+            };
+            files.insert(path, modified);
+        }
+        self.files = files;
+    }
+
+    ///
+    /// Returns the watched files.
+    ///
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.files.keys().map(PathBuf::as_path)
+    }
+
+    ///
+    /// Blocks until at least one of the watched files is modified, created, or removed.
+    ///
+    /// Returns the changed files.
+    ///
+    pub fn wait(&self) -> Vec<PathBuf> {
+        loop {
+            std::thread::sleep(Self::POLLING_INTERVAL);
+
+            let changed = self
+                .files
+                .iter()
+                .filter(|(path, modified)| Self::modified(path.as_path()) != **modified)
+                .map(|(path, _)| path.to_owned())
+                .collect::<Vec<PathBuf>>();
+            if !changed.is_empty() {
+                return changed;
+            }
+        }
+    }
+
+    ///
+    /// Returns the modification time of the file at `path`, or `None` if it does not exist.
+    ///
+    fn modified(path: &Path) -> Option<SystemTime> {
+        std::fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+    }
+}
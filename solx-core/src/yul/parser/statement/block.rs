@@ -41,6 +41,12 @@ impl solx_codegen_evm::WriteLLVM for Block {
                 break;
             }
 
+            let location = statement.location();
+            let source_location = context
+                .yul()
+                .and_then(|data| data.source_location(location.line, location.column));
+            context.set_source_location(source_location);
+
             match statement {
                 Statement::Block(block) => {
                     block.wrap().into_llvm(context)?;
@@ -25,6 +25,27 @@ impl FunctionCall {
     ) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>> {
         let location = self.0.location;
 
+        if let Some(evm_version) = self.0.name.reserved_since() {
+            let identifier = solx_yul::yul::printer::name_identifier(&self.0.name);
+            if context.evm_version() < evm_version
+                && context.get_function(identifier.as_str()).is_some()
+            {
+                self.0.name = Name::UserDefined(identifier);
+            }
+        }
+
+        let versioned_opcode = match self.0.name {
+            Name::UserDefined(_) => None,
+            ref name => solx_utils::Opcode::versioned(
+                solx_yul::yul::printer::name_identifier(name).as_str(),
+            ),
+        };
+        if let Some(opcode) = versioned_opcode {
+            context
+                .check_opcode(opcode.0)
+                .map_err(|error| anyhow::anyhow!("{location} {error}"))?;
+        }
+
         match self.0.name {
             Name::UserDefined(name) => {
                 let mut values = Vec::with_capacity(self.0.arguments.len());
@@ -247,6 +268,14 @@ impl FunctionCall {
                 )
                 .map(Some)
             }
+            Name::Clz => {
+                let arguments = self.pop_arguments_llvm::<1>(context)?;
+                solx_codegen_evm::bitwise::count_leading_zeros(
+                    context,
+                    arguments[0].into_int_value(),
+                )
+                .map(Some)
+            }
             Name::Byte => {
                 let arguments = self.pop_arguments_llvm::<2>(context)?;
                 solx_codegen_evm::bitwise::byte(
@@ -710,11 +739,20 @@ impl FunctionCall {
 
                 solx_codegen_evm::contract_context::block_hash(context, index).map(Some)
             }
+            Name::BlobHash => {
+                let arguments = self.pop_arguments_llvm::<1>(context)?;
+                let index = arguments[0].into_int_value();
+
+                solx_codegen_evm::contract_context::blob_hash(context, index).map(Some)
+            }
             Name::Difficulty | Name::Prevrandao => {
                 solx_codegen_evm::contract_context::difficulty(context).map(Some)
             }
             Name::CoinBase => solx_codegen_evm::contract_context::coinbase(context).map(Some),
             Name::BaseFee => solx_codegen_evm::contract_context::basefee(context).map(Some),
+            Name::BlobBaseFee => {
+                solx_codegen_evm::contract_context::blob_basefee(context).map(Some)
+            }
             Name::MSize => solx_codegen_evm::contract_context::msize(context).map(Some),
 
             Name::UnsafeAsm => {
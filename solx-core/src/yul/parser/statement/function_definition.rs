@@ -4,6 +4,7 @@
 
 use inkwell::types::BasicType;
 use solx_codegen_evm::IContext;
+use solx_yul::yul::parser::statement::expression::function_call::name::Name as FunctionName;
 
 use crate::declare_wrapper;
 use crate::yul::parser::dialect::era::EraDialect;
@@ -16,6 +17,17 @@ declare_wrapper!(
 
 impl solx_codegen_evm::WriteLLVM for FunctionDefinition {
     fn declare(&mut self, context: &mut solx_codegen_evm::Context) -> anyhow::Result<()> {
+        if FunctionName::from(self.0.identifier.as_str())
+            .reserved_since()
+            .is_some_and(|evm_version| context.evm_version() >= evm_version)
+        {
+            anyhow::bail!(
+                "{} The identifier `{}` is reserved",
+                self.0.location,
+                self.0.identifier
+            );
+        }
+
         let argument_types: Vec<_> = self
             .0
             .arguments
@@ -5,6 +5,7 @@
 use inkwell::types::BasicType;
 use inkwell::values::BasicValue;
 use solx_codegen_evm::IContext;
+use solx_yul::yul::parser::statement::expression::function_call::name::Name as FunctionName;
 
 use crate::declare_wrapper;
 use crate::yul::parser::wrapper::Wrap;
@@ -19,6 +20,19 @@ impl solx_codegen_evm::WriteLLVM for VariableDeclaration {
         mut self,
         context: &mut solx_codegen_evm::Context<'ctx>,
     ) -> anyhow::Result<()> {
+        for binding in self.0.bindings.iter() {
+            if FunctionName::from(binding.inner.as_str())
+                .reserved_since()
+                .is_some_and(|evm_version| context.evm_version() >= evm_version)
+            {
+                anyhow::bail!(
+                    "{} The identifier `{}` is reserved",
+                    binding.location,
+                    binding.inner
+                );
+            }
+        }
+
         if self.0.bindings.len() == 1 {
             let identifier = self.0.bindings.remove(0);
             let r#type = identifier
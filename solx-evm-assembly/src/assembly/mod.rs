@@ -14,6 +14,7 @@ use twox_hash::XxHash3_64;
 
 use solx_codegen_evm::IContext;
 
+use crate::ethereal_ir::function::graph::Graph as FunctionGraph;
 use crate::ethereal_ir::EtherealIR;
 use crate::extra_metadata::ExtraMetadata;
 
@@ -40,7 +41,11 @@ pub struct Assembly {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub full_path: Option<String>,
     /// The EVM legacy assembly extra metadata.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        alias = "extraMetadata",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub extra_metadata: Option<ExtraMetadata>,
 }
 
@@ -166,23 +171,6 @@ impl Assembly {
     pub fn preprocess_dependencies(
         contracts: BTreeMap<String, BTreeMap<String, &mut Self>>,
     ) -> anyhow::Result<()> {
-        let mut hash_path_mapping = BTreeMap::new();
-
-        for (path, file) in contracts.iter() {
-            for (name, deploy_code_assembly) in file.iter() {
-                let deploy_code_path = format!("{path}:{name}");
-                let deploy_code_hash = deploy_code_assembly.hash();
-
-                let runtime_code_path =
-                    format!("{path}:{name}.{}", solx_utils::CodeSegment::Runtime);
-                let runtime_code_assembly = deploy_code_assembly.runtime_code()?;
-                let runtime_code_hash = runtime_code_assembly.hash();
-
-                hash_path_mapping.insert(deploy_code_hash, deploy_code_path);
-                hash_path_mapping.insert(runtime_code_hash, runtime_code_path);
-            }
-        }
-
         let mut assemblies = BTreeMap::new();
         for (path, file) in contracts.into_iter() {
             for (name, assembly) in file.into_iter() {
@@ -190,6 +178,30 @@ impl Assembly {
                 assemblies.insert(full_path, assembly);
             }
         }
+        Self::preprocess_full_path_dependencies(assemblies)
+    }
+
+    ///
+    /// Replaces with dependency indexes with actual data.
+    ///
+    /// The `assemblies` are keyed by their full contract paths.
+    ///
+    pub fn preprocess_full_path_dependencies(
+        assemblies: BTreeMap<String, &mut Self>,
+    ) -> anyhow::Result<()> {
+        let mut hash_path_mapping = BTreeMap::new();
+
+        for (full_path, deploy_code_assembly) in assemblies.iter() {
+            let deploy_code_hash = deploy_code_assembly.hash();
+
+            let runtime_code_path = format!("{full_path}.{}", solx_utils::CodeSegment::Runtime);
+            let runtime_code_assembly = deploy_code_assembly.runtime_code()?;
+            let runtime_code_hash = runtime_code_assembly.hash();
+
+            hash_path_mapping.insert(deploy_code_hash, full_path.to_owned());
+            hash_path_mapping.insert(runtime_code_hash, runtime_code_path);
+        }
+
         assemblies
             .into_par_iter()
             .map(|(full_path, assembly)| {
@@ -205,6 +217,31 @@ impl Assembly {
         Ok(())
     }
 
+    ///
+    /// Returns the assemblies of the contracts created by this one, named as in the `solc` text assembly.
+    ///
+    /// The deploy code dependencies are named `sub_<index>`, and the runtime code ones `sub_0.sub_<index>`.
+    ///
+    pub fn sub_assemblies(&self) -> Vec<(String, &Assembly)> {
+        let mut sub_assemblies = Vec::new();
+        for (index, data) in self.data.iter().flatten() {
+            if index == "0" {
+                continue;
+            }
+            if let Some(assembly) = data.get_assembly() {
+                sub_assemblies.push((format!("sub_{index}"), assembly));
+            }
+        }
+        if let Ok(runtime_code) = self.runtime_code() {
+            for (index, data) in runtime_code.data.iter().flatten() {
+                if let Some(assembly) = data.get_assembly() {
+                    sub_assemblies.push((format!("sub_0.sub_{index}"), assembly));
+                }
+            }
+        }
+        sub_assemblies
+    }
+
     ///
     /// Preprocesses an assembly JSON structure dependency data map.
     ///
@@ -399,6 +436,15 @@ impl solx_codegen_evm::WriteLLVM for Assembly {
                 path.push_str(format!(".{code_segment}").as_str());
             }
             debug_config.dump_ethir(path.as_str(), ethereal_ir.to_string().as_str())?;
+            for function in std::iter::once(&ethereal_ir.entry_function)
+                .chain(ethereal_ir.defined_functions.values())
+            {
+                debug_config.dump_ethir_graph(
+                    path.as_str(),
+                    function.name.as_str(),
+                    FunctionGraph::new(function).to_string().as_str(),
+                )?;
+            }
         }
 
         let mut entry = solx_codegen_evm::EntryFunction::new(ethereal_ir);
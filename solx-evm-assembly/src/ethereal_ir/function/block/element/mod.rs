@@ -76,6 +76,18 @@ impl Element {
 
 impl solx_codegen_evm::WriteLLVM for Element {
     fn into_llvm(mut self, context: &mut solx_codegen_evm::Context) -> anyhow::Result<()> {
+        context.set_source_location(Some(solx_codegen_evm::SourceLocation::new_with_range(
+            self.instruction.begin,
+            self.instruction.end,
+            self.instruction.source.unwrap_or(-1),
+        )));
+
+        if let Some(opcode) =
+            solx_utils::Opcode::versioned(self.instruction.name.to_string().as_str())
+        {
+            context.check_opcode(opcode.0)?;
+        }
+
         let mut original = self.instruction.value.clone();
 
         let result = match self.instruction.name.clone() {
@@ -613,6 +625,14 @@ impl solx_codegen_evm::WriteLLVM for Element {
                 )
                 .map(Some)
             }
+            InstructionName::CLZ => {
+                let arguments = self.pop_arguments_llvm(context)?;
+                solx_codegen_evm::bitwise::count_leading_zeros(
+                    context,
+                    arguments[0].into_int_value(),
+                )
+                .map(Some)
+            }
             InstructionName::BYTE => {
                 let arguments = self.pop_arguments_llvm(context)?;
                 solx_codegen_evm::bitwise::byte(
@@ -1055,8 +1075,10 @@ impl solx_codegen_evm::WriteLLVM for Element {
                 solx_codegen_evm::contract_context::block_hash(context, index).map(Some)
             }
             InstructionName::BLOBHASH => {
-                let _arguments = self.pop_arguments_llvm(context)?;
-                anyhow::bail!("The `BLOBHASH` instruction is not supported");
+                let arguments = self.pop_arguments_llvm(context)?;
+                let index = arguments[0].into_int_value();
+
+                solx_codegen_evm::contract_context::blob_hash(context, index).map(Some)
             }
             InstructionName::DIFFICULTY | InstructionName::PREVRANDAO => {
                 solx_codegen_evm::contract_context::difficulty(context).map(Some)
@@ -1068,7 +1090,7 @@ impl solx_codegen_evm::WriteLLVM for Element {
                 solx_codegen_evm::contract_context::basefee(context).map(Some)
             }
             InstructionName::BLOBBASEFEE => {
-                anyhow::bail!("The `BLOBBASEFEE` instruction is not supported");
+                solx_codegen_evm::contract_context::blob_basefee(context).map(Some)
             }
             InstructionName::MSIZE => solx_codegen_evm::contract_context::msize(context).map(Some),
 
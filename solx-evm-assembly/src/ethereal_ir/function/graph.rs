@@ -0,0 +1,163 @@
+//!
+//! The Ethereal IR function control-flow graph.
+//!
+
+use num::Zero;
+
+use crate::assembly::instruction::name::Name as InstructionName;
+use crate::ethereal_ir::function::r#type::Type;
+use crate::ethereal_ir::function::Function;
+
+///
+/// The Ethereal IR function control-flow graph.
+///
+/// Rendered in the Graphviz DOT format, with a node for each block instance and an edge from each
+/// of its predecessors. The nodes are labeled with the stack state at the block entry, and the
+/// recursive function calls and returns taken from the `solc` extra metadata are highlighted.
+///
+#[derive(Debug)]
+pub struct Graph<'a> {
+    /// The function to render.
+    function: &'a Function,
+}
+
+impl<'a> Graph<'a> {
+    /// The color of the blocks with recursive function calls and returns.
+    pub const RECURSIVE_COLOR: &'static str = "red";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(function: &'a Function) -> Self {
+        Self { function }
+    }
+
+    ///
+    /// Returns the node identifier of the block instance.
+    ///
+    fn node(block_key: &solx_codegen_evm::BlockKey, instance: usize) -> String {
+        format!("\"{block_key}/{instance}\"")
+    }
+
+    ///
+    /// Escapes the quotes and backslashes in a DOT string.
+    ///
+    fn escape(string: &str) -> String {
+        string.replace('\\', "\\\\").replace('"', "\\\"")
+    }
+}
+
+impl std::fmt::Display for Graph<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (title, entry_key) = match self.function.r#type {
+            Type::Entry => (format!("function {}", self.function.name), None),
+            Type::Defined {
+                ref block_key,
+                input_size,
+                output_size,
+                ..
+            } => (
+                format!(
+                    "recursive function {}({input_size}) -> {output_size}",
+                    self.function.name
+                ),
+                Some(block_key),
+            ),
+        };
+
+        writeln!(
+            f,
+            "digraph \"{}\" {{",
+            Self::escape(self.function.name.as_str())
+        )?;
+        writeln!(
+            f,
+            "    label=\"{}\\lstack usage: {}\\l\";",
+            Self::escape(title.as_str()),
+            self.function.stack_size
+        )?;
+        writeln!(f, "    labelloc=t;")?;
+        writeln!(f, "    node [shape=box, fontname=\"monospace\"];")?;
+
+        for blocks in self.function.blocks.values() {
+            for block in blocks.iter() {
+                let instance = block.instance.unwrap_or_default();
+                let node = Self::node(&block.key, instance);
+
+                let mut label = format!(
+                    "block_{}/{instance}\\lentry stack ({}): {}\\linstructions: {}\\l",
+                    block.key,
+                    block.initial_stack.len(),
+                    Self::escape(block.initial_stack.to_string().as_str()),
+                    block.elements.len(),
+                );
+                let mut callees = Vec::new();
+                let mut is_recursive = false;
+                for element in block.elements.iter() {
+                    match element.instruction.name {
+                        InstructionName::RecursiveCall {
+                            ref name,
+                            ref entry_key,
+                            ..
+                        } => {
+                            let callee = format!("{name}_{entry_key}");
+                            label.push_str(
+                                format!("recursive call {}\\l", Self::escape(callee.as_str()))
+                                    .as_str(),
+                            );
+                            callees.push(callee);
+                            is_recursive = true;
+                        }
+                        InstructionName::RecursiveReturn { .. } => {
+                            label.push_str("recursive return\\l");
+                            is_recursive = true;
+                        }
+                        _ => {}
+                    }
+                }
+
+                let is_entry = match entry_key {
+                    Some(entry_key) => &block.key == entry_key && instance == 0,
+                    None => block.key.tag.is_zero(),
+                };
+                let mut attributes = format!("label=\"{label}\"");
+                if is_entry {
+                    attributes.push_str(", penwidth=2");
+                }
+                if is_recursive {
+                    attributes.push_str(format!(", color={}", Self::RECURSIVE_COLOR).as_str());
+                }
+                writeln!(f, "    {node} [{attributes}];")?;
+
+                for callee in callees.into_iter() {
+                    let callee = Self::escape(callee.as_str());
+                    writeln!(
+                        f,
+                        "    \"{callee}\" [shape=ellipse, style=dashed, color={}];",
+                        Self::RECURSIVE_COLOR
+                    )?;
+                    writeln!(
+                        f,
+                        "    {node} -> \"{callee}\" [style=dashed, color={}];",
+                        Self::RECURSIVE_COLOR
+                    )?;
+                }
+            }
+        }
+
+        for blocks in self.function.blocks.values() {
+            for block in blocks.iter() {
+                let node = Self::node(&block.key, block.instance.unwrap_or_default());
+                for (predecessor_key, predecessor_instance) in block.predecessors.iter() {
+                    writeln!(
+                        f,
+                        "    {} -> {node};",
+                        Self::node(predecessor_key, *predecessor_instance)
+                    )?;
+                }
+            }
+        }
+
+        writeln!(f, "}}")
+    }
+}
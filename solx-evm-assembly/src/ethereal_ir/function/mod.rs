@@ -3,6 +3,7 @@
 //!
 
 pub mod block;
+pub mod graph;
 pub mod queue_element;
 pub mod r#type;
 pub mod visited_element;
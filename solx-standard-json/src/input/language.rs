@@ -14,4 +14,6 @@ pub enum Language {
     /// LLVM IR.
     #[serde(rename = "LLVM IR")]
     LLVMIR,
+    /// EVM legacy assembly JSON.
+    EVMAssembly,
 }
@@ -253,4 +253,62 @@ impl Input {
             ),
         }
     }
+
+    ///
+    /// A shortcut constructor from paths to EVM legacy assembly JSON files.
+    ///
+    pub fn from_evmla_paths(
+        paths: &[PathBuf],
+        libraries: solx_utils::Libraries,
+        optimizer: InputSettingsOptimizer,
+        output_selection: &InputSettingsSelection,
+        metadata: InputSettingsMetadata,
+        llvm_options: Vec<String>,
+    ) -> Self {
+        let sources = paths
+            .iter()
+            .map(|path| {
+                (
+                    path.to_string_lossy().to_string(),
+                    Source::from(path.as_path()),
+                )
+            })
+            .collect();
+
+        Self::from_evmla_sources(
+            sources,
+            libraries,
+            optimizer,
+            output_selection,
+            metadata,
+            llvm_options,
+        )
+    }
+
+    ///
+    /// A shortcut constructor from EVM legacy assembly JSON source code.
+    ///
+    pub fn from_evmla_sources(
+        sources: BTreeMap<String, Source>,
+        libraries: solx_utils::Libraries,
+        optimizer: InputSettingsOptimizer,
+        output_selection: &InputSettingsSelection,
+        metadata: InputSettingsMetadata,
+        llvm_options: Vec<String>,
+    ) -> Self {
+        Self {
+            language: Language::EVMAssembly,
+            sources,
+            settings: Settings::new(
+                optimizer,
+                libraries,
+                BTreeSet::new(),
+                None,
+                false,
+                output_selection.to_owned(),
+                metadata,
+                llvm_options,
+            ),
+        }
+    }
 }
@@ -0,0 +1,22 @@
+//!
+//! The `solc --standard-json` input settings contract override.
+//!
+
+///
+/// The `solc --standard-json` input settings contract override.
+///
+/// Overrides the global optimizer settings and LLVM options for a source file or contract.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractOverride {
+    /// The optimization mode string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<char>,
+    /// Whether to try to recompile with -Oz if the bytecode is too large.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size_fallback: Option<bool>,
+    /// The extra LLVM options, replacing the global ones.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub llvm_options: Option<Vec<String>>,
+}
@@ -2,12 +2,16 @@
 //! The `solc --standard-json` input settings.
 //!
 
+pub mod contract_override;
 pub mod metadata;
 pub mod optimizer;
 pub mod selection;
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
+use std::path::PathBuf;
 
+use self::contract_override::ContractOverride;
 use self::metadata::Metadata;
 use self::optimizer::Optimizer;
 use self::selection::Selection;
@@ -50,6 +54,16 @@ pub struct Settings {
     /// The extra LLVM options.
     #[serde(default, skip_serializing)]
     pub llvm_options: Vec<String>,
+    /// The compilation cache directory.
+    #[serde(default, skip_serializing)]
+    pub cache_dir: Option<PathBuf>,
+    /// The bytecode size limits.
+    #[serde(default, skip_serializing)]
+    pub code_size_limits: solx_utils::CodeSizeLimits,
+    /// The optimizer settings and LLVM options overrides.
+    /// Keys are either source paths or full contract paths in the `<path>:<name>` format.
+    #[serde(default, skip_serializing)]
+    pub contract_overrides: BTreeMap<String, ContractOverride>,
 }
 
 impl Settings {
@@ -84,6 +98,9 @@ impl Settings {
             output_selection,
             metadata,
             llvm_options,
+            cache_dir: None,
+            code_size_limits: solx_utils::CodeSizeLimits::default(),
+            contract_overrides: BTreeMap::new(),
         }
     }
 
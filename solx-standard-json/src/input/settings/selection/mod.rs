@@ -79,11 +79,15 @@ impl Selection {
                     }
                     Selector::BytecodeObject
                     | Selector::BytecodeLLVMAssembly
+                    | Selector::BytecodeLLVMIR
+                    | Selector::BytecodeLLVMIROptimized
+                    | Selector::BytecodeRemarks
                     | Selector::BytecodeOpcodes
                     | Selector::BytecodeLinkReferences
                     | Selector::BytecodeSourceMap
                     | Selector::BytecodeFunctionDebugData
                     | Selector::BytecodeGeneratedSources
+                    | Selector::BytecodeSize
                         if contract.contains(&Selector::Bytecode)
                             || contract.contains(&Selector::EVM) =>
                     {
@@ -91,12 +95,16 @@ impl Selection {
                     }
                     Selector::RuntimeBytecodeObject
                     | Selector::RuntimeBytecodeLLVMAssembly
+                    | Selector::RuntimeBytecodeLLVMIR
+                    | Selector::RuntimeBytecodeLLVMIROptimized
+                    | Selector::RuntimeBytecodeRemarks
                     | Selector::RuntimeBytecodeOpcodes
                     | Selector::RuntimeBytecodeLinkReferences
                     | Selector::RuntimeBytecodeImmutableReferences
                     | Selector::RuntimeBytecodeSourceMap
                     | Selector::RuntimeBytecodeFunctionDebugData
                     | Selector::RuntimeBytecodeGeneratedSources
+                    | Selector::RuntimeBytecodeSize
                         if contract.contains(&Selector::RuntimeBytecode)
                             || contract.contains(&Selector::EVM) =>
                     {
@@ -171,8 +179,18 @@ impl Selection {
                 if contract.contains(&Selector::EVM)
                     || contract.contains(&Selector::Bytecode)
                     || contract.contains(&Selector::BytecodeObject)
+                    || contract.contains(&Selector::BytecodeOpcodes)
+                    || contract.contains(&Selector::BytecodeSourceMap)
+                    || contract.contains(&Selector::BytecodeFunctionDebugData)
+                    || contract.contains(&Selector::BytecodeSize)
                     || contract.contains(&Selector::RuntimeBytecode)
                     || contract.contains(&Selector::RuntimeBytecodeObject)
+                    || contract.contains(&Selector::RuntimeBytecodeOpcodes)
+                    || contract.contains(&Selector::RuntimeBytecodeImmutableReferences)
+                    || contract.contains(&Selector::RuntimeBytecodeSourceMap)
+                    || contract.contains(&Selector::RuntimeBytecodeFunctionDebugData)
+                    || contract.contains(&Selector::RuntimeBytecodeSize)
+                    || contract.contains(&Selector::GasEstimates)
                     || contract.contains(&Selector::Any)
                 {
                     return true;
@@ -182,6 +200,18 @@ impl Selection {
         false
     }
 
+    ///
+    /// Checks if the single-item `selector` is requested for at least one contract.
+    ///
+    /// Must be called on a normalized selection.
+    ///
+    pub fn is_set_for_any(&self, selector: Selector) -> bool {
+        self.inner.values().any(|file| {
+            file.values()
+                .any(|contract| contract.contains(&selector) || contract.contains(&Selector::Any))
+        })
+    }
+
     ///
     /// Whether the selection is empty.
     ///
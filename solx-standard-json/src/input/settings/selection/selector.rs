@@ -55,6 +55,15 @@ pub enum Selector {
     /// The deploy LLVM assembly.
     #[serde(rename = "evm.bytecode.llvmAssembly")]
     BytecodeLLVMAssembly,
+    /// The deploy unoptimized LLVM IR.
+    #[serde(rename = "evm.bytecode.llvmIr")]
+    BytecodeLLVMIR,
+    /// The deploy optimized LLVM IR.
+    #[serde(rename = "evm.bytecode.llvmIrOptimized")]
+    BytecodeLLVMIROptimized,
+    /// The deploy optimization remarks.
+    #[serde(rename = "evm.bytecode.remarks")]
+    BytecodeRemarks,
     /// The deploy bytecode opcodes.
     #[serde(rename = "evm.bytecode.opcodes")]
     BytecodeOpcodes,
@@ -70,6 +79,9 @@ pub enum Selector {
     /// The deploy bytecode generated sources.
     #[serde(rename = "evm.bytecode.generatedSources")]
     BytecodeGeneratedSources,
+    /// The deploy bytecode size breakdown.
+    #[serde(rename = "evm.bytecode.size")]
+    BytecodeSize,
     /// The runtime bytecode.
     #[serde(rename = "evm.deployedBytecode")]
     RuntimeBytecode,
@@ -79,6 +91,15 @@ pub enum Selector {
     /// The runtime LLVM assembly.
     #[serde(rename = "evm.deployedBytecode.llvmAssembly")]
     RuntimeBytecodeLLVMAssembly,
+    /// The runtime unoptimized LLVM IR.
+    #[serde(rename = "evm.deployedBytecode.llvmIr")]
+    RuntimeBytecodeLLVMIR,
+    /// The runtime optimized LLVM IR.
+    #[serde(rename = "evm.deployedBytecode.llvmIrOptimized")]
+    RuntimeBytecodeLLVMIROptimized,
+    /// The runtime optimization remarks.
+    #[serde(rename = "evm.deployedBytecode.remarks")]
+    RuntimeBytecodeRemarks,
     /// The runtime bytecode opcodes.
     #[serde(rename = "evm.deployedBytecode.opcodes")]
     RuntimeBytecodeOpcodes,
@@ -97,6 +118,9 @@ pub enum Selector {
     /// The runtime bytecode generated sources.
     #[serde(rename = "evm.deployedBytecode.generatedSources")]
     RuntimeBytecodeGeneratedSources,
+    /// The runtime bytecode size breakdown.
+    #[serde(rename = "evm.deployedBytecode.size")]
+    RuntimeBytecodeSize,
     /// The gas estimates.
     #[serde(rename = "evm.gasEstimates")]
     GasEstimates,
@@ -118,18 +142,26 @@ impl Selector {
                 | Self::Bytecode
                 | Self::BytecodeObject
                 | Self::BytecodeLLVMAssembly
+                | Self::BytecodeLLVMIR
+                | Self::BytecodeLLVMIROptimized
+                | Self::BytecodeRemarks
                 | Self::BytecodeLinkReferences
                 | Self::BytecodeSourceMap
                 | Self::BytecodeFunctionDebugData
                 | Self::BytecodeGeneratedSources
+                | Self::BytecodeSize
                 | Self::RuntimeBytecode
                 | Self::RuntimeBytecodeObject
                 | Self::RuntimeBytecodeLLVMAssembly
+                | Self::RuntimeBytecodeLLVMIR
+                | Self::RuntimeBytecodeLLVMIROptimized
+                | Self::RuntimeBytecodeRemarks
                 | Self::RuntimeBytecodeSourceMap
                 | Self::RuntimeBytecodeFunctionDebugData
                 | Self::RuntimeBytecodeGeneratedSources
                 | Self::RuntimeBytecodeLinkReferences
                 | Self::RuntimeBytecodeImmutableReferences
+                | Self::RuntimeBytecodeSize
                 | Self::GasEstimates
         )
     }
@@ -143,40 +175,56 @@ impl Selector {
                 Self::Bytecode,
                 Self::BytecodeObject,
                 Self::BytecodeLLVMAssembly,
+                Self::BytecodeLLVMIR,
+                Self::BytecodeLLVMIROptimized,
+                Self::BytecodeRemarks,
                 Self::BytecodeOpcodes,
                 Self::BytecodeLinkReferences,
                 Self::BytecodeSourceMap,
                 Self::BytecodeFunctionDebugData,
                 Self::BytecodeGeneratedSources,
+                Self::BytecodeSize,
                 Self::RuntimeBytecode,
                 Self::RuntimeBytecodeObject,
                 Self::RuntimeBytecodeLLVMAssembly,
+                Self::RuntimeBytecodeLLVMIR,
+                Self::RuntimeBytecodeLLVMIROptimized,
+                Self::RuntimeBytecodeRemarks,
                 Self::RuntimeBytecodeOpcodes,
                 Self::RuntimeBytecodeLinkReferences,
                 Self::RuntimeBytecodeImmutableReferences,
                 Self::RuntimeBytecodeSourceMap,
                 Self::RuntimeBytecodeFunctionDebugData,
                 Self::RuntimeBytecodeGeneratedSources,
+                Self::RuntimeBytecodeSize,
                 Self::GasEstimates,
             ],
             Self::Bytecode => vec![
                 Self::BytecodeObject,
                 Self::BytecodeLLVMAssembly,
+                Self::BytecodeLLVMIR,
+                Self::BytecodeLLVMIROptimized,
+                Self::BytecodeRemarks,
                 Self::BytecodeOpcodes,
                 Self::BytecodeLinkReferences,
                 Self::BytecodeSourceMap,
                 Self::BytecodeFunctionDebugData,
                 Self::BytecodeGeneratedSources,
+                Self::BytecodeSize,
             ],
             Self::RuntimeBytecode => vec![
                 Self::RuntimeBytecodeObject,
                 Self::RuntimeBytecodeLLVMAssembly,
+                Self::RuntimeBytecodeLLVMIR,
+                Self::RuntimeBytecodeLLVMIROptimized,
+                Self::RuntimeBytecodeRemarks,
                 Self::RuntimeBytecodeOpcodes,
                 Self::RuntimeBytecodeLinkReferences,
                 Self::RuntimeBytecodeImmutableReferences,
                 Self::RuntimeBytecodeSourceMap,
                 Self::RuntimeBytecodeFunctionDebugData,
                 Self::RuntimeBytecodeGeneratedSources,
+                Self::RuntimeBytecodeSize,
             ],
             Self::Any => vec![
                 Self::AST,
@@ -194,20 +242,28 @@ impl Selector {
                 Self::Bytecode,
                 Self::BytecodeObject,
                 Self::BytecodeLLVMAssembly,
+                Self::BytecodeLLVMIR,
+                Self::BytecodeLLVMIROptimized,
+                Self::BytecodeRemarks,
                 Self::BytecodeOpcodes,
                 Self::BytecodeLinkReferences,
                 Self::BytecodeSourceMap,
                 Self::BytecodeFunctionDebugData,
                 Self::BytecodeGeneratedSources,
+                Self::BytecodeSize,
                 Self::RuntimeBytecode,
                 Self::RuntimeBytecodeObject,
                 Self::RuntimeBytecodeLLVMAssembly,
+                Self::RuntimeBytecodeLLVMIR,
+                Self::RuntimeBytecodeLLVMIROptimized,
+                Self::RuntimeBytecodeRemarks,
                 Self::RuntimeBytecodeOpcodes,
                 Self::RuntimeBytecodeLinkReferences,
                 Self::RuntimeBytecodeImmutableReferences,
                 Self::RuntimeBytecodeSourceMap,
                 Self::RuntimeBytecodeFunctionDebugData,
                 Self::RuntimeBytecodeGeneratedSources,
+                Self::RuntimeBytecodeSize,
                 Self::GasEstimates,
             ],
             selector => vec![selector],
@@ -14,6 +14,7 @@ pub mod output;
 pub mod version;
 
 pub use self::input::language::Language as InputLanguage;
+pub use self::input::settings::contract_override::ContractOverride as InputContractOverride;
 pub use self::input::settings::metadata::Metadata as InputMetadata;
 pub use self::input::settings::optimizer::Optimizer as InputOptimizer;
 pub use self::input::settings::selection::selector::Selector as InputSelector;
@@ -21,6 +22,10 @@ pub use self::input::settings::selection::Selection as InputSelection;
 pub use self::input::settings::Settings as InputSettings;
 pub use self::input::source::Source as InputSource;
 pub use self::input::Input;
+pub use self::output::contract::evm::bytecode::function_debug_data::FunctionDebugData as OutputContractEVMBytecodeFunctionDebugData;
+pub use self::output::contract::evm::bytecode::immutable_reference::ImmutableReference as OutputContractEVMBytecodeImmutableReference;
+pub use self::output::contract::evm::bytecode::remark::Remark as OutputContractEVMBytecodeRemark;
+pub use self::output::contract::evm::bytecode::size::Size as OutputContractEVMBytecodeSize;
 pub use self::output::contract::evm::bytecode::Bytecode as OutputContractEVMBytecode;
 pub use self::output::contract::evm::EVM as OutputContractEVM;
 pub use self::output::contract::Contract as OutputContract;
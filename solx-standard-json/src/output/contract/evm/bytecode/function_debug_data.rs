@@ -0,0 +1,38 @@
+//!
+//! The `solc --standard-json` output contract EVM bytecode function debug data.
+//!
+
+///
+/// The `solc --standard-json` output contract EVM bytecode function debug data.
+///
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FunctionDebugData {
+    /// Offset of the function entry in the bytecode.
+    pub entry_point: Option<u64>,
+    /// AST ID of the function definition.
+    pub id: Option<usize>,
+    /// Number of stack slots taken by the function parameters.
+    pub parameter_slots: usize,
+    /// Number of stack slots taken by the function return values.
+    pub return_slots: usize,
+}
+
+impl FunctionDebugData {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        entry_point: Option<u64>,
+        id: Option<usize>,
+        parameter_slots: usize,
+        return_slots: usize,
+    ) -> Self {
+        Self {
+            entry_point,
+            id,
+            parameter_slots,
+            return_slots,
+        }
+    }
+}
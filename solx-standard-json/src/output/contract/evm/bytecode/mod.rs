@@ -2,11 +2,20 @@
 //! The `solc --standard-json` output contract EVM bytecode.
 //!
 
+pub mod function_debug_data;
+pub mod immutable_reference;
 pub mod link_reference;
+pub mod remark;
+pub mod size;
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 
+use self::function_debug_data::FunctionDebugData;
+use self::immutable_reference::ImmutableReference;
 use self::link_reference::LinkReference;
+use self::remark::Remark;
+use self::size::Size;
 
 ///
 /// The `solc --standard-json` output contract EVM bytecode.
@@ -20,12 +29,24 @@ pub struct Bytecode {
     /// Text assembly from LLVM.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub llvm_assembly: Option<String>,
+    /// Unoptimized LLVM IR.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub llvm_ir: Option<String>,
+    /// Optimized LLVM IR.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub llvm_ir_optimized: Option<String>,
+    /// Optimization remarks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remarks: Option<Vec<Remark>>,
     /// Link references placeholder.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub link_references: Option<BTreeMap<String, BTreeMap<String, Vec<LinkReference>>>>,
     /// Compilation pipeline benchmarks.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub benchmarks: Vec<(String, u64)>,
+    /// Bytecode size breakdown.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<Size>,
 
     /// Opcodes placeholder.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -33,15 +54,15 @@ pub struct Bytecode {
     /// Source maps placeholder.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source_map: Option<String>,
-    /// Function debug data placeholder.
+    /// Function debug data.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub function_debug_data: Option<BTreeMap<String, serde_json::Value>>,
+    pub function_debug_data: Option<BTreeMap<String, FunctionDebugData>>,
     /// Generated sources placeholder.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub generated_sources: Option<Vec<serde_json::Value>>,
-    /// Immutable generated_sources placeholder.
+    /// Immutable references, mapped from the AST IDs of the immutable variables.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub immutable_references: Option<serde_json::Value>,
+    pub immutable_references: Option<BTreeMap<String, Vec<ImmutableReference>>>,
 }
 
 impl Bytecode {
@@ -51,14 +72,18 @@ impl Bytecode {
     pub fn new(
         object: Option<String>,
         llvm_assembly: Option<String>,
+        llvm_ir: Option<String>,
+        llvm_ir_optimized: Option<String>,
+        remarks: Option<Vec<Remark>>,
         unlinked_symbols: Option<BTreeMap<String, Vec<u64>>>,
         benchmarks: Vec<(String, u64)>,
+        size: Option<Size>,
 
         opcodes: Option<String>,
         source_map: Option<String>,
-        function_debug_data: Option<BTreeMap<String, serde_json::Value>>,
+        function_debug_data: Option<BTreeMap<String, FunctionDebugData>>,
         generated_sources: Option<Vec<serde_json::Value>>,
-        immutable_references: Option<serde_json::Value>,
+        immutables: Option<BTreeMap<String, BTreeSet<u64>>>,
     ) -> Self {
         let link_references = unlinked_symbols.map(|unlinked_symbols| {
             let mut link_references = BTreeMap::new();
@@ -81,11 +106,30 @@ impl Bytecode {
             link_references
         });
 
+        let immutable_references = immutables.map(|immutables| {
+            immutables
+                .into_iter()
+                .map(|(id, offsets)| {
+                    (
+                        id,
+                        offsets
+                            .into_iter()
+                            .map(ImmutableReference::new)
+                            .collect::<Vec<ImmutableReference>>(),
+                    )
+                })
+                .collect::<BTreeMap<String, Vec<ImmutableReference>>>()
+        });
+
         Self {
             object,
             llvm_assembly,
+            llvm_ir,
+            llvm_ir_optimized,
+            remarks,
             link_references,
             benchmarks,
+            size,
 
             opcodes,
             source_map,
@@ -101,8 +145,12 @@ impl Bytecode {
     pub fn is_empty(&self) -> bool {
         self.object.is_none()
             && self.llvm_assembly.is_none()
+            && self.llvm_ir.is_none()
+            && self.llvm_ir_optimized.is_none()
+            && self.remarks.is_none()
             && self.link_references.is_none()
             && self.benchmarks.is_empty()
+            && self.size.is_none()
             && self.opcodes.is_none()
             && self.source_map.is_none()
             && self.function_debug_data.is_none()
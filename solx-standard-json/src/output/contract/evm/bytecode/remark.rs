@@ -0,0 +1,27 @@
+//!
+//! The `solc --standard-json` output contract EVM bytecode optimization remark.
+//!
+
+///
+/// The `solc --standard-json` output contract EVM bytecode optimization remark.
+///
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Remark {
+    /// The compilation pipeline element the remark was emitted in.
+    pub pipeline_element: String,
+    /// The remark message.
+    pub message: String,
+}
+
+impl Remark {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(pipeline_element: String, message: String) -> Self {
+        Self {
+            pipeline_element,
+            message,
+        }
+    }
+}
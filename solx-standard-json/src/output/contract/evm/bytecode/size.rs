@@ -0,0 +1,59 @@
+//!
+//! The `solc --standard-json` output contract EVM bytecode size breakdown.
+//!
+
+///
+/// The `solc --standard-json` output contract EVM bytecode size breakdown.
+///
+/// All sizes are in bytes.
+///
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Size {
+    /// Total size of the bytecode.
+    pub total: usize,
+    /// Size of the code itself.
+    pub code: usize,
+    /// Size of the CBOR metadata.
+    pub metadata: usize,
+    /// Size of the immutable value slots.
+    pub immutables: usize,
+    /// Size of the embedded dependency objects, e.g. the runtime code or contracts created with `new`.
+    pub dependencies: usize,
+    /// Size limit of the bytecode.
+    pub limit: usize,
+}
+
+impl Size {
+    ///
+    /// A shortcut constructor.
+    ///
+    /// The code size is derived from the total size and other components.
+    ///
+    pub fn new(
+        total: usize,
+        metadata: usize,
+        immutables: usize,
+        dependencies: usize,
+        limit: usize,
+    ) -> Self {
+        Self {
+            total,
+            code: total.saturating_sub(metadata + immutables + dependencies),
+            metadata,
+            immutables,
+            dependencies,
+            limit,
+        }
+    }
+}
+
+impl std::fmt::Display for Size {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}B of {}B limit (code: {}B, metadata: {}B, immutables: {}B, dependencies: {}B)",
+            self.total, self.limit, self.code, self.metadata, self.immutables, self.dependencies,
+        )
+    }
+}
@@ -78,6 +78,22 @@ impl Output {
     /// Prunes the output JSON and prints it to stdout.
     ///
     pub fn write_and_exit(mut self, output_selection: &InputSettingsSelection) -> ! {
+        self.prune(output_selection);
+        self.write_pruned_and_exit()
+    }
+
+    ///
+    /// Prints the already pruned output JSON to stdout.
+    ///
+    pub fn write_pruned_and_exit(self) -> ! {
+        serde_json::to_writer(std::io::stdout(), &self).expect("Stdout writing error");
+        std::process::exit(solx_utils::EXIT_CODE_SUCCESS);
+    }
+
+    ///
+    /// Removes the data not requested by `output_selection`, and the empty contracts.
+    ///
+    pub fn prune(&mut self, output_selection: &InputSettingsSelection) {
         for (path, file) in self.contracts.iter_mut() {
             for (name, contract) in file.iter_mut() {
                 if !output_selection.check_selection(
@@ -127,9 +143,6 @@ impl Output {
             contracts.retain(|_, contract| !contract.is_empty());
             !contracts.is_empty()
         });
-
-        serde_json::to_writer(std::io::stdout(), &self).expect("Stdout writing error");
-        std::process::exit(solx_utils::EXIT_CODE_SUCCESS);
     }
 
     ///
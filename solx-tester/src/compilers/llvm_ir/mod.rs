@@ -65,7 +65,7 @@ impl Compiler for LLVMIRCompiler {
                 selectors.insert(solx_standard_json::InputSelector::Bytecode);
                 selectors.insert(solx_standard_json::InputSelector::RuntimeBytecode);
                 selectors.insert(solx_standard_json::InputSelector::Metadata);
-                let solx_input = solx_standard_json::Input::from_llvm_ir_sources(
+                let mut solx_input = solx_standard_json::Input::from_llvm_ir_sources(
                     sources,
                     libraries.to_owned(),
                     solx_standard_json::InputOptimizer::new(
@@ -78,6 +78,7 @@ impl Compiler for LLVMIRCompiler {
                     solx_standard_json::InputMetadata::default(),
                     llvm_options,
                 );
+                solx_input.settings.evm_version = solx.evm_version;
 
                 let solx_output = solx.standard_json(
                     mode,
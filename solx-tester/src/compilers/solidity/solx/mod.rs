@@ -29,6 +29,8 @@ pub struct SolidityCompiler {
     pub path: PathBuf,
     /// The `solx` compiler version.
     pub version: semver::Version,
+    /// The target EVM version.
+    pub evm_version: Option<solx_utils::EVMVersion>,
 }
 
 impl SolidityCompiler {
@@ -38,9 +40,16 @@ impl SolidityCompiler {
     ///
     /// A shortcut constructor.
     ///
-    pub fn try_from_path(path: PathBuf) -> anyhow::Result<Self> {
+    pub fn try_from_path(
+        path: PathBuf,
+        evm_version: Option<solx_utils::EVMVersion>,
+    ) -> anyhow::Result<Self> {
         let version = Self::version(path.as_path())?;
-        Ok(Self { path, version })
+        Ok(Self {
+            path,
+            version,
+            evm_version,
+        })
     }
 
     ///
@@ -276,7 +285,7 @@ impl Compiler for SolidityCompiler {
                     .llvm_optimizer_settings
                     .is_fallback_to_size_enabled,
             ),
-            None,
+            self.evm_version,
             solx_mode.via_ir,
             &solx_standard_json::InputSelection::new(selectors),
             solx_standard_json::InputMetadata::default(),
@@ -76,7 +76,7 @@ impl Compiler for YulCompiler {
                 selectors.insert(solx_standard_json::InputSelector::MethodIdentifiers);
                 selectors.insert(solx_standard_json::InputSelector::Metadata);
                 selectors.insert(solx_standard_json::InputSelector::Yul);
-                let solx_input = solx_standard_json::Input::from_yul_sources(
+                let mut solx_input = solx_standard_json::Input::from_yul_sources(
                     sources,
                     libraries.to_owned(),
                     solx_standard_json::InputOptimizer::new(
@@ -87,6 +87,7 @@ impl Compiler for YulCompiler {
                     solx_standard_json::InputMetadata::default(),
                     llvm_options,
                 );
+                solx_input.settings.evm_version = solx.evm_version;
 
                 let solx_output = solx.standard_json(
                     mode,
@@ -29,6 +29,9 @@ pub struct Metadata {
     /// If the entire test file must be ignored.
     #[serde(default)]
     pub ignore: bool,
+    /// The minimum EVM version required by the test.
+    #[serde(default)]
+    pub evm_version: Option<solx_utils::EVMVersion>,
 }
 
 impl FromStr for Metadata {
@@ -107,6 +107,10 @@ impl MatterLabsTest {
             return None;
         }
 
+        if !filters.check_evm_version(metadata.evm_version) {
+            return None;
+        }
+
         let sources = if metadata.contracts.is_empty() {
             if path.ends_with("test.json") {
                 vec![]
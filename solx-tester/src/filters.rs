@@ -17,6 +17,8 @@ pub struct Filters {
     mode_filters: HashSet<String>,
     /// The group filters.
     group_filters: HashSet<String>,
+    /// The target EVM version.
+    evm_version: solx_utils::EVMVersion,
 }
 
 impl Filters {
@@ -27,6 +29,7 @@ impl Filters {
         path_filters: Vec<String>,
         mode_filters: Vec<String>,
         group_filters: Vec<String>,
+        evm_version: Option<solx_utils::EVMVersion>,
     ) -> Self {
         Self {
             path_filters: path_filters.into_iter().collect(),
@@ -37,6 +40,7 @@ impl Filters {
                 .map(|f| f.replace(' ', ""))
                 .collect(),
             group_filters: group_filters.into_iter().collect(),
+            evm_version: evm_version.unwrap_or_default(),
         }
     }
 
@@ -81,4 +85,14 @@ impl Filters {
             false
         }
     }
+
+    ///
+    /// Check if the target EVM version is not older than the one required by the test.
+    ///
+    pub fn check_evm_version(&self, evm_version: Option<solx_utils::EVMVersion>) -> bool {
+        match evm_version {
+            Some(evm_version) => self.evm_version >= evm_version,
+            None => true,
+        }
+    }
 }
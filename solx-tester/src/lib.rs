@@ -64,6 +64,8 @@ pub struct SolxTester {
     pub debug_config: Option<solx_codegen_evm::DebugConfig>,
     /// Actions to perform.
     pub workflow: Workflow,
+    /// The target EVM version.
+    pub evm_version: Option<solx_utils::EVMVersion>,
 }
 
 impl SolxTester {
@@ -90,12 +92,14 @@ impl SolxTester {
         filters: Filters,
         debug_config: Option<solx_codegen_evm::DebugConfig>,
         workflow: Workflow,
+        evm_version: Option<solx_utils::EVMVersion>,
     ) -> anyhow::Result<Self> {
         Ok(Self {
             summary,
             filters,
             debug_config,
             workflow,
+            evm_version,
         })
     }
 
@@ -126,7 +130,11 @@ impl SolxTester {
                     specialized_debug_config,
                 ) {
                     if let Workflow::BuildAndRun = self.workflow {
-                        test.run_revm(self.summary.clone(), enable_trace)
+                        test.run_revm(
+                            self.summary.clone(),
+                            enable_trace,
+                            self.evm_version.unwrap_or_default(),
+                        )
                     };
                 }
             })
@@ -140,7 +148,7 @@ impl SolxTester {
     ///
     fn all_tests(&self, toolchain: Toolchain, solx: Option<PathBuf>) -> anyhow::Result<Vec<Test>> {
         let solx_path = solx.unwrap_or_else(|| PathBuf::from("solx"));
-        let solidity_compiler = Arc::new(SolxCompiler::try_from_path(solx_path)?);
+        let solidity_compiler = Arc::new(SolxCompiler::try_from_path(solx_path, self.evm_version)?);
         let llvm_ir_compiler = Arc::new(LLVMIRCompiler::Solx(solidity_compiler.clone()));
 
         let (solidity_compiler, yul_compiler, llvm_ir_compiler): (
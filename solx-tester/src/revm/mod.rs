@@ -78,6 +78,13 @@ impl REVM {
         "0x3737373737373737373737373737373737373737373737373737373737373737";
     /// Default base fee for REVM.
     pub const BASE_FEE: u64 = 0;
+    /// Default blob base fee for REVM.
+    pub const BLOB_BASE_FEE: u64 = 37;
+    /// Default blob versioned hashes of runtime transactions, which are only set since Cancun.
+    pub const BLOB_HASHES: [&'static str; 2] = [
+        "0x0137373737373737373737373737373737373737373737373737373737373701",
+        "0x0137373737373737373737373737373737373737373737373737373737373702",
+    ];
     /// Default block gas limit for REVM.
     pub const BLOCK_GAS_LIMIT: u64 = 30000000;
     /// Default current block number.
@@ -97,21 +104,23 @@ impl REVM {
     pub const GAS_COST_PER_NON_ZERO_CALLDATA_BYTE: u64 = 16;
     /// Gas cost per byte in runtime code.
     pub const GAS_COST_PER_RUNTIME_CODE_BYTE: u64 = 200;
+    /// Blob gas per blob.
+    pub const GAS_PER_BLOB: u64 = 131072;
 
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(enable_trace: bool) -> Self {
+    pub fn new(enable_trace: bool, evm_version: solx_utils::EVMVersion) -> Self {
         let mut evm = if enable_trace {
             REVM::Tracing(Evm::new_with_inspector(
-                Self::context(),
+                Self::context(evm_version),
                 TracerEip3155::new_stdout(),
                 EthInstructions::new_mainnet(),
                 EthPrecompiles::default(),
             ))
         } else {
             REVM::Default(Evm::new(
-                Self::context(),
+                Self::context(evm_version),
                 EthInstructions::new_mainnet(),
                 EthPrecompiles::default(),
             ))
@@ -187,20 +196,55 @@ impl REVM {
     ///
     /// Fills a runtime transaction with the given parameters.
     ///
+    /// If `blob_hashes` are not empty, the transaction is an EIP-4844 one, paying the default
+    /// blob base fee.
+    ///
     pub fn new_runtime_transaction(
         address: web3::types::Address,
         caller: web3::types::Address,
         calldata: Calldata,
         value: Option<u128>,
+        blob_hashes: Vec<revm::primitives::B256>,
     ) -> revm::context::TxEnv {
-        revm::context::TxEnv::builder()
+        let mut builder = revm::context::TxEnv::builder()
             .caller(web3_address_to_revm_address(&caller))
             .data(revm::primitives::Bytes::from(calldata.inner))
             .value(revm::primitives::U256::from(value.unwrap_or_default()))
             .to(web3_address_to_revm_address(&address))
             .gas_price(Self::GAS_PRICE as u128)
-            .gas_limit(Self::BLOCK_GAS_LIMIT)
-            .build_fill()
+            .gas_limit(Self::BLOCK_GAS_LIMIT);
+        if !blob_hashes.is_empty() {
+            builder = builder
+                .blob_hashes(blob_hashes)
+                .max_fee_per_blob_gas(Self::BLOB_BASE_FEE as u128);
+        }
+        builder.build_fill()
+    }
+
+    ///
+    /// Returns the blob versioned hashes of runtime transactions.
+    ///
+    /// The hashes are empty before Cancun, as blob transactions are not supported there.
+    ///
+    pub fn blob_hashes(&self) -> Vec<revm::primitives::B256> {
+        let spec = match self {
+            REVM::Default(evm) => evm.cfg.spec,
+            REVM::Tracing(evm) => evm.cfg.spec,
+        };
+        if !spec.is_enabled_in(revm::primitives::hardfork::SpecId::CANCUN) {
+            return vec![];
+        }
+        Self::BLOB_HASHES
+            .iter()
+            .map(|hash| revm::primitives::B256::from_str(hash).expect("Always valid"))
+            .collect()
+    }
+
+    ///
+    /// Returns the blob fee paid by a transaction with `blob_count` blobs.
+    ///
+    pub fn blob_fee(blob_count: usize) -> web3::types::U256 {
+        web3::types::U256::from(Self::GAS_PER_BLOB * Self::BLOB_BASE_FEE * blob_count as u64)
     }
 
     ///
@@ -366,7 +410,7 @@ impl REVM {
     ///
     /// Builds the default context for REVM.
     ///
-    fn context() -> Context {
+    fn context(evm_version: solx_utils::EVMVersion) -> Context {
         let mut cache = CacheState::new(false);
         // Account 0x00 needs to have its code hash on 0.
         cache.insert_account_with_storage(
@@ -414,7 +458,29 @@ impl REVM {
             .with_bundle_update()
             .build();
 
-        Context::new(state, revm::primitives::hardfork::PRAGUE)
+        Context::new(state, Self::spec_id(evm_version))
+    }
+
+    ///
+    /// Returns the REVM specification of the EVM version.
+    ///
+    fn spec_id(evm_version: solx_utils::EVMVersion) -> revm::primitives::hardfork::SpecId {
+        match evm_version {
+            solx_utils::EVMVersion::Homestead => revm::primitives::hardfork::HOMESTEAD,
+            solx_utils::EVMVersion::TangerineWhistle => revm::primitives::hardfork::TANGERINE,
+            solx_utils::EVMVersion::SpuriousDragon => revm::primitives::hardfork::SPURIOUS_DRAGON,
+            solx_utils::EVMVersion::Byzantium => revm::primitives::hardfork::BYZANTIUM,
+            solx_utils::EVMVersion::Constantinople => revm::primitives::hardfork::CONSTANTINOPLE,
+            solx_utils::EVMVersion::Petersburg => revm::primitives::hardfork::PETERSBURG,
+            solx_utils::EVMVersion::Istanbul => revm::primitives::hardfork::ISTANBUL,
+            solx_utils::EVMVersion::Berlin => revm::primitives::hardfork::BERLIN,
+            solx_utils::EVMVersion::London => revm::primitives::hardfork::LONDON,
+            solx_utils::EVMVersion::Paris => revm::primitives::hardfork::MERGE,
+            solx_utils::EVMVersion::Shanghai => revm::primitives::hardfork::SHANGHAI,
+            solx_utils::EVMVersion::Cancun => revm::primitives::hardfork::CANCUN,
+            solx_utils::EVMVersion::Prague => revm::primitives::hardfork::PRAGUE,
+            solx_utils::EVMVersion::Osaka => revm::primitives::hardfork::OSAKA,
+        }
     }
 
     ///
@@ -432,6 +498,11 @@ impl REVM {
         evm.block.beneficiary =
             revm::primitives::Address::from_str(Self::COIN_BASE).expect("Always valid");
         evm.block.basefee = Self::BASE_FEE;
+        evm.block.blob_excess_gas_and_price =
+            Some(revm::context_interface::block::BlobExcessGasAndPrice {
+                excess_blob_gas: 0,
+                blob_gasprice: Self::BLOB_BASE_FEE as u128,
+            });
         evm.block.difficulty =
             revm::primitives::U256::from_str(Self::BLOCK_PREVRANDAO).expect("Always valid");
         evm.block.prevrandao =
@@ -80,4 +80,9 @@ pub struct Arguments {
     /// Sets the `debug logging` option in LLVM.
     #[structopt(long)]
     pub llvm_debug_logging: bool,
+
+    /// The EVM version to compile and run the tests for.
+    /// The default is chosen by `solc`.
+    #[structopt(long)]
+    pub evm_version: Option<solx_utils::EVMVersion>,
 }
@@ -85,13 +85,19 @@ fn main_inner(arguments: Arguments) -> anyhow::Result<()> {
 
     let summary = solx_tester::Summary::new(arguments.verbose, arguments.quiet).wrap();
 
-    let filters = solx_tester::Filters::new(arguments.path, arguments.mode, arguments.group);
+    let filters = solx_tester::Filters::new(
+        arguments.path,
+        arguments.mode,
+        arguments.group,
+        arguments.evm_version,
+    );
 
     let compiler_tester = solx_tester::SolxTester::new(
         summary.clone(),
         filters,
         debug_config.clone(),
         arguments.workflow,
+        arguments.evm_version,
     )?;
 
     let run_time_start = Instant::now();
@@ -160,6 +166,7 @@ mod tests {
             )),
             llvm_verify_each: false,
             llvm_debug_logging: false,
+            evm_version: None,
         };
 
         crate::main_inner(arguments).expect("Manual testing failed");
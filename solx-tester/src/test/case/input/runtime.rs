@@ -91,9 +91,11 @@ impl Runtime {
             self.caller,
             self.calldata.clone(),
             self.value,
+            vm.blob_hashes(),
         );
 
-        let mut initial_balance = web3::types::U256::from(self.value.unwrap_or_default());
+        let mut initial_balance = web3::types::U256::from(self.value.unwrap_or_default())
+            + REVM::blob_fee(tx.blob_hashes.len());
         if REVM::get_rich_addresses().contains(&self.caller) {
             initial_balance += web3::types::U256::from(1) << 100;
         }
@@ -102,6 +102,8 @@ impl Value {
             )
         } else if value == "$BASE_FEE" {
             web3::types::U256::from(REVM::BASE_FEE)
+        } else if value == "$BLOB_BASE_FEE" {
+            web3::types::U256::from(REVM::BLOB_BASE_FEE)
         } else if value == "$GAS_PRICE" {
             web3::types::U256::from(REVM::GAS_PRICE)
         } else {
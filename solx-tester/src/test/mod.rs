@@ -49,14 +49,23 @@ impl Test {
     ///
     /// Runs the test on REVM.
     ///
-    pub fn run_revm(self, summary: Arc<Mutex<Summary>>, enable_trace: bool) {
+    pub fn run_revm(
+        self,
+        summary: Arc<Mutex<Summary>>,
+        enable_trace: bool,
+        evm_version: solx_utils::EVMVersion,
+    ) {
         for case in self.cases {
             let context = CaseContext {
                 name: &self.name,
                 mode: &self.mode,
                 group: &self.group,
             };
-            case.run_revm(summary.clone(), &context, REVM::new(enable_trace));
+            case.run_revm(
+                summary.clone(),
+                &context,
+                REVM::new(enable_trace, evm_version),
+            );
         }
     }
 }
@@ -5,7 +5,7 @@
 ///
 /// CBOR payload.
 ///
-/// Used for encoding IPFS contract metadata hash.
+/// Used for encoding and decoding the IPFS or Swarm contract metadata hash.
 ///
 #[derive(Debug, Clone, PartialEq)]
 pub struct CBOR<'a, S>
@@ -50,8 +50,9 @@ where
         cbor.push(0xA0_u8 + (field_count as u8));
 
         if let Some((r#type, hash)) = self.hash.as_ref() {
-            cbor.push(0x64_u8);
-            cbor.extend(r#type.to_string().as_bytes());
+            let r#type = r#type.to_string();
+            cbor.push(0x60_u8 + (r#type.len() as u8));
+            cbor.extend(r#type.as_bytes());
             cbor.push(0x58_u8);
             cbor.push(hash.len() as u8);
             cbor.extend_from_slice(hash);
@@ -74,10 +75,209 @@ where
     }
 }
 
+impl<'a> CBOR<'a, String> {
+    /// The size of the CBOR payload length suffix in bytes.
+    pub const LENGTH_SUFFIX_SIZE: usize = 2;
+
+    /// The hash types the metadata hash can be decoded for.
+    pub const HASH_TYPES: [&'static str; 3] = ["ipfs", "bzzr0", "bzzr1"];
+
+    /// The unsigned integer major type.
+    const MAJOR_TYPE_UNSIGNED: u8 = 0;
+
+    /// The negative integer major type.
+    const MAJOR_TYPE_NEGATIVE: u8 = 1;
+
+    /// The byte string major type.
+    const MAJOR_TYPE_BYTES: u8 = 2;
+
+    /// The text string major type.
+    const MAJOR_TYPE_TEXT: u8 = 3;
+
+    /// The map major type.
+    const MAJOR_TYPE_MAP: u8 = 5;
+
+    /// The simple value major type, which includes booleans.
+    const MAJOR_TYPE_SIMPLE: u8 = 7;
+
+    ///
+    /// Decodes the CBOR payload at the end of `bytecode`.
+    ///
+    /// The payload is located by its 2-byte big-endian length suffix. The version data is expected
+    /// either in the `name:version;...` text format emitted by `solx`, as the plain version text emitted
+    /// by the `solc` prereleases, or as the 3-byte version emitted by the `solc` releases.
+    /// The keys other than the metadata hash and the version are skipped, including the ones with
+    /// integer and boolean values, such as `experimental`.
+    ///
+    pub fn from_bytecode(bytecode: &'a [u8]) -> anyhow::Result<Self> {
+        let length_offset = bytecode
+            .len()
+            .checked_sub(Self::LENGTH_SUFFIX_SIZE)
+            .ok_or_else(|| {
+                anyhow::anyhow!("The bytecode is too short to contain CBOR metadata.")
+            })?;
+        let length = u16::from_be_bytes(bytecode[length_offset..].try_into().expect("Always valid"))
+            as usize;
+        let payload_offset = length_offset.checked_sub(length).ok_or_else(|| {
+            anyhow::anyhow!(
+                "The CBOR metadata length suffix {length} exceeds the bytecode size of {length_offset} bytes."
+            )
+        })?;
+        let mut payload = &bytecode[payload_offset..length_offset];
+
+        let (major_type, field_count) = Self::decode_head(&mut payload)?;
+        if major_type != Self::MAJOR_TYPE_MAP {
+            anyhow::bail!("The CBOR metadata is not a map.");
+        }
+
+        let mut hash = None;
+        let version_key = "solc".to_owned();
+        let mut version_data = Vec::new();
+        for _ in 0..field_count {
+            let (major_type, key) = Self::decode_item(&mut payload)?;
+            if major_type != Self::MAJOR_TYPE_TEXT {
+                anyhow::bail!("The CBOR metadata keys must be text strings.");
+            }
+            let key = std::str::from_utf8(key)
+                .map_err(|error| anyhow::anyhow!("The CBOR metadata key is invalid: {error}"))?;
+
+            let (major_type, value) = Self::decode_item(&mut payload)?;
+            match (key, major_type) {
+                (key, Self::MAJOR_TYPE_BYTES) if Self::HASH_TYPES.contains(&key) => {
+                    hash = Some((key.to_owned(), value));
+                }
+                (key, Self::MAJOR_TYPE_TEXT) if key == version_key => {
+                    let value = std::str::from_utf8(value).map_err(|error| {
+                        anyhow::anyhow!("The CBOR metadata version is invalid: {error}")
+                    })?;
+                    if !value.contains(':') {
+                        let version = Self::parse_version(value).map_err(|error| {
+                            anyhow::anyhow!(
+                                "The CBOR metadata version `{value}` is invalid: {error}"
+                            )
+                        })?;
+                        version_data.push((version_key.clone(), version));
+                        continue;
+                    }
+                    for entry in value.split(';') {
+                        let (name, version) = entry.split_once(':').ok_or_else(|| {
+                            anyhow::anyhow!("The CBOR metadata version entry `{entry}` is invalid.")
+                        })?;
+                        let version = Self::parse_version(version).map_err(|error| {
+                            anyhow::anyhow!("The CBOR metadata version `{version}` of `{name}` is invalid: {error}")
+                        })?;
+                        version_data.push((name.to_owned(), version));
+                    }
+                }
+                (key, Self::MAJOR_TYPE_BYTES) if key == version_key => {
+                    let [major, minor, patch] = value else {
+                        anyhow::bail!("The CBOR metadata version must be 3 bytes long.");
+                    };
+                    version_data.push((
+                        version_key.clone(),
+                        semver::Version::new(*major as u64, *minor as u64, *patch as u64),
+                    ));
+                }
+                _ => {}
+            }
+        }
+        if !payload.is_empty() {
+            anyhow::bail!(
+                "The CBOR metadata contains {} unexpected trailing bytes.",
+                payload.len()
+            );
+        }
+        if version_data.is_empty() {
+            anyhow::bail!("The CBOR metadata does not contain the compiler version.");
+        }
+
+        Ok(Self {
+            hash,
+            version_key,
+            version_data,
+        })
+    }
+
+    ///
+    /// Parses the compiler `version`.
+    ///
+    /// The build metadata, e.g. the `solc` commit and platform, is dropped if it is not valid semver.
+    ///
+    fn parse_version(version: &str) -> Result<semver::Version, semver::Error> {
+        semver::Version::parse(version).or_else(|error| match version.split_once('+') {
+            Some((version, _build)) => semver::Version::parse(version),
+            None => Err(error),
+        })
+    }
+
+    ///
+    /// Decodes the data item at the start of `payload`, and advances it.
+    ///
+    /// Returns the major type and the contents of byte and text strings.
+    /// The contents of integers and simple values are empty.
+    ///
+    fn decode_item(payload: &mut &'a [u8]) -> anyhow::Result<(u8, &'a [u8])> {
+        let (major_type, argument) = Self::decode_head(payload)?;
+        match major_type {
+            Self::MAJOR_TYPE_BYTES | Self::MAJOR_TYPE_TEXT => {
+                let length = usize::try_from(argument)
+                    .ok()
+                    .filter(|length| *length <= payload.len())
+                    .ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "The CBOR metadata string length {argument} is out of bounds."
+                        )
+                    })?;
+                let (contents, rest) = payload.split_at(length);
+                *payload = rest;
+                Ok((major_type, contents))
+            }
+            Self::MAJOR_TYPE_UNSIGNED | Self::MAJOR_TYPE_NEGATIVE | Self::MAJOR_TYPE_SIMPLE => {
+                Ok((major_type, &[]))
+            }
+            major_type => {
+                anyhow::bail!("The CBOR metadata major type {major_type} is not supported.")
+            }
+        }
+    }
+
+    ///
+    /// Decodes the head of the data item at the start of `payload`, and advances it.
+    ///
+    /// Returns the major type and the argument, which is the length of strings and maps.
+    ///
+    fn decode_head(payload: &mut &'a [u8]) -> anyhow::Result<(u8, u64)> {
+        let (&initial, rest) = payload
+            .split_first()
+            .ok_or_else(|| anyhow::anyhow!("The CBOR metadata is truncated."))?;
+        *payload = rest;
+
+        let major_type = initial >> 5;
+        let argument_size = match initial & 0x1F {
+            argument @ 0..=23 => return Ok((major_type, argument as u64)),
+            24 => 1,
+            25 => 2,
+            26 => 4,
+            27 => 8,
+            _ => anyhow::bail!("The CBOR metadata indefinite length items are not supported."),
+        };
+        if payload.len() < argument_size {
+            anyhow::bail!("The CBOR metadata is truncated.");
+        }
+        let (argument, rest) = payload.split_at(argument_size);
+        *payload = rest;
+        let argument = argument
+            .iter()
+            .fold(0u64, |result, byte| (result << 8) | (*byte as u64));
+        Ok((major_type, argument))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::IPFSHash;
     use crate::MetadataHashType;
+    use crate::SwarmHash;
 
     #[test]
     #[should_panic(expected = "Version data cannot be empty")]
@@ -156,4 +356,136 @@ mod tests {
             "a264697066735822122060d9c5c201b8c2ed8ac8de1e21afc4ef115ad9f47863e21ffd29f272544b512564736f6c637821736f6c783a302e312e303b736f6c633a302e382e32393b6c6c766d3a312e302e320052"
         );
     }
+
+    #[test]
+    fn swarm_hash_and_extended_version_data() {
+        let hash_type = MetadataHashType::Swarm.to_string();
+        let swarm = SwarmHash::from_slice("hello world".as_bytes());
+
+        let cbor = super::CBOR::new(
+            Some((hash_type.as_str(), swarm.as_bytes())),
+            "solc".to_owned(),
+            vec![
+                ("solx".to_string(), semver::Version::new(0, 1, 0)),
+                ("solc".to_string(), semver::Version::new(0, 8, 29)),
+                ("llvm".to_string(), semver::Version::new(1, 0, 2)),
+            ],
+        );
+
+        assert_eq!(
+            hex::encode(cbor.to_vec()),
+            "a265627a7a7231582092672a471f4419b255d7cb0cf313474a6f5856fb347c5ece85fb706d644b630f64736f6c637821736f6c783a302e312e303b736f6c633a302e382e32393b6c6c766d3a312e302e320051"
+        );
+    }
+
+    #[test]
+    fn decode_ipfs_hash_and_extended_version_data() {
+        let hash_type = MetadataHashType::IPFS.to_string();
+        let ipfs = IPFSHash::from_slice("LLVM is the Endgame".as_bytes());
+        let version_data = vec![
+            ("solx".to_string(), semver::Version::new(0, 1, 0)),
+            ("solc".to_string(), semver::Version::new(0, 8, 29)),
+            ("llvm".to_string(), semver::Version::new(1, 0, 2)),
+        ];
+        let cbor = super::CBOR::new(
+            Some((hash_type.as_str(), ipfs.as_bytes())),
+            "solc".to_owned(),
+            version_data.clone(),
+        );
+        let mut bytecode = hex::decode("6080604052").expect("Always valid");
+        bytecode.extend(cbor.to_vec());
+
+        let decoded = super::CBOR::from_bytecode(bytecode.as_slice()).expect("Always valid");
+        assert_eq!(decoded.hash, Some((hash_type, ipfs.as_bytes())));
+        assert_eq!(decoded.version_key, "solc");
+        assert_eq!(decoded.version_data, version_data);
+    }
+
+    #[test]
+    fn decode_none_and_solx_version_data() {
+        let bytecode =
+            hex::decode("a164736f6c63780b736f6c783a302e382e32390013").expect("Always valid");
+
+        let decoded = super::CBOR::from_bytecode(bytecode.as_slice()).expect("Always valid");
+        assert_eq!(decoded.hash, None);
+        assert_eq!(
+            decoded.version_data,
+            vec![("solx".to_owned(), semver::Version::new(0, 8, 29))]
+        );
+    }
+
+    #[test]
+    fn decode_solc_version_bytes() {
+        let bytecode = hex::decode("a264697066735822122060d9c5c201b8c2ed8ac8de1e21afc4ef115ad9f47863e21ffd29f272544b512564736f6c634300081d0033").expect("Always valid");
+
+        let decoded = super::CBOR::from_bytecode(bytecode.as_slice()).expect("Always valid");
+        assert_eq!(
+            decoded
+                .hash
+                .map(|(r#type, hash)| (r#type, hex::encode(hash))),
+            Some((
+                "ipfs".to_owned(),
+                "122060d9c5c201b8c2ed8ac8de1e21afc4ef115ad9f47863e21ffd29f272544b5125".to_owned()
+            ))
+        );
+        assert_eq!(
+            decoded.version_data,
+            vec![("solc".to_owned(), semver::Version::new(0, 8, 29))]
+        );
+    }
+
+    #[test]
+    fn decode_solc_prerelease_version_text() {
+        let version = "0.8.31-nightly.2025.6.3+commit.b61c2a91";
+        let mut bytecode = vec![0xA1, 0x64];
+        bytecode.extend("solc".as_bytes());
+        bytecode.extend([0x78, version.len() as u8]);
+        bytecode.extend(version.as_bytes());
+        bytecode.extend((bytecode.len() as u16).to_be_bytes());
+
+        let decoded = super::CBOR::from_bytecode(bytecode.as_slice()).expect("Always valid");
+        assert_eq!(
+            decoded.version_data,
+            vec![(
+                "solc".to_owned(),
+                semver::Version::parse(version).expect("Always valid")
+            )]
+        );
+    }
+
+    #[test]
+    fn decode_unknown_integer_keys() {
+        let mut bytecode = vec![0xA3, 0x64];
+        bytecode.extend("solc".as_bytes());
+        bytecode.extend([0x43, 0x00, 0x08, 0x1D]);
+        bytecode.push(0x67);
+        bytecode.extend("counter".as_bytes());
+        bytecode.extend([0x19, 0x01, 0x00]);
+        bytecode.push(0x68);
+        bytecode.extend("negative".as_bytes());
+        bytecode.push(0x20);
+        bytecode.extend((bytecode.len() as u16).to_be_bytes());
+
+        let decoded = super::CBOR::from_bytecode(bytecode.as_slice()).expect("Always valid");
+        assert_eq!(
+            decoded.version_data,
+            vec![("solc".to_owned(), semver::Version::new(0, 8, 29))]
+        );
+    }
+
+    #[test]
+    fn decode_length_out_of_bounds() {
+        let bytecode = hex::decode("6080604052ffff").expect("Always valid");
+
+        let error = super::CBOR::from_bytecode(bytecode.as_slice()).expect_err("Always invalid");
+        assert!(error.to_string().contains("exceeds the bytecode size"));
+    }
+
+    #[test]
+    fn decode_not_a_map() {
+        let bytecode = hex::decode("60806040520005").expect("Always valid");
+
+        let error = super::CBOR::from_bytecode(bytecode.as_slice()).expect_err("Always invalid");
+        assert!(error.to_string().contains("is not a map"));
+    }
 }
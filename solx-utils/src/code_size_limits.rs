@@ -0,0 +1,57 @@
+//!
+//! EVM bytecode size limits.
+//!
+
+use crate::code_segment::CodeSegment;
+
+///
+/// EVM bytecode size limits.
+///
+/// Defaults to the Ethereum mainnet limits, but can be raised for L2s or local testing.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CodeSizeLimits {
+    /// The deploy bytecode size limit.
+    pub deploy: usize,
+    /// The runtime bytecode size limit.
+    pub runtime: usize,
+    /// Whether overrunning the limits is an error rather than a warning.
+    #[serde(rename = "overrunAsError")]
+    pub is_overrun_error: bool,
+}
+
+impl CodeSizeLimits {
+    /// The EIP-3860 deploy bytecode size limit.
+    pub const DEFAULT_DEPLOY: usize = 49152;
+
+    /// The EIP-170 runtime bytecode size limit.
+    pub const DEFAULT_RUNTIME: usize = 24576;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(deploy: usize, runtime: usize, is_overrun_error: bool) -> Self {
+        Self {
+            deploy,
+            runtime,
+            is_overrun_error,
+        }
+    }
+
+    ///
+    /// Returns the limit of the `code_segment`.
+    ///
+    pub fn get(&self, code_segment: CodeSegment) -> usize {
+        match code_segment {
+            CodeSegment::Deploy => self.deploy,
+            CodeSegment::Runtime => self.runtime,
+        }
+    }
+}
+
+impl Default for CodeSizeLimits {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_DEPLOY, Self::DEFAULT_RUNTIME, false)
+    }
+}
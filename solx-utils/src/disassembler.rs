@@ -0,0 +1,131 @@
+//!
+//! EVM bytecode disassembler.
+//!
+
+use std::collections::BTreeMap;
+
+use crate::opcode::Opcode;
+
+///
+/// Disassembles `bytecode` into the `solc` space-separated opcode string.
+///
+/// `placeholders` maps the offsets of unlinked library addresses to their placeholders, which
+/// are printed instead of the zeroed `PUSH20` operands.
+///
+/// The trailing CBOR metadata is not decoded as instructions.
+///
+pub fn disassemble(bytecode: &[u8], placeholders: &BTreeMap<usize, String>) -> String {
+    let code_length = bytecode.len() - metadata_length(bytecode).unwrap_or_default();
+
+    let mut opcodes = String::with_capacity(code_length * 4);
+    let mut offset = 0;
+    while offset < code_length {
+        let opcode = Opcode(bytecode[offset]);
+        match opcode.name() {
+            Some(name) => opcodes.push_str(name),
+            None => opcodes.push_str(format!("0x{:X}", opcode.0).as_str()),
+        }
+
+        let push_size = opcode.push_size();
+        if push_size > 0 {
+            let operand_offset = offset + 1;
+            match placeholders.get(&operand_offset) {
+                Some(placeholder) => {
+                    opcodes.push(' ');
+                    opcodes.push_str(placeholder.as_str());
+                }
+                None => {
+                    let mut operand = bytecode[operand_offset.min(code_length)
+                        ..(operand_offset + push_size).min(code_length)]
+                        .to_vec();
+                    operand.resize(push_size, 0);
+
+                    let operand = hex::encode_upper(operand);
+                    let operand = operand.trim_start_matches('0');
+                    opcodes.push_str(" 0x");
+                    opcodes.push_str(if operand.is_empty() { "0" } else { operand });
+                }
+            }
+        }
+        opcodes.push(' ');
+
+        offset += 1 + push_size;
+    }
+    opcodes
+}
+
+///
+/// Returns the length of the CBOR metadata at the end of `bytecode`, including its
+/// two-byte length suffix.
+///
+/// Returns `None` if there is no metadata.
+///
+pub fn metadata_length(bytecode: &[u8]) -> Option<usize> {
+    let length_offset = bytecode.len().checked_sub(2)?;
+    let cbor_length = u16::from_be_bytes([bytecode[length_offset], bytecode[length_offset + 1]]);
+    let cbor_offset = length_offset.checked_sub(cbor_length as usize)?;
+    if cbor_length == 0 || !(0xA1..=0xA5).contains(&bytecode[cbor_offset]) {
+        return None;
+    }
+    Some(cbor_length as usize + 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn disassemble() {
+        let bytecode = hex::decode("608060405234801561000f575f5ffd5b").expect("Always valid");
+        assert_eq!(
+            super::disassemble(bytecode.as_slice(), &BTreeMap::new()),
+            "PUSH1 0x80 PUSH1 0x40 MSTORE CALLVALUE DUP1 ISZERO PUSH2 0xF JUMPI PUSH0 PUSH0 REVERT JUMPDEST ",
+        );
+    }
+
+    #[test]
+    fn disassemble_invalid_and_truncated() {
+        let bytecode = hex::decode("0c61ff").expect("Always valid");
+        assert_eq!(
+            super::disassemble(bytecode.as_slice(), &BTreeMap::new()),
+            "0xC PUSH2 0xFF00 ",
+        );
+    }
+
+    #[test]
+    fn disassemble_osaka() {
+        let bytecode = hex::decode("60011e00").expect("Always valid");
+        assert_eq!(
+            super::disassemble(bytecode.as_slice(), &BTreeMap::new()),
+            "PUSH1 0x1 CLZ STOP ",
+        );
+    }
+
+    #[test]
+    fn disassemble_placeholder() {
+        let mut bytecode = vec![0x73];
+        bytecode.extend([0u8; 20]);
+        bytecode.push(0x00);
+        let placeholders = BTreeMap::from([(1, "__$placeholder$__".to_owned())]);
+        assert_eq!(
+            super::disassemble(bytecode.as_slice(), &placeholders),
+            "PUSH20 __$placeholder$__ STOP ",
+        );
+    }
+
+    #[test]
+    fn disassemble_metadata() {
+        let cbor = crate::CBOR::<String>::new(
+            None,
+            "solc".to_owned(),
+            vec![("solx".to_owned(), semver::Version::new(0, 1, 0))],
+        )
+        .to_vec();
+        let mut bytecode = vec![0x5f, 0x00];
+        bytecode.extend(cbor);
+        assert_eq!(
+            super::disassemble(bytecode.as_slice(), &BTreeMap::new()),
+            "PUSH0 STOP ",
+        );
+    }
+}
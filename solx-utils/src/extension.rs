@@ -8,6 +8,9 @@ pub static EXTENSION_MANIFEST: &str = "toml";
 /// The JSON data file extension.
 pub static EXTENSION_JSON: &str = "json";
 
+/// The YAML data file extension.
+pub static EXTENSION_YAML: &str = "yaml";
+
 /// The ABI file extension.
 pub static EXTENSION_ABI: &str = "abi";
 
@@ -20,6 +23,9 @@ pub static EXTENSION_EVMLA: &str = "evmla";
 /// The Ethereal IR file extension.
 pub static EXTENSION_ETHIR: &str = "ethir";
 
+/// The Graphviz DOT graph file extension.
+pub static EXTENSION_DOT: &str = "dot";
+
 /// The EVM file extension.
 pub static EXTENSION_EVM: &str = "evm";
 
@@ -3,6 +3,7 @@
 //!
 
 use base58::FromBase58;
+use base58::ToBase58;
 
 ///
 /// IPFS hash utilities.
@@ -38,6 +39,24 @@ impl IPFS {
         }
     }
 
+    ///
+    /// Wraps the 34-byte IPFS hash `bytes`, e.g. decoded from the bytecode metadata.
+    ///
+    pub fn try_from_bytes(bytes: &[u8]) -> anyhow::Result<Self> {
+        let bytes: [u8; 2 + crate::BYTE_LENGTH_FIELD] = bytes.try_into().map_err(|_| {
+            anyhow::anyhow!(
+                "The IPFS hash must be {} bytes long, found {}.",
+                2 + crate::BYTE_LENGTH_FIELD,
+                bytes.len()
+            )
+        })?;
+        Ok(Self {
+            bytes,
+            string_base58: bytes.to_base58(),
+            string_hex: hex::encode(bytes),
+        })
+    }
+
     ///
     /// Returns a reference to the 34-byte IPFS hash.
     ///
@@ -91,6 +110,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn try_from_bytes() {
+        let hash = super::IPFS::from_slice("LLVM is the Endgame".as_bytes());
+        assert_eq!(
+            super::IPFS::try_from_bytes(hash.as_bytes()).expect("Always valid"),
+            hash
+        );
+        assert!(super::IPFS::try_from_bytes(&hash.as_bytes()[1..]).is_err());
+    }
+
     #[test]
     fn display() {
         assert_eq!(
@@ -4,9 +4,11 @@
 
 pub mod ipfs;
 pub mod keccak256;
+pub mod swarm;
 
 use self::ipfs::IPFS;
 use self::keccak256::Keccak256;
+use self::swarm::Swarm;
 
 ///
 /// Hash enum to make encoding easier.
@@ -17,6 +19,8 @@ pub enum Hash {
     IPFS(IPFS),
     /// Keccak256 hash.
     Keccak256(Keccak256),
+    /// Swarm hash.
+    Swarm(Swarm),
 }
 
 impl Hash {
@@ -27,6 +31,7 @@ impl Hash {
         match self {
             Hash::IPFS(hash) => hash.as_bytes(),
             Hash::Keccak256(hash) => hash.as_bytes(),
+            Hash::Swarm(hash) => hash.as_bytes(),
         }
     }
 
@@ -37,6 +42,7 @@ impl Hash {
         match self {
             Hash::IPFS(hash) => hash.as_hex_str(),
             Hash::Keccak256(hash) => hash.as_str(),
+            Hash::Swarm(hash) => hash.as_str(),
         }
     }
 }
@@ -52,3 +58,9 @@ impl From<Keccak256> for Hash {
         Self::Keccak256(hash)
     }
 }
+
+impl From<Swarm> for Hash {
+    fn from(hash: Swarm) -> Self {
+        Self::Swarm(hash)
+    }
+}
@@ -0,0 +1,173 @@
+//!
+//! Swarm hash utilities.
+//!
+
+use sha3::Digest;
+
+///
+/// Swarm hash utilities.
+///
+/// Implements the `bzzr1` binary Merkle tree hash, as computed by `solc`.
+///
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Swarm {
+    /// Binary representation.
+    bytes: [u8; crate::BYTE_LENGTH_FIELD],
+    /// Hexadecimal string representation.
+    string: String,
+}
+
+impl Swarm {
+    /// The Swarm chunk size in bytes.
+    pub const CHUNK_SIZE: usize = 4096;
+
+    /// The number of hashes a single intermediate chunk can reference.
+    pub const CHUNK_BRANCHES: usize = Self::CHUNK_SIZE / crate::BYTE_LENGTH_FIELD;
+
+    ///
+    /// Computes the `bzzr1` hash for `preimage`.
+    ///
+    /// The hash of an empty preimage is all zeros.
+    ///
+    pub fn from_slice(preimage: &[u8]) -> Self {
+        let bytes = if preimage.is_empty() {
+            [0u8; crate::BYTE_LENGTH_FIELD]
+        } else {
+            Self::chunk_hash(preimage, false)
+        };
+        let string = hex::encode(bytes);
+        Self { bytes, string }
+    }
+
+    ///
+    /// Returns a reference to the 32-byte Swarm hash.
+    ///
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    ///
+    /// Returns a reference to the hexadecimal string representation of the Swarm hash.
+    ///
+    pub fn as_str(&self) -> &str {
+        self.string.as_str()
+    }
+
+    ///
+    /// Extracts the binary representation.
+    ///
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.bytes.to_vec()
+    }
+
+    ///
+    /// Computes the hash of a chunk spanning `data`.
+    ///
+    /// The data larger than a chunk is split into subtrees, whose hashes constitute the chunk.
+    /// `is_forced_intermediate` makes a full-sized leaf an intermediate chunk, which is required
+    /// if its siblings are intermediate chunks as well.
+    ///
+    fn chunk_hash(data: &[u8], is_forced_intermediate: bool) -> [u8; crate::BYTE_LENGTH_FIELD] {
+        let mut chunk = if data.len() < Self::CHUNK_SIZE
+            || (data.len() == Self::CHUNK_SIZE && !is_forced_intermediate)
+        {
+            data.to_vec()
+        } else {
+            let mut subtree_size = Self::CHUNK_SIZE;
+            while subtree_size * Self::CHUNK_BRANCHES < data.len() {
+                subtree_size *= Self::CHUNK_BRANCHES;
+            }
+            let is_forced_intermediate = subtree_size > Self::CHUNK_SIZE;
+
+            let mut chunk = Vec::with_capacity(Self::CHUNK_SIZE);
+            for subtree in data.chunks(subtree_size) {
+                chunk.extend(Self::chunk_hash(subtree, is_forced_intermediate));
+            }
+            chunk
+        };
+        chunk.resize(Self::CHUNK_SIZE, 0);
+
+        let mut hasher = sha3::Keccak256::new();
+        hasher.update((data.len() as u64).to_le_bytes());
+        hasher.update(Self::bmt_hash(chunk.as_slice()));
+        hasher.finalize().into()
+    }
+
+    ///
+    /// Computes the binary Merkle tree hash of a chunk.
+    ///
+    fn bmt_hash(data: &[u8]) -> [u8; crate::BYTE_LENGTH_FIELD] {
+        if data.len() <= crate::BYTE_LENGTH_FIELD * 2 {
+            return sha3::Keccak256::digest(data).into();
+        }
+
+        let mut middle = crate::BYTE_LENGTH_FIELD;
+        while middle * 2 < data.len() {
+            middle *= 2;
+        }
+        let mut hasher = sha3::Keccak256::new();
+        hasher.update(Self::bmt_hash(&data[..middle]));
+        hasher.update(Self::bmt_hash(&data[middle..]));
+        hasher.finalize().into()
+    }
+}
+
+impl std::fmt::Display for Swarm {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn empty() {
+        assert_eq!(
+            super::Swarm::from_slice(&[]).as_str(),
+            "0000000000000000000000000000000000000000000000000000000000000000"
+        );
+    }
+
+    #[test]
+    fn short() {
+        assert_eq!(
+            super::Swarm::from_slice("hello world".as_bytes()).as_str(),
+            "92672a471f4419b255d7cb0cf313474a6f5856fb347c5ece85fb706d644b630f"
+        );
+    }
+
+    #[test]
+    fn single_chunk() {
+        assert_eq!(
+            super::Swarm::from_slice(vec![b'a'; super::Swarm::CHUNK_SIZE].as_slice()).as_str(),
+            "e3b049dc6984e0f87dd32fd738dc42281044ffb1baf276ada67432b89e949c05"
+        );
+    }
+
+    #[test]
+    fn two_chunks() {
+        assert_eq!(
+            super::Swarm::from_slice(vec![b'a'; super::Swarm::CHUNK_SIZE + 1].as_slice()).as_str(),
+            "04efeceb19c8ead5ba11a312dc5065e78a6e10a7e74a1a93682fc3555ed45ddf"
+        );
+    }
+
+    #[test]
+    fn two_levels() {
+        assert_eq!(
+            super::Swarm::from_slice(
+                vec![b'a'; super::Swarm::CHUNK_SIZE * super::Swarm::CHUNK_BRANCHES + 1].as_slice()
+            )
+            .as_str(),
+            "1ff2d752472b9e9a25d89b1ff538f35f73f9090174e6a4aedfde506b774232b4"
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            super::Swarm::from_slice("hello world".as_bytes()).to_string(),
+            "92672a471f4419b255d7cb0cf313474a6f5856fb347c5ece85fb706d644b630f"
+        );
+    }
+}
@@ -7,8 +7,10 @@ pub(crate) mod bit_length;
 pub(crate) mod byte_length;
 pub(crate) mod cbor;
 pub(crate) mod code_segment;
+pub(crate) mod code_size_limits;
 pub(crate) mod contract_name;
 pub(crate) mod deserializer;
+pub(crate) mod disassembler;
 pub(crate) mod error;
 pub(crate) mod evm_version;
 pub(crate) mod exit_code;
@@ -17,6 +19,7 @@ pub(crate) mod hash;
 pub(crate) mod libraries;
 pub(crate) mod llvm_ir;
 pub(crate) mod metadata_hash_type;
+pub(crate) mod opcode;
 pub(crate) mod target;
 
 pub use self::base::*;
@@ -24,16 +27,20 @@ pub use self::bit_length::*;
 pub use self::byte_length::*;
 pub use self::cbor::CBOR;
 pub use self::code_segment::CodeSegment;
+pub use self::code_size_limits::CodeSizeLimits;
 pub use self::contract_name::ContractName;
 pub use self::deserializer::*;
+pub use self::disassembler::*;
 pub use self::error::*;
 pub use self::evm_version::EVMVersion;
 pub use self::exit_code::*;
 pub use self::extension::*;
 pub use self::hash::ipfs::IPFS as IPFSHash;
 pub use self::hash::keccak256::Keccak256 as Keccak256Hash;
+pub use self::hash::swarm::Swarm as SwarmHash;
 pub use self::hash::Hash;
 pub use self::libraries::Libraries;
 pub use self::llvm_ir::*;
 pub use self::metadata_hash_type::MetadataHashType;
+pub use self::opcode::Opcode;
 pub use self::target::Target;
@@ -0,0 +1,334 @@
+//!
+//! EVM opcode.
+//!
+
+use crate::evm_version::EVMVersion;
+
+///
+/// EVM opcode.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Opcode(pub u8);
+
+impl Opcode {
+    /// The `SHL` opcode.
+    pub const SHL: u8 = 0x1b;
+
+    /// The `SHR` opcode.
+    pub const SHR: u8 = 0x1c;
+
+    /// The `SAR` opcode.
+    pub const SAR: u8 = 0x1d;
+
+    /// The `CLZ` opcode.
+    pub const CLZ: u8 = 0x1e;
+
+    /// The `RETURNDATASIZE` opcode.
+    pub const RETURNDATASIZE: u8 = 0x3d;
+
+    /// The `RETURNDATACOPY` opcode.
+    pub const RETURNDATACOPY: u8 = 0x3e;
+
+    /// The `EXTCODEHASH` opcode.
+    pub const EXTCODEHASH: u8 = 0x3f;
+
+    /// The `CHAINID` opcode.
+    pub const CHAINID: u8 = 0x46;
+
+    /// The `SELFBALANCE` opcode.
+    pub const SELFBALANCE: u8 = 0x47;
+
+    /// The `BASEFEE` opcode.
+    pub const BASEFEE: u8 = 0x48;
+
+    /// The `BLOBHASH` opcode.
+    pub const BLOBHASH: u8 = 0x49;
+
+    /// The `BLOBBASEFEE` opcode.
+    pub const BLOBBASEFEE: u8 = 0x4a;
+
+    /// The `TLOAD` opcode.
+    pub const TLOAD: u8 = 0x5c;
+
+    /// The `TSTORE` opcode.
+    pub const TSTORE: u8 = 0x5d;
+
+    /// The `MCOPY` opcode.
+    pub const MCOPY: u8 = 0x5e;
+
+    /// The `PUSH0` opcode.
+    pub const PUSH0: u8 = 0x5f;
+
+    /// The `PUSH1` opcode.
+    pub const PUSH1: u8 = 0x60;
+
+    /// The `PUSH32` opcode.
+    pub const PUSH32: u8 = 0x7f;
+
+    /// The `CREATE2` opcode.
+    pub const CREATE2: u8 = 0xf5;
+
+    /// The `STATICCALL` opcode.
+    pub const STATICCALL: u8 = 0xfa;
+
+    /// The `REVERT` opcode.
+    pub const REVERT: u8 = 0xfd;
+
+    /// The source-level instructions that are not available on all EVM versions.
+    ///
+    /// `PUSH0` is not listed, as it is only emitted by the code generator, which checks the
+    /// EVM version itself.
+    pub const VERSIONED: [u8; 18] = [
+        Self::SHL,
+        Self::SHR,
+        Self::SAR,
+        Self::CLZ,
+        Self::RETURNDATASIZE,
+        Self::RETURNDATACOPY,
+        Self::EXTCODEHASH,
+        Self::REVERT,
+        Self::STATICCALL,
+        Self::CREATE2,
+        Self::CHAINID,
+        Self::SELFBALANCE,
+        Self::BASEFEE,
+        Self::BLOBHASH,
+        Self::BLOBBASEFEE,
+        Self::TLOAD,
+        Self::TSTORE,
+        Self::MCOPY,
+    ];
+
+    ///
+    /// Returns the opcode with the case-insensitive `mnemonic`, if it is not available on all
+    /// EVM versions.
+    ///
+    /// Is shared by the Yul and EVM assembly pipelines, whose instruction names only differ in case.
+    ///
+    pub fn versioned(mnemonic: &str) -> Option<Self> {
+        Self::VERSIONED.into_iter().map(Self).find(|opcode| {
+            opcode
+                .name()
+                .is_some_and(|name| name.eq_ignore_ascii_case(mnemonic))
+        })
+    }
+
+    ///
+    /// Returns the opcode mnemonic, or `None` if the byte is not a valid opcode.
+    ///
+    pub fn name(&self) -> Option<&'static str> {
+        let name = match self.0 {
+            0x00 => "STOP",
+            0x01 => "ADD",
+            0x02 => "MUL",
+            0x03 => "SUB",
+            0x04 => "DIV",
+            0x05 => "SDIV",
+            0x06 => "MOD",
+            0x07 => "SMOD",
+            0x08 => "ADDMOD",
+            0x09 => "MULMOD",
+            0x0a => "EXP",
+            0x0b => "SIGNEXTEND",
+
+            0x10 => "LT",
+            0x11 => "GT",
+            0x12 => "SLT",
+            0x13 => "SGT",
+            0x14 => "EQ",
+            0x15 => "ISZERO",
+            0x16 => "AND",
+            0x17 => "OR",
+            0x18 => "XOR",
+            0x19 => "NOT",
+            0x1a => "BYTE",
+            0x1b => "SHL",
+            0x1c => "SHR",
+            0x1d => "SAR",
+            0x1e => "CLZ",
+
+            0x20 => "KECCAK256",
+
+            0x30 => "ADDRESS",
+            0x31 => "BALANCE",
+            0x32 => "ORIGIN",
+            0x33 => "CALLER",
+            0x34 => "CALLVALUE",
+            0x35 => "CALLDATALOAD",
+            0x36 => "CALLDATASIZE",
+            0x37 => "CALLDATACOPY",
+            0x38 => "CODESIZE",
+            0x39 => "CODECOPY",
+            0x3a => "GASPRICE",
+            0x3b => "EXTCODESIZE",
+            0x3c => "EXTCODECOPY",
+            0x3d => "RETURNDATASIZE",
+            0x3e => "RETURNDATACOPY",
+            0x3f => "EXTCODEHASH",
+
+            0x40 => "BLOCKHASH",
+            0x41 => "COINBASE",
+            0x42 => "TIMESTAMP",
+            0x43 => "NUMBER",
+            0x44 => "PREVRANDAO",
+            0x45 => "GASLIMIT",
+            0x46 => "CHAINID",
+            0x47 => "SELFBALANCE",
+            0x48 => "BASEFEE",
+            0x49 => "BLOBHASH",
+            0x4a => "BLOBBASEFEE",
+
+            0x50 => "POP",
+            0x51 => "MLOAD",
+            0x52 => "MSTORE",
+            0x53 => "MSTORE8",
+            0x54 => "SLOAD",
+            0x55 => "SSTORE",
+            0x56 => "JUMP",
+            0x57 => "JUMPI",
+            0x58 => "PC",
+            0x59 => "MSIZE",
+            0x5a => "GAS",
+            0x5b => "JUMPDEST",
+            0x5c => "TLOAD",
+            0x5d => "TSTORE",
+            0x5e => "MCOPY",
+            0x5f => "PUSH0",
+
+            0x60 => "PUSH1",
+            0x61 => "PUSH2",
+            0x62 => "PUSH3",
+            0x63 => "PUSH4",
+            0x64 => "PUSH5",
+            0x65 => "PUSH6",
+            0x66 => "PUSH7",
+            0x67 => "PUSH8",
+            0x68 => "PUSH9",
+            0x69 => "PUSH10",
+            0x6a => "PUSH11",
+            0x6b => "PUSH12",
+            0x6c => "PUSH13",
+            0x6d => "PUSH14",
+            0x6e => "PUSH15",
+            0x6f => "PUSH16",
+            0x70 => "PUSH17",
+            0x71 => "PUSH18",
+            0x72 => "PUSH19",
+            0x73 => "PUSH20",
+            0x74 => "PUSH21",
+            0x75 => "PUSH22",
+            0x76 => "PUSH23",
+            0x77 => "PUSH24",
+            0x78 => "PUSH25",
+            0x79 => "PUSH26",
+            0x7a => "PUSH27",
+            0x7b => "PUSH28",
+            0x7c => "PUSH29",
+            0x7d => "PUSH30",
+            0x7e => "PUSH31",
+            0x7f => "PUSH32",
+
+            0x80 => "DUP1",
+            0x81 => "DUP2",
+            0x82 => "DUP3",
+            0x83 => "DUP4",
+            0x84 => "DUP5",
+            0x85 => "DUP6",
+            0x86 => "DUP7",
+            0x87 => "DUP8",
+            0x88 => "DUP9",
+            0x89 => "DUP10",
+            0x8a => "DUP11",
+            0x8b => "DUP12",
+            0x8c => "DUP13",
+            0x8d => "DUP14",
+            0x8e => "DUP15",
+            0x8f => "DUP16",
+
+            0x90 => "SWAP1",
+            0x91 => "SWAP2",
+            0x92 => "SWAP3",
+            0x93 => "SWAP4",
+            0x94 => "SWAP5",
+            0x95 => "SWAP6",
+            0x96 => "SWAP7",
+            0x97 => "SWAP8",
+            0x98 => "SWAP9",
+            0x99 => "SWAP10",
+            0x9a => "SWAP11",
+            0x9b => "SWAP12",
+            0x9c => "SWAP13",
+            0x9d => "SWAP14",
+            0x9e => "SWAP15",
+            0x9f => "SWAP16",
+
+            0xa0 => "LOG0",
+            0xa1 => "LOG1",
+            0xa2 => "LOG2",
+            0xa3 => "LOG3",
+            0xa4 => "LOG4",
+
+            0xf0 => "CREATE",
+            0xf1 => "CALL",
+            0xf2 => "CALLCODE",
+            0xf3 => "RETURN",
+            0xf4 => "DELEGATECALL",
+            0xf5 => "CREATE2",
+            0xfa => "STATICCALL",
+            0xfd => "REVERT",
+            0xfe => "INVALID",
+            0xff => "SELFDESTRUCT",
+
+            _ => return None,
+        };
+        Some(name)
+    }
+
+    ///
+    /// Returns the number of immediate bytes following the opcode.
+    ///
+    pub fn push_size(&self) -> usize {
+        match self.0 {
+            Self::PUSH1..=Self::PUSH32 => (self.0 - Self::PUSH1 + 1) as usize,
+            _ => 0,
+        }
+    }
+
+    ///
+    /// Returns the EVM version where the opcode was introduced.
+    ///
+    pub fn evm_version(&self) -> EVMVersion {
+        match self.0 {
+            Self::RETURNDATASIZE | Self::RETURNDATACOPY | Self::STATICCALL | Self::REVERT => {
+                EVMVersion::Byzantium
+            }
+            Self::SHL | Self::SHR | Self::SAR | Self::EXTCODEHASH | Self::CREATE2 => {
+                EVMVersion::Constantinople
+            }
+            Self::CHAINID | Self::SELFBALANCE => EVMVersion::Istanbul,
+            Self::BASEFEE => EVMVersion::London,
+            Self::PUSH0 => EVMVersion::Shanghai,
+            Self::BLOBHASH | Self::BLOBBASEFEE | Self::TLOAD | Self::TSTORE | Self::MCOPY => {
+                EVMVersion::Cancun
+            }
+            Self::CLZ => EVMVersion::Osaka,
+            _ => EVMVersion::Homestead,
+        }
+    }
+
+    ///
+    /// Whether the opcode is one of the `PUSH` family, including `PUSH0`.
+    ///
+    pub fn is_push(&self) -> bool {
+        (Self::PUSH0..=Self::PUSH32).contains(&self.0)
+    }
+}
+
+impl std::fmt::Display for Opcode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.name() {
+            Some(name) => write!(f, "{name}"),
+            None => write!(f, "INVALID"),
+        }
+    }
+}
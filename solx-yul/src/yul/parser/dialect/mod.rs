@@ -16,9 +16,15 @@ use super::identifier::Identifier;
 ///
 /// Describes a pragmatic, target-specific part of the parser.
 ///
-pub trait Dialect: for<'de> Deserialize<'de> + Serialize + Eq + PartialEq + Debug {
+pub trait Dialect: for<'de> Deserialize<'de> + Serialize + Eq + PartialEq + Clone + Debug {
     /// Type of function attributes parsed from their identifiers.
-    type FunctionAttribute: for<'de> Deserialize<'de> + Debug + Eq + PartialEq + Serialize + Ord;
+    type FunctionAttribute: for<'de> Deserialize<'de>
+        + Debug
+        + Clone
+        + Eq
+        + PartialEq
+        + Serialize
+        + Ord;
 
     ///
     /// Extractor for the function attributes.
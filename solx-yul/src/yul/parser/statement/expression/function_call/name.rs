@@ -52,6 +52,8 @@ pub enum Name {
     Shr,
     /// signed arithmetic shift right `y` by `x` bits
     Sar,
+    /// number of leading zero bits of `x`, `256` if `x == 0`
+    Clz,
     /// `n`th byte of `x`, where the most significant byte is the `0`th byte
     Byte,
     /// discard value x
@@ -229,6 +231,22 @@ pub enum Name {
     SelfDestruct,
 }
 
+impl Name {
+    ///
+    /// Returns the EVM version since which the builtin name is reserved, or `None` if it is
+    /// reserved on all EVM versions or is not a builtin.
+    ///
+    /// Such names may still be used as identifiers by the parser, and are only resolved into
+    /// builtins during code generation, where the target EVM version is known.
+    ///
+    pub fn reserved_since(&self) -> Option<solx_utils::EVMVersion> {
+        match self {
+            Self::Clz => Some(solx_utils::EVMVersion::Osaka),
+            _ => None,
+        }
+    }
+}
+
 impl From<&str> for Name {
     fn from(input: &str) -> Self {
         match input {
@@ -254,6 +272,7 @@ impl From<&str> for Name {
             "shl" => Self::Shl,
             "shr" => Self::Shr,
             "sar" => Self::Sar,
+            "clz" => Self::Clz,
             "byte" => Self::Byte,
             "pop" => Self::Pop,
 
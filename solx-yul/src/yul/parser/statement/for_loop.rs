@@ -16,7 +16,7 @@ use crate::yul::parser::statement::expression::Expression;
 ///
 /// The Yul for-loop statement.
 ///
-#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
 #[serde(bound = "P: serde::de::DeserializeOwned")]
 pub struct ForLoop<P>
 where
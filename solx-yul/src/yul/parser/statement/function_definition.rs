@@ -27,7 +27,7 @@ use crate::yul::parser::statement::expression::function_call::name::Name as Func
 /// 1. The hoisted declaration
 /// 2. The definition, which now has the access to all function signatures
 ///
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(bound = "P: serde::de::DeserializeOwned")]
 pub struct FunctionDefinition<P>
 where
@@ -76,6 +76,7 @@ where
 
         match FunctionName::from(identifier.inner.as_str()) {
             FunctionName::UserDefined(_) => {}
+            function_name if function_name.reserved_since().is_some() => {}
             _function_name => {
                 return Err(ParserError::ReservedIdentifier {
                     location,
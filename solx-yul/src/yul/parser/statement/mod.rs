@@ -40,7 +40,7 @@ use super::dialect::Dialect;
 ///
 /// The Yul block statement.
 ///
-#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
 #[serde(bound = "P: serde::de::DeserializeOwned")]
 pub enum Statement<P>
 where
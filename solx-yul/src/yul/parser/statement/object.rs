@@ -3,7 +3,6 @@
 //!
 
 use std::collections::BTreeSet;
-use std::collections::HashSet;
 
 use crate::dependencies::Dependencies;
 use crate::yul::error::Error;
@@ -20,7 +19,7 @@ use crate::yul::parser::statement::code::Code;
 ///
 /// The upper-level Yul object, representing the deploy code.
 ///
-#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
 #[serde(bound = "P: serde::de::DeserializeOwned")]
 pub struct Object<P>
 where
@@ -37,7 +36,7 @@ where
     /// The factory dependency objects, which are represented by nested Yul object. The nested
     /// objects are duplicates of the upper-level objects describing the dependencies, so only
     /// their identifiers are preserved. The identifiers are used to address upper-level objects.
-    pub factory_dependencies: HashSet<String>,
+    pub factory_dependencies: BTreeSet<String>,
 }
 
 impl<P> Object<P>
@@ -102,7 +101,7 @@ where
 
         let code = Code::parse(lexer, None)?;
         let mut inner_object = None;
-        let mut factory_dependencies = HashSet::new();
+        let mut factory_dependencies = BTreeSet::new();
 
         if let solx_utils::CodeSegment::Deploy = code_segment {
             inner_object = match lexer.peek()? {
@@ -112,7 +111,7 @@ where
                 } if identifier.inner.as_str() == "object" => {
                     let mut object = Self::parse(lexer, None, solx_utils::CodeSegment::Runtime)?;
 
-                    factory_dependencies.extend(object.factory_dependencies.drain());
+                    factory_dependencies.append(&mut object.factory_dependencies);
                     Some(Box::new(object))
                 }
                 _ => None,
@@ -44,6 +44,7 @@ impl VariableDeclaration {
         for binding in bindings.iter() {
             match FunctionName::from(binding.inner.as_str()) {
                 FunctionName::UserDefined(_) => continue,
+                function_name if function_name.reserved_since().is_some() => continue,
                 _function_name => {
                     return Err(ParserError::ReservedIdentifier {
                         location: binding.location,
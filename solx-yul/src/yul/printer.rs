@@ -225,6 +225,7 @@ pub fn name_identifier(name: &Name) -> String {
             Name::Shl => "shl",
             Name::Shr => "shr",
             Name::Sar => "sar",
+            Name::Clz => "clz",
             Name::Byte => "byte",
             Name::Pop => "pop",
 
@@ -66,11 +66,21 @@ impl solx_core::Solc for Solc {
     ) -> anyhow::Result<solx_standard_json::Output> {
         let original_output_selection = input_json.settings.output_selection.to_owned();
         input_json.settings.output_selection.normalize();
+        let is_gas_estimates_selected = input_json
+            .settings
+            .output_selection
+            .is_set_for_any(solx_standard_json::InputSelector::GasEstimates);
         input_json.settings.output_selection.retain_solc();
         input_json
             .settings
             .output_selection
             .set_selector(solx_standard_json::InputSelector::Metadata);
+        if is_gas_estimates_selected {
+            input_json
+                .settings
+                .output_selection
+                .set_selector(solx_standard_json::InputSelector::MethodIdentifiers);
+        }
         input_json
             .settings
             .output_selection
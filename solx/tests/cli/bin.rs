@@ -114,6 +114,29 @@ fn stack_too_deep_llvm_suppressed() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn stack_too_deep_llvm_spill_area_diagnostic() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_STACK_TOO_DEEP_LLVM_PATH,
+        "--bin",
+        "-O1",
+    ];
+    let env_vars = vec![("EVM_DISABLE_MEMORY_SAFE_ASM_CHECK", "1".to_owned())];
+
+    let result = crate::cli::execute_solx_with_env_vars(args, env_vars)?;
+
+    result
+        .success()
+        .stderr(predicate::str::contains("Info: "))
+        .stderr(predicate::str::contains(
+            "stack-too-deep errors have been resolved by allocating a spill area of",
+        ));
+
+    Ok(())
+}
+
 #[test]
 fn fuzzed_linker_error() -> anyhow::Result<()> {
     crate::common::setup()?;
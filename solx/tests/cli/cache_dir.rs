@@ -0,0 +1,86 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use std::collections::BTreeSet;
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let cache_directory = TempDir::with_prefix("solx_cache")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--cache-dir",
+        cache_directory.path().to_str().unwrap(),
+    ];
+
+    let cold = crate::cli::execute_solx(args)?;
+    let cold_stdout = String::from_utf8_lossy(cold.get_output().stdout.as_slice()).to_string();
+    cold.success().stdout(predicate::str::contains("Binary"));
+    assert!(std::fs::read_dir(cache_directory.path())?.next().is_some());
+
+    let warm = crate::cli::execute_solx(args)?;
+    let warm_stdout = String::from_utf8_lossy(warm.get_output().stdout.as_slice()).to_string();
+    warm.success();
+    assert_eq!(cold_stdout, warm_stdout);
+
+    Ok(())
+}
+
+#[test]
+fn factory() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let cache_directory = TempDir::with_prefix("solx_cache")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_FACTORY_PATH,
+        "--via-ir",
+        "--bin",
+        "--cache-dir",
+        cache_directory.path().to_str().unwrap(),
+    ];
+
+    let cold = crate::cli::execute_solx(args)?;
+    let cold_stdout = String::from_utf8_lossy(cold.get_output().stdout.as_slice()).to_string();
+    cold.success().stdout(predicate::str::contains("Binary"));
+    let cold_entries = std::fs::read_dir(cache_directory.path())?
+        .map(|entry| entry.map(|entry| entry.file_name()))
+        .collect::<Result<BTreeSet<_>, _>>()?;
+
+    let warm = crate::cli::execute_solx(args)?;
+    let warm_stdout = String::from_utf8_lossy(warm.get_output().stdout.as_slice()).to_string();
+    warm.success();
+    let warm_entries = std::fs::read_dir(cache_directory.path())?
+        .map(|entry| entry.map(|entry| entry.file_name()))
+        .collect::<Result<BTreeSet<_>, _>>()?;
+    assert_eq!(cold_stdout, warm_stdout);
+    assert_eq!(cold_entries, warm_entries);
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_PATH,
+        "--cache-dir",
+        "cache",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Cache directory must be specified in standard JSON input settings.",
+    ));
+
+    Ok(())
+}
@@ -88,6 +88,91 @@ fn llvm_ir() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn ethir_graph() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_debug = TempDir::with_prefix("debug_output")?;
+
+    let args = &[
+        "--bin",
+        "--evmla",
+        crate::common::TEST_EVMLA_CONTRACT_PATH,
+        "--debug-output-dir",
+        tmp_dir_debug.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success();
+
+    let graphs = std::fs::read_dir(tmp_dir_debug.path())?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<PathBuf>, std::io::Error>>()?
+        .into_iter()
+        .filter(|path| path.extension().and_then(|extension| extension.to_str()) == Some("dot"))
+        .collect::<Vec<PathBuf>>();
+    assert_eq!(graphs.len(), 2, "Expected deploy and runtime code graphs");
+    for graph in graphs.iter() {
+        let graph = std::fs::read_to_string(graph)?;
+        assert!(graph.starts_with("digraph \"__entry\" {"));
+        assert!(graph.contains("entry stack (0): [  ]"));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn ethir_graph_recursion() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_debug = TempDir::with_prefix("debug_output")?;
+
+    let args = &[
+        "--bin",
+        "--evmla",
+        crate::common::TEST_EVMLA_CONTRACT_RECURSION_PATH,
+        "--debug-output-dir",
+        tmp_dir_debug.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success();
+
+    let graphs = std::fs::read_dir(tmp_dir_debug.path())?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<PathBuf>, std::io::Error>>()?
+        .into_iter()
+        .filter(|path| path.extension().and_then(|extension| extension.to_str()) == Some("dot"))
+        .map(std::fs::read_to_string)
+        .collect::<Result<Vec<String>, std::io::Error>>()?;
+    assert!(
+        graphs.len() >= 3,
+        "Expected deploy code, runtime code, and recursive function graphs"
+    );
+
+    let function = graphs
+        .iter()
+        .find(|graph| graph.starts_with("digraph \"f_rt_2\" {"))
+        .expect("The recursive function graph is missing");
+    assert!(function.contains("label=\"recursive function f_rt_2(1) -> 1\\lstack usage: "));
+    assert!(function.contains("recursive call f_rt_2\\l"));
+    assert!(function.contains("recursive return\\l"));
+    assert!(function.contains("\"f_rt_2\" [shape=ellipse, style=dashed, color=red];"));
+    assert!(function.contains("-> \"f_rt_2\" [style=dashed, color=red];"));
+    assert!(function.contains(", color=red];"));
+
+    let runtime_entry = graphs
+        .iter()
+        .find(|graph| {
+            graph.starts_with("digraph \"__entry\" {") && graph.contains("recursive call")
+        })
+        .expect("The runtime code entry graph is missing");
+    assert!(runtime_entry.contains("recursive call f_rt_2\\l"));
+    assert!(!runtime_entry.contains("recursive return\\l"));
+
+    Ok(())
+}
+
 #[test]
 fn standard_json() -> anyhow::Result<()> {
     crate::common::setup()?;
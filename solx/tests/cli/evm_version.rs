@@ -3,6 +3,7 @@
 //!
 
 use predicates::prelude::*;
+use tempfile::TempDir;
 
 #[test]
 fn default() -> anyhow::Result<()> {
@@ -38,9 +39,9 @@ fn yul() -> anyhow::Result<()> {
     ];
 
     let result = crate::cli::execute_solx(args)?;
-    result.failure().stderr(predicate::str::contains(
-        "EVM version is only allowed in Solidity mode",
-    ));
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"));
 
     Ok(())
 }
@@ -58,14 +59,138 @@ fn llvm_ir() -> anyhow::Result<()> {
         crate::common::TEST_LLVM_IR_CONTRACT_PATH,
     ];
 
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"));
+
+    Ok(())
+}
+
+#[test]
+fn llvm_ir_osaka() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let evm_version = solx_utils::EVMVersion::Osaka.to_string();
+    let args = &[
+        "--evm-version",
+        evm_version.as_str(),
+        "--llvm-ir",
+        "--bin",
+        crate::common::TEST_LLVM_IR_CONTRACT_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"));
+
+    Ok(())
+}
+
+#[test]
+fn invalid() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--evm-version",
+        "unknown",
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Unknown EVM version: unknown."));
+
+    Ok(())
+}
+
+#[test]
+fn yul_unsupported_instruction() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let evm_version = solx_utils::EVMVersion::Shanghai.to_string();
+    let args = &[
+        "--evm-version",
+        evm_version.as_str(),
+        "--yul",
+        "--bin",
+        crate::common::TEST_YUL_CONTRACT_TRANSIENT_STORAGE_PATH,
+    ];
+
     let result = crate::cli::execute_solx(args)?;
     result.failure().stderr(predicate::str::contains(
-        "EVM version is only allowed in Solidity mode",
+        "The `TSTORE` instruction is not supported by EVM version `shanghai`. It is only available since `cancun`.",
     ));
 
     Ok(())
 }
 
+#[test]
+fn yul_supported_instruction() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let evm_version = solx_utils::EVMVersion::Cancun.to_string();
+    let args = &[
+        "--evm-version",
+        evm_version.as_str(),
+        "--yul",
+        "--bin",
+        crate::common::TEST_YUL_CONTRACT_TRANSIENT_STORAGE_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"));
+
+    Ok(())
+}
+
+#[test]
+fn yul_user_defined_clz() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let evm_version = solx_utils::EVMVersion::Prague.to_string();
+    let args = &[
+        "--evm-version",
+        evm_version.as_str(),
+        "--yul",
+        "--bin",
+        crate::common::TEST_YUL_CONTRACT_COUNT_LEADING_ZEROS_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"));
+
+    Ok(())
+}
+
+#[test]
+fn yul_reserved_clz() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let evm_version = solx_utils::EVMVersion::Osaka.to_string();
+    let args = &[
+        "--evm-version",
+        evm_version.as_str(),
+        "--yul",
+        "--bin",
+        crate::common::TEST_YUL_CONTRACT_COUNT_LEADING_ZEROS_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("The identifier `clz` is reserved"));
+
+    Ok(())
+}
+
 #[test]
 fn standard_json() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -85,3 +210,138 @@ fn standard_json() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn target_cpu() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    for evm_version in [
+        solx_utils::EVMVersion::Homestead,
+        solx_utils::EVMVersion::TangerineWhistle,
+        solx_utils::EVMVersion::SpuriousDragon,
+        solx_utils::EVMVersion::Byzantium,
+        solx_utils::EVMVersion::Constantinople,
+        solx_utils::EVMVersion::Petersburg,
+        solx_utils::EVMVersion::Istanbul,
+        solx_utils::EVMVersion::Berlin,
+        solx_utils::EVMVersion::London,
+        solx_utils::EVMVersion::Paris,
+        solx_utils::EVMVersion::Shanghai,
+        solx_utils::EVMVersion::Cancun,
+        solx_utils::EVMVersion::Prague,
+        solx_utils::EVMVersion::Osaka,
+    ] {
+        let (output, stderr) = compile_standard_json(
+            evm_version,
+            "Solidity",
+            "MemoryCopy.sol",
+            crate::common::TEST_SOLIDITY_CONTRACT_MEMORY_COPY_PATH,
+        )?;
+        assert!(
+            !stderr.contains("is not a recognized processor"),
+            "{evm_version}: {stderr}"
+        );
+        let opcodes = opcodes(&output["contracts"]["MemoryCopy.sol"]["MemoryCopy"])?;
+        assert_eq!(
+            opcodes.contains(&"PUSH0"),
+            evm_version >= solx_utils::EVMVersion::Shanghai,
+            "{evm_version}: PUSH0 must be used only since shanghai"
+        );
+        if evm_version < solx_utils::EVMVersion::Cancun {
+            assert!(
+                !opcodes.contains(&"MCOPY"),
+                "{evm_version}: MCOPY must be used only since cancun"
+            );
+        }
+
+        let (output, _) = compile_standard_json(
+            evm_version,
+            "Yul",
+            "TransientStorage.yul",
+            crate::common::TEST_YUL_CONTRACT_TRANSIENT_STORAGE_PATH,
+        )?;
+        if evm_version < solx_utils::EVMVersion::Cancun {
+            assert!(
+                output["errors"]
+                    .as_array()
+                    .is_some_and(|errors| errors.iter().any(|error| error["severity"] == "error")),
+                "{evm_version}: TLOAD and TSTORE must be rejected before cancun"
+            );
+        } else {
+            let opcodes =
+                opcodes(&output["contracts"]["TransientStorage.yul"]["TransientStorage"])?;
+            assert!(
+                opcodes.contains(&"TLOAD") && opcodes.contains(&"TSTORE"),
+                "{evm_version}: TLOAD and TSTORE must be used since cancun"
+            );
+        }
+    }
+
+    Ok(())
+}
+
+///
+/// Compiles the `path` source in `language` for `evm_version` via standard JSON.
+///
+/// Returns the standard JSON output and the standard error stream.
+///
+fn compile_standard_json(
+    evm_version: solx_utils::EVMVersion,
+    language: &str,
+    name: &str,
+    path: &str,
+) -> anyhow::Result<(serde_json::Value, String)> {
+    let output_directory = TempDir::with_prefix("solx_output")?;
+    let input_path = output_directory.path().join("input.json");
+    let input = serde_json::json!({
+        "language": language,
+        "sources": {
+            name: {
+                "content": std::fs::read_to_string(path)?,
+            },
+        },
+        "settings": {
+            "evmVersion": evm_version.to_string(),
+            "outputSelection": {
+                "*": {
+                    "*": [
+                        "evm.bytecode.opcodes",
+                        "evm.deployedBytecode.opcodes",
+                    ],
+                },
+            },
+        },
+    });
+    std::fs::write(input_path.as_path(), input.to_string())?;
+
+    let args = &[
+        "--standard-json",
+        input_path.to_str().expect("Always valid"),
+    ];
+    let result = crate::cli::execute_solx(args)?;
+    let output = result.success().get_output().to_owned();
+    Ok((
+        serde_json::from_slice(output.stdout.as_slice())?,
+        String::from_utf8_lossy(output.stderr.as_slice()).into_owned(),
+    ))
+}
+
+///
+/// Returns the deploy and runtime code opcodes of the standard JSON output `contract`.
+///
+fn opcodes(contract: &serde_json::Value) -> anyhow::Result<Vec<&str>> {
+    ["bytecode", "deployedBytecode"]
+        .into_iter()
+        .map(|code| {
+            contract["evm"][code]["opcodes"]
+                .as_str()
+                .ok_or_else(|| anyhow::anyhow!("The {code} opcodes are missing"))
+        })
+        .collect::<anyhow::Result<Vec<&str>>>()
+        .map(|opcodes| {
+            opcodes
+                .into_iter()
+                .flat_map(str::split_whitespace)
+                .collect()
+        })
+}
@@ -0,0 +1,218 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use test_case::test_case;
+
+#[test]
+fn bin() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[crate::common::TEST_EVMLA_CONTRACT_PATH, "--evmla", "--bin"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary").count(1));
+
+    Ok(())
+}
+
+#[test]
+fn stdin() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--evmla",
+        "--bin",
+        solx_standard_json::InputSource::STDIN_INPUT_IDENTIFIER,
+    ];
+
+    let result =
+        crate::cli::execute_solx_with_stdin(args, crate::common::TEST_EVMLA_CONTRACT_PATH)?;
+
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary").count(1));
+
+    Ok(())
+}
+
+#[test]
+fn sub_assembly() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_EVMLA_CONTRACT_FACTORY_PATH,
+        "--evmla",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Factory.asm.json:sub_0.sub_0"))
+        .stdout(predicate::str::contains("Binary").count(2));
+
+    Ok(())
+}
+
+#[test]
+fn sub_assembly_passed_separately() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_EVMLA_CONTRACT_FACTORY_PATH,
+        crate::common::TEST_EVMLA_CONTRACT_PATH,
+        "--evmla",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("sub_0").not())
+        .stdout(predicate::str::contains("Binary").count(2));
+
+    Ok(())
+}
+
+#[test]
+fn metadata() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_EVMLA_CONTRACT_PATH,
+        "--evmla",
+        "--metadata",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Metadata"));
+
+    Ok(())
+}
+
+#[test_case("--ast-json")]
+#[test_case("--abi")]
+#[test_case("--hashes")]
+#[test_case("--userdoc")]
+#[test_case("--devdoc")]
+#[test_case("--storage-layout")]
+#[test_case("--transient-storage-layout")]
+#[test_case("--asm-solc-json")]
+#[test_case("--ir")]
+fn unavailable(flag: &str) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[crate::common::TEST_EVMLA_CONTRACT_PATH, "--evmla", flag];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "can be only emitted for Solidity contracts",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_input_text() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["--evmla", "--bin", crate::common::TEST_BROKEN_INPUT_PATH];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("EVM legacy assembly parsing:"));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_input_runtime_code() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--evmla",
+        "--bin",
+        crate::common::TEST_EVMLA_CONTRACT_INVALID_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Runtime code data not found"));
+
+    Ok(())
+}
+
+#[test]
+fn missing_file() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["--evmla", "--bin", crate::common::TEST_NON_EXISTENT_PATH];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("reading:"));
+
+    Ok(())
+}
+
+#[test]
+fn excess_mode_standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_EVMLA_CONTRACT_PATH,
+        "--evmla",
+        "--standard-json",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Only one mode is allowed at the same time",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_EVMLA_STANDARD_JSON_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("sub_0.sub_0"))
+        .stdout(predicate::str::contains("bytecode"));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json_invalid() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_EVMLA_STANDARD_JSON_INVALID_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Runtime code data not found"));
+
+    Ok(())
+}
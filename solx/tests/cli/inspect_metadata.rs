@@ -0,0 +1,185 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+use test_case::test_case;
+
+#[test_case("ipfs")]
+#[test_case("bzzr1")]
+fn default(metadata_hash_type: &str) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+    let paths = compile(&output_directory, metadata_hash_type)?;
+
+    let mut args = vec!["--inspect-metadata"];
+    args.extend(paths.iter().map(String::as_str));
+
+    let result = crate::cli::execute_solx(args.as_slice())?;
+    let output = result.success().get_output().stdout.to_owned();
+    let output: serde_json::Value = serde_json::from_slice(output.as_slice())?;
+    for path in paths.iter() {
+        let metadata = &output[path.as_str()];
+        assert_eq!(metadata["solx"], env!("CARGO_PKG_VERSION"));
+        assert!(metadata["solc"].is_string());
+        assert!(metadata["llvm"].is_string());
+        assert!(metadata[metadata_hash_type].is_string());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn ipfs_cid() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+    let paths = compile(&output_directory, "ipfs")?;
+
+    let mut args = vec!["--inspect-metadata"];
+    args.extend(paths.iter().map(String::as_str));
+
+    let result = crate::cli::execute_solx(args.as_slice())?;
+    let output = result.success().get_output().stdout.to_owned();
+    let output: serde_json::Value = serde_json::from_slice(output.as_slice())?;
+    for path in paths.iter() {
+        let hash = output[path.as_str()]["ipfs"]
+            .as_str()
+            .expect("Always exists");
+        assert!(hash.starts_with("Qm"), "`{hash}` is not a base58 CID");
+        assert_eq!(hash.len(), 46);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn unlinked() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_LINKER_MIXED_DEPS_PATH,
+        "--bin",
+        "--output-dir",
+        output_directory.path().to_str().expect("Always valid"),
+    ];
+    crate::cli::execute_solx(args)?.success();
+
+    let mut paths = Vec::new();
+    for entry in std::fs::read_dir(output_directory.path())? {
+        paths.push(entry?.path().to_string_lossy().to_string());
+    }
+    let is_unlinked = paths.iter().try_fold(false, |is_unlinked, path| {
+        std::fs::read_to_string(path).map(|bytecode| is_unlinked || bytecode.contains("__$"))
+    })?;
+    assert!(is_unlinked, "The test contract is expected to be unlinked");
+
+    let mut args = vec!["--inspect-metadata"];
+    args.extend(paths.iter().map(String::as_str));
+
+    let result = crate::cli::execute_solx(args.as_slice())?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"solx\""));
+
+    Ok(())
+}
+
+#[test]
+fn metadata_hash_none() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+    let paths = compile(&output_directory, "none")?;
+
+    let mut args = vec!["--inspect-metadata"];
+    args.extend(paths.iter().map(String::as_str));
+
+    let result = crate::cli::execute_solx(args.as_slice())?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"solx\""))
+        .stdout(predicate::str::contains("\"ipfs\"").not());
+
+    Ok(())
+}
+
+#[test]
+fn invalid_length() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+    let path = output_directory.path().join("bytecode.bin");
+    std::fs::write(path.as_path(), "0x6080604052ffff")?;
+
+    let args = &["--inspect-metadata", path.to_str().expect("Always valid")];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "The CBOR metadata length suffix 65535 exceeds the bytecode size",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn output() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--inspect-metadata",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "No outputs can be emitted in metadata inspection mode",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--inspect-metadata",
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Only one mode is allowed at the same time:",
+    ));
+
+    Ok(())
+}
+
+///
+/// Compiles the test contract with `metadata_hash_type` into `output_directory`.
+///
+/// Returns the paths to the bytecode files.
+///
+fn compile(output_directory: &TempDir, metadata_hash_type: &str) -> anyhow::Result<Vec<String>> {
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--metadata-hash",
+        metadata_hash_type,
+        "--output-dir",
+        output_directory.path().to_str().unwrap(),
+    ];
+    crate::cli::execute_solx(args)?.success();
+
+    let mut paths = Vec::new();
+    for entry in std::fs::read_dir(output_directory.path())? {
+        paths.push(entry?.path().to_string_lossy().to_string());
+    }
+    Ok(paths)
+}
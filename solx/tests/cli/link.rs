@@ -0,0 +1,108 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+    let paths = compile_unlinked(&output_directory)?;
+
+    let mut args = vec![
+        "--link",
+        "--libraries",
+        crate::common::LIBRARY_LINKER_MIXED_DEPS,
+    ];
+    args.extend(paths.iter().map(String::as_str));
+
+    let result = crate::cli::execute_solx(args.as_slice())?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Linking completed."))
+        .stdout(predicate::str::contains("unresolved libraries").not());
+    for path in paths.iter() {
+        assert!(!std::fs::read_to_string(path)?.contains("__$"));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn unresolved() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+    let paths = compile_unlinked(&output_directory)?;
+
+    let mut args = vec!["--link"];
+    args.extend(paths.iter().map(String::as_str));
+
+    let result = crate::cli::execute_solx(args.as_slice())?;
+    result
+        .success()
+        .stdout(predicate::str::contains("unresolved libraries: __$"))
+        .stdout(predicate::str::contains("Linking completed."));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--link",
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Only one mode is allowed at the same time:",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn output() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_LINKER_MIXED_DEPS_PATH,
+        "--link",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "No outputs can be emitted in linker mode",
+    ));
+
+    Ok(())
+}
+
+///
+/// Compiles the test contract with unlinked libraries into `output_directory`.
+///
+/// Returns the paths to the bytecode files.
+///
+fn compile_unlinked(output_directory: &TempDir) -> anyhow::Result<Vec<String>> {
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_LINKER_MIXED_DEPS_PATH,
+        "--bin",
+        "--output-dir",
+        output_directory.path().to_str().unwrap(),
+    ];
+    crate::cli::execute_solx(args)?.success();
+
+    let mut paths = Vec::new();
+    for entry in std::fs::read_dir(output_directory.path())? {
+        paths.push(entry?.path().to_string_lossy().to_string());
+    }
+    Ok(paths)
+}
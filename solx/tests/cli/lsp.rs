@@ -0,0 +1,139 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["--lsp"];
+
+    let result = crate::cli::execute_solx_with_stdin(args, crate::common::TEST_LSP_SESSION_PATH)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"capabilities\""))
+        .stdout(predicate::str::contains("textDocument/publishDiagnostics"))
+        .stdout(predicate::str::contains("\"severity\":1").not())
+        .stdout(predicate::str::contains("deploy code: "))
+        .stdout(predicate::str::contains("runtime code: "));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_source() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["--lsp"];
+
+    let result =
+        crate::cli::execute_solx_with_stdin(args, crate::common::TEST_LSP_SESSION_INVALID_PATH)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("textDocument/publishDiagnostics"))
+        .stdout(predicate::str::contains("\"severity\":1"))
+        .stdout(predicate::str::contains("\"line\":3"));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_params() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["--lsp"];
+
+    let result = crate::cli::execute_solx_with_stdin(
+        args,
+        crate::common::TEST_LSP_SESSION_INVALID_PARAMS_PATH,
+    )?;
+    result
+        .success()
+        .stdout(predicate::str::contains("window/logMessage"))
+        .stdout(predicate::str::contains(
+            "Method `textDocument/didOpen` parameters are invalid",
+        ))
+        .stdout(predicate::str::contains(
+            "\"id\":2,\"error\":{\"code\":-32602",
+        ))
+        .stdout(predicate::str::contains(
+            "Method `textDocument/codeLens` parameters are invalid",
+        ))
+        .stdout(predicate::str::contains("\"id\":3,\"result\":null"));
+
+    Ok(())
+}
+
+#[test]
+fn spill_area_code_lens() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["--lsp", "-O1"];
+    let env_vars = vec![("EVM_DISABLE_MEMORY_SAFE_ASM_CHECK", "1".to_owned())];
+
+    let result = crate::cli::execute_solx_with_stdin_and_env_vars(
+        args,
+        crate::common::TEST_LSP_SESSION_STACK_TOO_DEEP_PATH,
+        env_vars,
+    )?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"id\":2,\"result\":[{"))
+        .stdout(predicate::str::contains(
+            "stack-too-deep errors have been resolved by allocating a spill area of",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn size_fallback_code_lens() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--lsp",
+        "--optimization-size-fallback",
+        "--runtime-code-size-limit",
+        "1",
+    ];
+
+    let result = crate::cli::execute_solx_with_stdin(args, crate::common::TEST_LSP_SESSION_PATH)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"id\":2,\"result\":[{"))
+        .stdout(predicate::str::contains(
+            "runtime code exceeds the EVM bytecode size limit, so it has been recompiled with size optimizations",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn input_files() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["--lsp", crate::common::TEST_SOLIDITY_CONTRACT_PATH];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Input files cannot be passed in LSP mode, as the open documents are sent by the client.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["--lsp", "--standard-json"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Only one mode is allowed at the same time:",
+    ));
+
+    Ok(())
+}
@@ -41,6 +41,26 @@ fn ipfs() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn bzzr1() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let hash_type = MetadataHashType::Swarm.to_string();
+    let args = &[
+        "--metadata-hash",
+        hash_type.as_str(),
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("a265627a7a72315820"));
+
+    Ok(())
+}
+
 #[test]
 fn standard_json_cli_excess_arg() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -18,17 +18,22 @@ mod base_path;
 mod benchmarks;
 mod bin;
 mod bin_runtime;
+mod cache_dir;
 mod debug_output_dir;
 mod devdoc;
 mod empty;
 mod evm_version;
+mod evmla;
 mod hashes;
 mod help;
 mod include_path;
+mod inspect_metadata;
 mod ir;
 mod libraries;
+mod link;
 mod llvm_ir;
 mod llvm_options;
+mod lsp;
 mod metadata;
 mod metadata_hash;
 mod metadata_literal;
@@ -40,13 +45,19 @@ mod output_dir;
 mod overwrite;
 mod recursive_process;
 mod remappings;
+mod remarks;
+mod runtime_code_size_limit;
+mod size;
 mod standard_json;
 mod storage_layout;
 mod threads;
+mod trace;
 mod transient_storage_layout;
 mod userdoc;
+mod verify;
 mod version;
 mod via_ir;
+mod watch;
 mod yul;
 
 ///
@@ -77,11 +88,25 @@ pub fn execute_solx_with_env_vars(
 pub fn execute_solx_with_stdin(
     args: &[&str],
     path: &str,
+) -> anyhow::Result<assert_cmd::assert::Assert> {
+    execute_solx_with_stdin_and_env_vars(args, path, vec![])
+}
+
+///
+/// Execute `solx` with the given arguments, stdin input, and environment variables, and assert the result.
+///
+pub fn execute_solx_with_stdin_and_env_vars(
+    args: &[&str],
+    path: &str,
+    env_vars: Vec<(&str, String)>,
 ) -> anyhow::Result<assert_cmd::assert::Assert> {
     let content = std::fs::read_to_string(path)
         .map_err(|error| anyhow::anyhow!("Failed to read file {path}: {error}"))?;
 
     let mut command = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    for (key, value) in env_vars.into_iter() {
+        command.env(key, value);
+    }
     command.stdin(std::process::Stdio::piped());
     command.stdout(std::process::Stdio::piped());
     command.stderr(std::process::Stdio::piped());
@@ -0,0 +1,183 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+use test_case::test_case;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[crate::common::TEST_SOLIDITY_CONTRACT_PATH, "--remarks"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Optimization remarks:\n"))
+        .stdout(predicate::str::contains(format!(
+            "--- !Remark\nContract: \"{}:Test\"\nCodeSegment: deploy\nPipelineElement: \"OptimizeVerify\"\nMessage: \"",
+            crate::common::TEST_SOLIDITY_CONTRACT_PATH
+        )))
+        .stdout(predicate::str::contains("CodeSegment: runtime"))
+        .stdout(predicate::str::contains("inlined into"))
+        .stdout(predicate::str::contains("\"\n...\n"));
+
+    Ok(())
+}
+
+#[test]
+fn json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[crate::common::TEST_SOLIDITY_CONTRACT_PATH, "--remarks=json"];
+
+    let result = crate::cli::execute_solx(args)?;
+    let stdout = String::from_utf8(result.success().get_output().stdout.clone())?;
+    let remarks_json = stdout
+        .split_once("Optimization remarks:\n")
+        .map(|(_, remarks)| remarks)
+        .ok_or_else(|| anyhow::anyhow!("The remarks are missing"))?;
+    let remarks: Vec<serde_json::Value> = serde_json::Deserializer::from_str(remarks_json)
+        .into_iter::<Vec<serde_json::Value>>()
+        .next()
+        .ok_or_else(|| anyhow::anyhow!("The remarks array is missing"))??;
+
+    assert!(!remarks.is_empty());
+    for remark in remarks.iter() {
+        for field in ["contractPath", "codeSegment", "pipelineElement", "message"] {
+            assert!(
+                remark
+                    .get(field)
+                    .and_then(serde_json::Value::as_str)
+                    .is_some(),
+                "`{field}` is missing in {remark}"
+            );
+        }
+    }
+    for code_segment in ["deploy", "runtime"] {
+        assert!(remarks
+            .iter()
+            .any(|remark| remark["codeSegment"] == code_segment
+                && remark["pipelineElement"] == "OptimizeVerify"));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn spill_area() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_STACK_TOO_DEEP_LLVM_PATH,
+        "--remarks",
+        "-O1",
+    ];
+    let env_vars = vec![("EVM_DISABLE_MEMORY_SAFE_ASM_CHECK", "1".to_owned())];
+
+    let result = crate::cli::execute_solx_with_env_vars(args, env_vars)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(
+            "PipelineElement: \"InitVerify\"\nMessage: \"A spill area of ",
+        ))
+        .stdout(predicate::str::contains(
+            "has been allocated at offset 128 to resolve stack-too-deep errors",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn size_fallback() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--remarks",
+        "--optimization-size-fallback",
+        "--runtime-code-size-limit",
+        "1",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(
+            "CodeSegment: runtime\nPipelineElement: \"EmitBytecode\"\nMessage: \"The bytecode size of ",
+        ))
+        .stdout(predicate::str::contains(
+            "exceeds the limit of 1B, so the module is recompiled with size optimizations",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_format() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[crate::common::TEST_SOLIDITY_CONTRACT_PATH, "--remarks=toml"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "unknown optimization remarks format: `toml`",
+    ));
+
+    Ok(())
+}
+
+#[test_case(solx_codegen_evm::RemarkFormat::YAML, solx_utils::EXTENSION_YAML)]
+#[test_case(solx_codegen_evm::RemarkFormat::JSON, solx_utils::EXTENSION_JSON)]
+fn output_dir(format: solx_codegen_evm::RemarkFormat, extension: &str) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+    let remarks_argument = format!("--remarks={format}");
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        remarks_argument.as_str(),
+        "--output-dir",
+        output_directory.path().to_str().expect("Always valid"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Compiler run successful"));
+
+    let suffix = format!("_remarks.{extension}");
+    assert!(std::fs::read_dir(output_directory.path())?.any(|entry| {
+        entry
+            .map(|entry| {
+                entry
+                    .file_name()
+                    .to_string_lossy()
+                    .ends_with(suffix.as_str())
+            })
+            .unwrap_or_default()
+    }));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_PATH,
+        "--remarks",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Cannot output data outside of JSON in standard JSON mode.",
+    ));
+
+    Ok(())
+}
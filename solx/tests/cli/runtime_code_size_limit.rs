@@ -0,0 +1,66 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--runtime-code-size-limit",
+        "1",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"))
+        .stderr(predicate::str::contains("that exceeds the EVM limit of 1B"));
+
+    Ok(())
+}
+
+#[test]
+fn overrun_error() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--runtime-code-size-limit",
+        "1",
+        "--code-size-overrun-error",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+
+    result
+        .failure()
+        .stderr(predicate::str::contains("that exceeds the EVM limit of 1B"));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_PATH,
+        "--runtime-code-size-limit",
+        "1",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Bytecode size limits must be specified in standard JSON input settings.",
+    ));
+
+    Ok(())
+}
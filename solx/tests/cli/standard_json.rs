@@ -294,6 +294,24 @@ fn missing_sources() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn metadata_hash_bzzr1_and_metadata() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_JSON_METADATA_HASH_BZZR1_AND_METADATA,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("a265627a7a72315820"))
+        .stdout(predicate::str::contains("\"metadata\""));
+
+    Ok(())
+}
+
 #[test]
 fn metadata_hash_ipfs_and_metadata() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -438,11 +456,75 @@ fn select_evm_bytecode_opcodes() -> anyhow::Result<()> {
     result
         .success()
         .stdout(predicate::str::contains("opcodes"))
+        .stdout(predicate::str::contains("\"opcodes\":\"\"").not())
+        .stdout(predicate::str::contains("JUMPDEST"))
         .stdout(predicate::str::contains("deployedBytecode").not());
 
     Ok(())
 }
 
+#[test]
+fn select_evm_source_maps() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_SOURCE_MAPS_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"sourceMap\"").count(2))
+        .stdout(predicate::str::contains("\"sourceMap\":\"\"").not())
+        .stdout(predicate::str::contains("\"object\"").not());
+
+    Ok(())
+}
+
+#[test]
+fn select_evm_gas_estimates() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_GAS_ESTIMATES_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"codeDepositCost\""))
+        .stdout(predicate::str::contains("\"executionCost\""))
+        .stdout(predicate::str::contains("\"totalCost\""))
+        .stdout(predicate::str::contains("\"f(uint256)\""))
+        .stdout(predicate::str::contains("\"methodIdentifiers\"").not())
+        .stdout(predicate::str::contains("\"bytecode\"").not());
+
+    Ok(())
+}
+
+#[test]
+fn select_evm_function_debug_data() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_FUNCTION_DEBUG_DATA_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"functionDebugData\"").count(2))
+        .stdout(predicate::str::contains("\"entryPoint\""))
+        .stdout(predicate::str::contains("\"parameterSlots\""))
+        .stdout(predicate::str::contains("\"returnSlots\""))
+        .stdout(predicate::str::contains("\"object\"").not());
+
+    Ok(())
+}
+
 #[test]
 fn select_evm_deployed_bytecode_link_references() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -534,3 +616,134 @@ fn select_all(path: &str) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn contract_overrides() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_CONTRACT_OVERRIDES_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"object\"").count(2))
+        .stdout(predicate::str::contains("\"errors\"").not());
+
+    Ok(())
+}
+
+#[test]
+fn contract_overrides_invalid_mode() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_CONTRACT_OVERRIDES_INVALID_MODE_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Contract `A` optimizer settings override: unexpected optimization option 'x'",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn code_size_limits() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_CODE_SIZE_LIMITS_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"severity\":\"error\""))
+        .stdout(predicate::str::contains("that exceeds the EVM limit of 1B"));
+
+    Ok(())
+}
+
+#[test]
+fn select_evm_bytecode_size() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_BYTECODE_SIZE_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"size\"").count(2))
+        .stdout(predicate::str::contains("\"limit\":131072"))
+        .stdout(predicate::str::contains("\"dependencies\"").count(2));
+
+    Ok(())
+}
+
+#[test]
+fn select_evm_llvm_ir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_LLVM_IR_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"llvmIr\"").count(2))
+        .stdout(predicate::str::contains("\"llvmIrOptimized\"").count(2))
+        .stdout(predicate::str::contains("define"))
+        .stdout(predicate::str::contains("\"object\"").not())
+        .stdout(predicate::str::contains("\"llvmAssembly\"").not());
+
+    Ok(())
+}
+
+#[test]
+fn select_evm_remarks() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_REMARKS_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"remarks\"").count(2))
+        .stdout(predicate::str::contains("\"pipelineElement\""))
+        .stdout(predicate::str::contains("\"object\"").not())
+        .stdout(predicate::str::contains("\"llvmIr\"").not());
+
+    Ok(())
+}
+
+#[test]
+fn select_evm_deployed_bytecode_immutable_references() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_DEPLOYED_BYTECODE_IMMUTABLE_REFERENCES_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"immutableReferences\":{\""))
+        .stdout(predicate::str::contains("\"length\":32"));
+
+    Ok(())
+}
@@ -0,0 +1,165 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let trace_directory = TempDir::with_prefix("solx_trace")?;
+    let trace_path = trace_directory.path().join("trace.json");
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--trace",
+        trace_path.to_str().expect("Always valid"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains("Binary"));
+
+    let names = trace_event_names(trace_path.as_path())?;
+    for name in [
+        "solc_Solidity_Standard_JSON",
+        "solx_Solidity_IR_Analysis",
+        "solx_Compilation",
+        "solx_Linking",
+    ] {
+        assert!(
+            names.iter().any(|event| event == name),
+            "`{name}` is missing"
+        );
+    }
+    for name in ["InitVerify", "OptimizeVerify", "EmitBytecode"] {
+        assert!(
+            names.iter().any(|event| event.starts_with(name)),
+            "`{name}` is missing"
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let trace_directory = TempDir::with_prefix("solx_trace")?;
+    let trace_path = trace_directory.path().join("trace.json");
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        "--yul",
+        "--bin",
+        "--trace",
+        trace_path.to_str().expect("Always valid"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains("Binary"));
+
+    let names = trace_event_names(trace_path.as_path())?;
+    assert!(names.iter().any(|event| event == "solx_Yul_IR_Analysis"));
+    assert!(names.iter().any(|event| event.starts_with("YulToLLVMIR")));
+
+    Ok(())
+}
+
+#[test]
+fn passes() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let trace_directory = TempDir::with_prefix("solx_trace")?;
+    let trace_path = trace_directory.path().join("trace.json");
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_STACK_TOO_DEEP_LLVM_PATH,
+        "--bin",
+        "-O1",
+        "--trace",
+        trace_path.to_str().expect("Always valid"),
+    ];
+    let env_vars = vec![("EVM_DISABLE_MEMORY_SAFE_ASM_CHECK", "1".to_owned())];
+
+    let result = crate::cli::execute_solx_with_env_vars(args, env_vars)?;
+    result.success().stdout(predicate::str::contains("Binary"));
+
+    let names = trace_event_names(trace_path.as_path())?;
+    for name in ["Pass1/", "Pass2/"] {
+        assert!(
+            names.iter().any(|event| event.starts_with(name)),
+            "`{name}` is missing"
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn error() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let trace_directory = TempDir::with_prefix("solx_trace")?;
+    let trace_path = trace_directory.path().join("trace.json");
+
+    let evm_version = solx_utils::EVMVersion::Osaka.to_string();
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT_COUNT_LEADING_ZEROS_PATH,
+        "--yul",
+        "--bin",
+        "--evm-version",
+        evm_version.as_str(),
+        "--trace",
+        trace_path.to_str().expect("Always valid"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("The identifier `clz` is reserved"));
+
+    let names = trace_event_names(trace_path.as_path())?;
+    assert!(names.iter().any(|event| event == "solx_Compilation"));
+    assert!(names.iter().any(|event| event.starts_with("Pass1/")));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_PATH,
+        "--trace",
+        "trace.json",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Cannot output data outside of JSON in standard JSON mode.",
+    ));
+
+    Ok(())
+}
+
+///
+/// Reads the Chrome trace at `path`, and returns the names of its events.
+///
+fn trace_event_names(path: &std::path::Path) -> anyhow::Result<Vec<String>> {
+    let trace: serde_json::Value = serde_json::from_slice(std::fs::read(path)?.as_slice())?;
+    let events = trace
+        .get("traceEvents")
+        .and_then(serde_json::Value::as_array)
+        .ok_or_else(|| anyhow::anyhow!("The `traceEvents` array is missing"))?;
+    Ok(events
+        .iter()
+        .filter_map(|event| event.get("name").and_then(serde_json::Value::as_str))
+        .map(str::to_owned)
+        .collect())
+}
@@ -0,0 +1,442 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use std::path::PathBuf;
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+/// The CBOR metadata prefix preceding the IPFS hash.
+const METADATA_IPFS_PREFIX: &str = "a2646970667358221220";
+
+#[test]
+fn runtime() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+    let (metadata_path, _deploy_path, runtime_path) = compile(
+        &output_directory,
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "Test",
+    )?;
+
+    let args = &[
+        "--verify",
+        metadata_path.to_str().expect("Always valid"),
+        runtime_path.to_str().expect("Always valid"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("runtime code matches."));
+
+    Ok(())
+}
+
+#[test]
+fn deploy() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+    let (metadata_path, deploy_path, _runtime_path) = compile(
+        &output_directory,
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "Test",
+    )?;
+
+    let args = &[
+        "--verify",
+        metadata_path.to_str().expect("Always valid"),
+        deploy_path.to_str().expect("Always valid"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("deploy code matches."));
+
+    Ok(())
+}
+
+#[test]
+fn mismatch() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+    let (metadata_path, _deploy_path, runtime_path) = compile(
+        &output_directory,
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "Test",
+    )?;
+    let bytecode = std::fs::read_to_string(runtime_path.as_path())?;
+    std::fs::write(runtime_path.as_path(), format!("ff{}", &bytecode[2..]))?;
+
+    let args = &[
+        "--verify",
+        metadata_path.to_str().expect("Always valid"),
+        runtime_path.to_str().expect("Always valid"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stdout(predicate::str::contains("runtime code does not match."))
+        .stdout(predicate::str::contains(
+            "0x0000..0x0001 (1 bytes): expected 0x",
+        ))
+        .stderr(predicate::str::contains("Verification failed."));
+
+    Ok(())
+}
+
+#[test]
+fn metadata_mismatch() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+    let (metadata_path, _deploy_path, runtime_path) = compile(
+        &output_directory,
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "Test",
+    )?;
+    let bytecode = std::fs::read_to_string(runtime_path.as_path())?;
+    let bytecode = bytecode.trim();
+    let hash_offset =
+        bytecode.rfind(METADATA_IPFS_PREFIX).expect("Always exists") + METADATA_IPFS_PREFIX.len();
+    let hash_byte = u8::from_str_radix(&bytecode[hash_offset..hash_offset + 2], 16)? ^ 1;
+    std::fs::write(
+        runtime_path.as_path(),
+        format!(
+            "{}{hash_byte:02x}{}",
+            &bytecode[..hash_offset],
+            &bytecode[hash_offset + 2..]
+        ),
+    )?;
+
+    let args = &[
+        "--verify",
+        metadata_path.to_str().expect("Always valid"),
+        runtime_path.to_str().expect("Always valid"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stdout(predicate::str::contains("CBOR metadata: expected"))
+        .stdout(predicate::str::contains(
+            "The code is identical, but the CBOR metadata differs",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn constructor_arguments() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+    let (metadata_path, deploy_path, _runtime_path) = compile(
+        &output_directory,
+        crate::common::TEST_SOLIDITY_CONTRACT_IMMUTABLE_PATH,
+        "Immutable",
+    )?;
+    let bytecode = std::fs::read_to_string(deploy_path.as_path())?;
+    std::fs::write(
+        deploy_path.as_path(),
+        format!("{}{:064x}", bytecode.trim(), 42),
+    )?;
+
+    let args = &[
+        "--verify",
+        metadata_path.to_str().expect("Always valid"),
+        deploy_path.to_str().expect("Always valid"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("deploy code matches."))
+        .stdout(predicate::str::contains(
+            "Ignored 32 trailing bytes as constructor arguments.",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn truncated_deploy() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+    let (metadata_path, deploy_path, _runtime_path) = compile(
+        &output_directory,
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "Test",
+    )?;
+    let bytecode = std::fs::read_to_string(deploy_path.as_path())?;
+    let bytecode = bytecode.trim();
+    std::fs::write(deploy_path.as_path(), &bytecode[..bytecode.len() - 2])?;
+
+    let args = &[
+        "--verify",
+        metadata_path.to_str().expect("Always valid"),
+        deploy_path.to_str().expect("Always valid"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stdout(predicate::str::contains("runtime code does not match."))
+        .stdout(predicate::str::contains("Size: expected "))
+        .stdout(predicate::str::contains("constructor arguments").not());
+
+    Ok(())
+}
+
+#[test]
+fn libraries() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+    let (metadata_path, _deploy_path, runtime_path) = compile(
+        &output_directory,
+        crate::common::TEST_SOLIDITY_CONTRACT_LINKER_MIXED_DEPS_PATH,
+        "UpperContract",
+    )?;
+    let bytecode = std::fs::read_to_string(runtime_path.as_path())?;
+    assert!(
+        bytecode.contains("__$"),
+        "The runtime code is expected to be unlinked"
+    );
+
+    let args = &[
+        "--link",
+        runtime_path.to_str().expect("Always valid"),
+        "--libraries",
+        crate::common::LIBRARY_LINKER_MIXED_DEPS,
+    ];
+    crate::cli::execute_solx(args)?.success();
+    let bytecode = std::fs::read_to_string(runtime_path.as_path())?;
+    assert!(
+        !bytecode.contains("__$"),
+        "The runtime code is expected to be linked"
+    );
+
+    let args = &[
+        "--verify",
+        metadata_path.to_str().expect("Always valid"),
+        runtime_path.to_str().expect("Always valid"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("runtime code matches."))
+        .stdout(predicate::str::is_match(
+            "Masked 0 immutable references and [1-9][0-9]* library placeholders.",
+        )?);
+
+    Ok(())
+}
+
+#[test]
+fn libraries_unlinked() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+    let (metadata_path, _deploy_path, runtime_path) = compile(
+        &output_directory,
+        crate::common::TEST_SOLIDITY_CONTRACT_LINKER_MIXED_DEPS_PATH,
+        "UpperContract",
+    )?;
+
+    let args = &[
+        "--verify",
+        metadata_path.to_str().expect("Always valid"),
+        runtime_path.to_str().expect("Always valid"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("runtime code matches."))
+        .stdout(predicate::str::contains("library placeholders."));
+
+    Ok(())
+}
+
+#[test]
+fn immutables() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+    let input_path = output_directory.path().join("input.json");
+    let input = serde_json::json!({
+        "language": "Solidity",
+        "sources": {
+            "Immutable.sol": {
+                "content": std::fs::read_to_string(crate::common::TEST_SOLIDITY_CONTRACT_IMMUTABLE_PATH)?,
+            },
+        },
+        "settings": {
+            "metadata": {
+                "useLiteralContent": true,
+            },
+            "outputSelection": {
+                "*": {
+                    "*": [
+                        "metadata",
+                        "evm.deployedBytecode.object",
+                        "evm.deployedBytecode.immutableReferences",
+                    ],
+                },
+            },
+        },
+    });
+    std::fs::write(input_path.as_path(), input.to_string())?;
+
+    let args = &[
+        "--standard-json",
+        input_path.to_str().expect("Always valid"),
+    ];
+    let result = crate::cli::execute_solx(args)?;
+    let output = result.success().get_output().stdout.to_owned();
+    let output: serde_json::Value = serde_json::from_slice(output.as_slice())?;
+    let contract = &output["contracts"]["Immutable.sol"]["Immutable"];
+
+    let metadata = contract["metadata"]
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("The metadata is missing"))?;
+    let mut bytecode = contract["evm"]["deployedBytecode"]["object"]
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("The runtime code is missing"))?
+        .to_owned();
+    let references = contract["evm"]["deployedBytecode"]["immutableReferences"]
+        .as_object()
+        .ok_or_else(|| anyhow::anyhow!("The immutable references are missing"))?
+        .values()
+        .flat_map(|references| references.as_array().cloned().unwrap_or_default())
+        .collect::<Vec<serde_json::Value>>();
+    assert!(
+        !references.is_empty(),
+        "The immutable references are missing"
+    );
+    for reference in references.iter() {
+        let start = reference["start"].as_u64().expect("Always exists") as usize;
+        let length = reference["length"].as_u64().expect("Always exists") as usize;
+        bytecode.replace_range(
+            start * 2..(start + length) * 2,
+            "2a".repeat(length).as_str(),
+        );
+    }
+
+    let metadata_path = output_directory.path().join("Immutable_meta.json");
+    let runtime_path = output_directory.path().join("Immutable.bin-runtime");
+    std::fs::write(metadata_path.as_path(), metadata)?;
+    std::fs::write(runtime_path.as_path(), bytecode)?;
+
+    let args = &[
+        "--verify",
+        metadata_path.to_str().expect("Always valid"),
+        runtime_path.to_str().expect("Always valid"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("runtime code matches."))
+        .stdout(predicate::str::contains(format!(
+            "Masked {} immutable references and 0 library placeholders.",
+            references.len()
+        )));
+
+    Ok(())
+}
+
+#[test]
+fn missing_bytecode() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["--verify", "metadata.json"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Verification mode requires exactly two input files",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn settings() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["--verify", "metadata.json", "bytecode.bin", "-O1"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Compilation settings are taken from the metadata in verification mode.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--verify",
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Only one mode is allowed at the same time:",
+    ));
+
+    Ok(())
+}
+
+///
+/// Compiles the contracts at `path` with their metadata into `output_directory`.
+///
+/// Returns the paths to the metadata, deploy bytecode, and runtime bytecode files of contract `name`.
+///
+fn compile(
+    output_directory: &TempDir,
+    path: &str,
+    name: &str,
+) -> anyhow::Result<(PathBuf, PathBuf, PathBuf)> {
+    let args = &[
+        path,
+        "--metadata",
+        "--bin",
+        "--bin-runtime",
+        "--output-dir",
+        output_directory.path().to_str().unwrap(),
+    ];
+    crate::cli::execute_solx(args)?.success();
+
+    let mut paths = (None, None, None);
+    for entry in std::fs::read_dir(output_directory.path())? {
+        let path = entry?.path();
+        let file_name = path.to_string_lossy().to_string();
+        if file_name.ends_with(format!("_{name}_meta.json").as_str()) {
+            paths.0 = Some(path);
+        } else if file_name.ends_with(format!("_{name}.bin").as_str()) {
+            paths.1 = Some(path);
+        } else if file_name.ends_with(format!("_{name}.bin-runtime").as_str()) {
+            paths.2 = Some(path);
+        }
+    }
+    match paths {
+        (Some(metadata_path), Some(deploy_path), Some(runtime_path)) => {
+            Ok((metadata_path, deploy_path, runtime_path))
+        }
+        _ => anyhow::bail!("The compiled files are missing."),
+    }
+}
@@ -0,0 +1,196 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::Duration;
+use std::time::Instant;
+
+use assert_cmd::cargo::CommandCargoExt;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+/// The time to wait for a rebuild before failing.
+const REBUILD_TIMEOUT: Duration = Duration::from_secs(120);
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let source_directory = TempDir::with_prefix("solx_watch_source")?;
+    let output_directory = TempDir::with_prefix("solx_watch_output")?;
+    let trace_path = source_directory.path().join("trace.json");
+    let changed_path = source_directory.path().join("Test.sol");
+    let unchanged_path = source_directory.path().join("Optimized.sol");
+    std::fs::copy(
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        changed_path.as_path(),
+    )?;
+    std::fs::copy(
+        crate::common::TEST_SOLIDITY_CONTRACT_OPTIMIZED_PATH,
+        unchanged_path.as_path(),
+    )?;
+
+    let mut process = Command::cargo_bin(env!("CARGO_PKG_NAME"))?
+        .args([
+            changed_path.to_str().expect("Always valid"),
+            unchanged_path.to_str().expect("Always valid"),
+            "--bin",
+            "--watch",
+            "--output-dir",
+            output_directory.path().to_str().expect("Always valid"),
+            "--trace",
+            trace_path.to_str().expect("Always valid"),
+        ])
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()?;
+
+    let result = (|| -> anyhow::Result<()> {
+        let initial_bytecode = wait_for_bytecode(output_directory.path(), "Test", None)?;
+        wait_for_bytecode(output_directory.path(), "Optimized", None)?;
+        let initial_categories = trace_categories(trace_path.as_path())?;
+        assert!(initial_categories
+            .iter()
+            .any(|category| category.contains("Optimized.sol:Optimized")));
+
+        let mut source = std::fs::read_to_string(changed_path.as_path())?;
+        source.push_str("\n// Changed.\n");
+        std::fs::write(changed_path.as_path(), source)?;
+
+        let rebuilt_bytecode =
+            wait_for_bytecode(output_directory.path(), "Test", Some(&initial_bytecode))?;
+        assert_ne!(initial_bytecode, rebuilt_bytecode);
+        assert!(!output_directory
+            .path()
+            .join(solx_core::EVMBuild::STAGING_DIRECTORY_NAME)
+            .exists());
+
+        let rebuilt_categories = trace_categories(trace_path.as_path())?;
+        assert!(rebuilt_categories
+            .iter()
+            .any(|category| category.contains("Test.sol:Test")));
+        assert!(
+            !rebuilt_categories
+                .iter()
+                .any(|category| category.contains("Optimized.sol:Optimized")),
+            "The unchanged contract has been recompiled: {rebuilt_categories:?}"
+        );
+        Ok(())
+    })();
+
+    process.kill()?;
+    process.wait()?;
+    result
+}
+
+#[test]
+fn no_output_dir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--watch",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Watch mode requires an output directory.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn stdin() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_watch_output")?;
+
+    let args = &[
+        solx_standard_json::InputSource::STDIN_INPUT_IDENTIFIER,
+        "--bin",
+        "--watch",
+        "--output-dir",
+        output_directory.path().to_str().expect("Always valid"),
+    ];
+
+    let result =
+        crate::cli::execute_solx_with_stdin(args, crate::common::TEST_SOLIDITY_CONTRACT_PATH)?;
+    result.failure().stderr(predicate::str::contains(
+        "Standard input cannot be watched.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_PATH,
+        "--watch",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Watch mode can only be used with Solidity, Yul, LLVM IR, and EVM assembly input files.",
+    ));
+
+    Ok(())
+}
+
+///
+/// Waits until a bytecode file of `contract` different from `previous` appears in `output_directory`.
+///
+fn wait_for_bytecode(
+    output_directory: &Path,
+    contract: &str,
+    previous: Option<&str>,
+) -> anyhow::Result<String> {
+    let file_name_suffix = format!("_{contract}.{}", solx_utils::EXTENSION_EVM_BINARY);
+    let start = Instant::now();
+    while start.elapsed() < REBUILD_TIMEOUT {
+        let bytecode_path = std::fs::read_dir(output_directory)?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .find(|path: &PathBuf| {
+                path.file_name()
+                    .and_then(|file_name| file_name.to_str())
+                    .is_some_and(|file_name| file_name.ends_with(file_name_suffix.as_str()))
+            });
+        if let Some(bytecode_path) = bytecode_path {
+            let bytecode = std::fs::read_to_string(bytecode_path)?;
+            if Some(bytecode.as_str()) != previous {
+                return Ok(bytecode);
+            }
+        }
+        std::thread::sleep(solx_core::Watcher::POLLING_INTERVAL);
+    }
+    anyhow::bail!(
+        "No bytecode of `{contract}` was written to {output_directory:?} in {REBUILD_TIMEOUT:?}"
+    )
+}
+
+///
+/// Reads the Chrome trace at `path`, and returns the categories of its events.
+///
+/// The translation unit events are categorized by the contract and code segment, and cached
+/// translation units do not produce any events.
+///
+fn trace_categories(path: &Path) -> anyhow::Result<Vec<String>> {
+    let trace: serde_json::Value = serde_json::from_slice(std::fs::read(path)?.as_slice())?;
+    let events = trace
+        .get("traceEvents")
+        .and_then(serde_json::Value::as_array)
+        .ok_or_else(|| anyhow::anyhow!("The `traceEvents` array is missing"))?;
+    Ok(events
+        .iter()
+        .filter_map(|event| event.get("cat").and_then(serde_json::Value::as_str))
+        .map(str::to_owned)
+        .collect())
+}
@@ -20,6 +20,9 @@ pub const TEST_SOLIDITY_CONTRACT_CALLER_CALLABLE_PATH: &str =
 pub const TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH: &str =
     "tests/data/contracts/solidity/SimpleContract.sol";
 
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_FACTORY_PATH: &str = "tests/data/contracts/solidity/Factory.sol";
+
 /// A test input file.
 pub const TEST_SOLIDITY_CONTRACT_LINKER_MIXED_DEPS_PATH: &str =
     "tests/data/contracts/solidity/LinkedMixedDeps.sol";
@@ -40,6 +43,14 @@ pub const TEST_SOLIDITY_CONTRACT_STACK_TOO_DEEP_SOLC_PATH: &str =
 pub const TEST_SOLIDITY_CONTRACT_STACK_TOO_DEEP_LLVM_PATH: &str =
     "tests/data/contracts/solidity/StackTooDeepLLVM.sol";
 
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_IMMUTABLE_PATH: &str =
+    "tests/data/contracts/solidity/Immutable.sol";
+
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_MEMORY_COPY_PATH: &str =
+    "tests/data/contracts/solidity/MemoryCopy.sol";
+
 /// A test input file.
 pub const TEST_SOLIDITY_CONTRACT_INTERFACE_EMPTY_YUL_PATH: &str =
     "tests/data/contracts/solidity/InterfaceEmptyYul.sol";
@@ -51,6 +62,14 @@ pub const TEST_SOLIDITY_CONTRACT_FUZZED_LINKER_ERROR_PATH: &str =
 /// A test input file.
 pub const TEST_YUL_CONTRACT_PATH: &str = "tests/data/contracts/yul/Test.yul";
 
+/// A test input file.
+pub const TEST_YUL_CONTRACT_TRANSIENT_STORAGE_PATH: &str =
+    "tests/data/contracts/yul/TransientStorage.yul";
+
+/// A test input file.
+pub const TEST_YUL_CONTRACT_COUNT_LEADING_ZEROS_PATH: &str =
+    "tests/data/contracts/yul/CountLeadingZeros.yul";
+
 /// A test input file.
 pub const TEST_YUL_CONTRACT_OBJECT_NAMING_PATH: &str = "tests/data/contracts/yul/ObjectNaming.yul";
 
@@ -64,6 +83,19 @@ pub const TEST_LLVM_IR_CONTRACT_INVALID_PATH: &str = "tests/data/contracts/llvm_
 pub const TEST_LLVM_IR_CONTRACT_LINKER_ERROR_PATH: &str =
     "tests/data/contracts/llvm_ir/LinkerError.ll";
 
+/// A test input file.
+pub const TEST_EVMLA_CONTRACT_PATH: &str = "tests/data/contracts/evmla/Test.asm.json";
+
+/// A test input file.
+pub const TEST_EVMLA_CONTRACT_FACTORY_PATH: &str = "tests/data/contracts/evmla/Factory.asm.json";
+
+/// A test input file.
+pub const TEST_EVMLA_CONTRACT_RECURSION_PATH: &str =
+    "tests/data/contracts/evmla/Recursion.asm.json";
+
+/// A test input file.
+pub const TEST_EVMLA_CONTRACT_INVALID_PATH: &str = "tests/data/contracts/evmla/Invalid.asm.json";
+
 /// A test input file.
 pub const TEST_SOLIDITY_STANDARD_JSON_NON_EXISTENT_PATH: &str =
     "tests/data/standard_json_input/non_existent.json";
@@ -111,10 +143,50 @@ pub const TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_BYTECODE_PATH: &str =
 pub const TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_BYTECODE_OPCODES_PATH: &str =
     "tests/data/standard_json_input/select_evm_bytecode_opcodes.json";
 
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_SOURCE_MAPS_PATH: &str =
+    "tests/data/standard_json_input/select_evm_source_maps.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_GAS_ESTIMATES_PATH: &str =
+    "tests/data/standard_json_input/select_evm_gas_estimates.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_FUNCTION_DEBUG_DATA_PATH: &str =
+    "tests/data/standard_json_input/select_evm_function_debug_data.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_CONTRACT_OVERRIDES_PATH: &str =
+    "tests/data/standard_json_input/contract_overrides.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_CONTRACT_OVERRIDES_INVALID_MODE_PATH: &str =
+    "tests/data/standard_json_input/contract_overrides_invalid_mode.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_CODE_SIZE_LIMITS_PATH: &str =
+    "tests/data/standard_json_input/code_size_limits.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_BYTECODE_SIZE_PATH: &str =
+    "tests/data/standard_json_input/select_evm_bytecode_size.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_LLVM_IR_PATH: &str =
+    "tests/data/standard_json_input/select_evm_llvm_ir.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_REMARKS_PATH: &str =
+    "tests/data/standard_json_input/select_evm_remarks.json";
+
 /// A test input file.
 pub const TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_DEPLOYED_BYTECODE_PATH: &str =
     "tests/data/standard_json_input/select_evm_deployed_bytecode.json";
 
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_DEPLOYED_BYTECODE_IMMUTABLE_REFERENCES_PATH: &str =
+    "tests/data/standard_json_input/select_evm_deployed_bytecode_immutable_references.json";
+
 /// A test input file.
 pub const TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_DEPLOYED_BYTECODE_LINK_REFERENCES_PATH: &str =
     "tests/data/standard_json_input/select_evm_deployed_bytecode_link_references.json";
@@ -157,6 +229,17 @@ pub const TEST_LLVM_IR_STANDARD_JSON_INVALID_PATH: &str =
 pub const TEST_LLVM_IR_STANDARD_JSON_MISSING_FILE_PATH: &str =
     "tests/data/standard_json_input/llvm_ir_urls_missing_file.json";
 
+/// A test input file.
+pub const TEST_EVMLA_STANDARD_JSON_PATH: &str = "tests/data/standard_json_input/evmla_urls.json";
+
+/// A test input file.
+pub const TEST_EVMLA_STANDARD_JSON_INVALID_PATH: &str =
+    "tests/data/standard_json_input/evmla_urls_invalid.json";
+
+/// A test input file.
+pub const TEST_JSON_METADATA_HASH_BZZR1_AND_METADATA: &str =
+    "tests/data/standard_json_input/metadata_hash_bzzr1_and_metadata.json";
+
 /// A test input file.
 pub const TEST_JSON_METADATA_HASH_IPFS_AND_METADATA: &str =
     "tests/data/standard_json_input/metadata_hash_ipfs_and_metadata.json";
@@ -185,6 +268,9 @@ pub const TEST_NON_EXISTENT_PATH: &str = "tests/data/non_existent";
 /// A test constant.
 pub const LIBRARY_DEFAULT: &str = "tests/data/contracts/solidity/MiniMath.sol:MiniMath=0xF9702469Dfb84A9aC171E284F71615bd3D3f1EdC";
 
+/// A test constant.
+pub const LIBRARY_LINKER_MIXED_DEPS: &str = "tests/data/contracts/solidity/LinkedMixedDeps.sol:UpperLibrary=0xF9702469Dfb84A9aC171E284F71615bd3D3f1EdC";
+
 /// A test constant.
 pub const LIBRARY_CONTRACT_NAME_MISSING: &str =
     "tests/data/contracts/solidity/MiniMath.sol=0xF9702469Dfb84A9aC171E284F71615bd3D3f1EdC";
@@ -195,3 +281,15 @@ pub const LIBRARY_ADDRESS_MISSING: &str = "tests/data/contracts/solidity/MiniMat
 /// A test constant.
 pub const LIBRARY_ADDRESS_INVALID: &str =
     "tests/data/contracts/solidity/MiniMath.sol:MiniMath=INVALID";
+
+/// A test input file.
+pub const TEST_LSP_SESSION_PATH: &str = "tests/data/lsp/session.lsp";
+
+/// A test input file.
+pub const TEST_LSP_SESSION_INVALID_PATH: &str = "tests/data/lsp/session_invalid.lsp";
+
+/// A test input file.
+pub const TEST_LSP_SESSION_INVALID_PARAMS_PATH: &str = "tests/data/lsp/session_invalid_params.lsp";
+
+/// A test input file.
+pub const TEST_LSP_SESSION_STACK_TOO_DEEP_PATH: &str = "tests/data/lsp/session_stack_too_deep.lsp";
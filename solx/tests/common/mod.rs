@@ -143,6 +143,12 @@ pub fn build_solidity_standard_json(
         input.settings.metadata.append_cbor,
         optimizer_settings,
         vec![],
+        &BTreeMap::new(),
+        solx_utils::CodeSizeLimits::default(),
+        input.settings.evm_version.unwrap_or_default(),
+        None,
+        false,
+        &solx_core::EVMProcessSubprocess::default(),
         None,
     )?;
     build.check_errors()?;
@@ -197,6 +203,12 @@ pub fn build_yul_standard_json(
         input.settings.metadata.append_cbor,
         optimizer_settings,
         vec![],
+        &BTreeMap::new(),
+        solx_utils::CodeSizeLimits::default(),
+        input.settings.evm_version.unwrap_or_default(),
+        None,
+        false,
+        &solx_core::EVMProcessSubprocess::default(),
         None,
     )?;
     build.check_errors()?;
@@ -247,6 +259,12 @@ pub fn build_llvm_ir_standard_json(
         input.settings.metadata.append_cbor,
         optimizer_settings,
         vec![],
+        &BTreeMap::new(),
+        solx_utils::CodeSizeLimits::default(),
+        input.settings.evm_version.unwrap_or_default(),
+        None,
+        false,
+        &solx_core::EVMProcessSubprocess::default(),
         None,
     )?;
     build.check_errors()?;
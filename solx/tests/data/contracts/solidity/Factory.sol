@@ -0,0 +1,18 @@
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.8.0;
+
+contract First {
+    uint256 public value = 1;
+}
+
+contract Second {
+    uint256 public value = 2;
+}
+
+// A contract with several factory dependencies
+contract Factory {
+    function create() external returns (address, address) {
+        return (address(new First()), address(new Second()));
+    }
+}
@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: MIT
+
+pragma solidity >=0.8.0;
+
+contract MemoryCopy {
+    function concat(bytes memory first, bytes memory second) public pure returns (bytes memory) {
+        return abi.encodePacked(first, second);
+    }
+}
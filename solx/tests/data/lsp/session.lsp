@@ -0,0 +1,13 @@
+Content-Length: 107
+
+{"jsonrpc":"2.0","id":1,"method":"initialize","params":{"processId":null,"rootUri":null,"capabilities":{}}}Content-Length: 52
+
+{"jsonrpc":"2.0","method":"initialized","params":{}}Content-Length: 327
+
+{"jsonrpc":"2.0","method":"textDocument/didOpen","params":{"textDocument":{"uri":"file:///solx_lsp_test/Test.sol","languageId":"solidity","version":1,"text":"// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.0;\n\ncontract Test {\n    function f() external pure returns (uint256) {\n        return 42;\n    }\n}\n"}}}Content-Length: 124
+
+{"jsonrpc":"2.0","id":2,"method":"textDocument/codeLens","params":{"textDocument":{"uri":"file:///solx_lsp_test/Test.sol"}}}Content-Length: 44
+
+{"jsonrpc":"2.0","id":3,"method":"shutdown"}Content-Length: 33
+
+{"jsonrpc":"2.0","method":"exit"}
\ No newline at end of file
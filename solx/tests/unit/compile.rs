@@ -0,0 +1,182 @@
+//!
+//! Unit tests for the library compilation API.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use solx_standard_json::CollectableError;
+
+///
+/// Reads the standard JSON input at `path` and compiles it with `executor`.
+///
+fn compile(path: &str, executor: &dyn solx_core::EVMProcessExecutor) -> solx_standard_json::Output {
+    let input = solx_standard_json::Input::try_from(Some(PathBuf::from(path).as_path()))
+        .expect("Standard JSON reading error");
+
+    compile_input(input, executor)
+}
+
+///
+/// Compiles the Solidity contract that only fits the stack with a spill area with `executor`.
+///
+fn compile_stack_too_deep(
+    executor: &dyn solx_core::EVMProcessExecutor,
+) -> solx_standard_json::Output {
+    let sources: BTreeMap<String, solx_standard_json::InputSource> =
+        crate::common::read_sources(&[
+            crate::common::TEST_SOLIDITY_CONTRACT_STACK_TOO_DEEP_LLVM_PATH,
+        ])
+        .into_iter()
+        .map(|(path, source)| (path, solx_standard_json::InputSource::from(source)))
+        .collect();
+
+    let mut selectors = BTreeSet::new();
+    selectors.insert(solx_standard_json::InputSelector::BytecodeObject);
+    selectors.insert(solx_standard_json::InputSelector::RuntimeBytecodeObject);
+    let input = solx_standard_json::Input::try_from_solidity_sources(
+        sources,
+        solx_utils::Libraries::default(),
+        BTreeSet::new(),
+        solx_standard_json::InputOptimizer::new('1', false),
+        None,
+        false,
+        &solx_standard_json::InputSelection::new(selectors),
+        solx_standard_json::InputMetadata::default(),
+        vec![],
+    )
+    .expect("Standard JSON input error");
+
+    compile_input(input, executor)
+}
+
+///
+/// Compiles the standard JSON `input` with `executor`.
+///
+fn compile_input(
+    input: solx_standard_json::Input,
+    executor: &dyn solx_core::EVMProcessExecutor,
+) -> solx_standard_json::Output {
+    let _lock = crate::common::UNIT_TEST_LOCK.lock();
+    solx_core::compile(&solx::Solc::default(), input, executor).expect("Test failure")
+}
+
+///
+/// Returns the deploy bytecode of `contract` in `path` from `output`.
+///
+fn bytecode<'a>(output: &'a solx_standard_json::Output, path: &str, contract: &str) -> &'a str {
+    output
+        .contracts
+        .get(path)
+        .unwrap_or_else(|| panic!("The `{path}` file is missing"))
+        .get(contract)
+        .unwrap_or_else(|| panic!("The `{contract}` contract is missing"))
+        .evm
+        .as_ref()
+        .expect("The `evm` field is missing")
+        .bytecode
+        .as_ref()
+        .expect("The `bytecode` field is missing")
+        .object
+        .as_deref()
+        .expect("The `object` field is missing")
+}
+
+#[test]
+fn solidity_in_process() {
+    let output = compile(
+        "tests/data/standard_json_input/solidity.json",
+        &solx_core::EVMProcessInProcess,
+    );
+
+    assert!(!output.has_errors(), "{:?}", output.errors);
+    assert!(!bytecode(&output, "A", "C").is_empty());
+}
+
+#[test]
+fn solidity_in_process_invalid() {
+    let output = compile(
+        "tests/data/standard_json_input/solidity_invalid.json",
+        &solx_core::EVMProcessInProcess,
+    );
+
+    assert!(output.has_errors());
+}
+
+#[test]
+fn yul_in_process() {
+    let output = compile(
+        "tests/data/standard_json_input/yul.json",
+        &solx_core::EVMProcessInProcess,
+    );
+
+    assert!(!output.has_errors(), "{:?}", output.errors);
+    assert!(!bytecode(&output, "Test", "Return").is_empty());
+}
+
+#[test]
+fn yul_subprocess() {
+    crate::common::setup().expect("Test setup failure");
+
+    let in_process_output = compile(
+        "tests/data/standard_json_input/yul.json",
+        &solx_core::EVMProcessInProcess,
+    );
+    let subprocess_output = compile(
+        "tests/data/standard_json_input/yul.json",
+        &solx_core::EVMProcessSubprocess::default(),
+    );
+
+    assert_eq!(
+        bytecode(&in_process_output, "Test", "Return"),
+        bytecode(&subprocess_output, "Test", "Return"),
+    );
+}
+
+#[test]
+fn solidity_deterministic() {
+    let path = "tests/data/standard_json_input/solidity_deploy_time_linking.json";
+    let first_output = compile(path, &solx_core::EVMProcessInProcess);
+    let second_output = compile(path, &solx_core::EVMProcessInProcess);
+
+    assert!(!first_output.has_errors(), "{:?}", first_output.errors);
+    for (path, contracts) in first_output.contracts.iter() {
+        for contract in contracts.keys() {
+            assert_eq!(
+                bytecode(&first_output, path, contract),
+                bytecode(&second_output, path, contract),
+            );
+        }
+    }
+}
+
+#[test]
+fn solidity_in_process_stack_too_deep() {
+    crate::common::setup().expect("Test setup failure");
+    std::env::set_var(
+        solx_utils::ENV_DISABLE_UNSAFE_MEMORY_ASM_STACK_TOO_DEEP_CHECK,
+        "1",
+    );
+
+    let in_process_output = compile_stack_too_deep(&solx_core::EVMProcessInProcess);
+    let subprocess_output = compile_stack_too_deep(&solx_core::EVMProcessSubprocess::default());
+
+    assert!(
+        !in_process_output.has_errors(),
+        "{:?}",
+        in_process_output.errors
+    );
+    assert!(
+        in_process_output.errors.iter().any(|error| error
+            .message
+            .contains("stack-too-deep errors have been resolved by allocating a spill area of")),
+        "{:?}",
+        in_process_output.errors
+    );
+    let path = crate::common::TEST_SOLIDITY_CONTRACT_STACK_TOO_DEEP_LLVM_PATH;
+    assert_eq!(
+        bytecode(&in_process_output, path, "Test"),
+        bytecode(&subprocess_output, path, "Test"),
+    );
+}
@@ -2,6 +2,7 @@
 //! The unit tests entry module.
 //!
 
+mod compile;
 mod ir_artifacts;
 mod libraries;
 mod optimizer;
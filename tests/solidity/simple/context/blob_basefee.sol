@@ -0,0 +1,24 @@
+//! { "cases": [ {
+//!     "name": "main",
+//!     "inputs": [
+//!         {
+//!             "method": "main",
+//!             "calldata": [
+//!             ]
+//!         }
+//!     ],
+//!     "expected": [
+//!         "$BLOB_BASE_FEE"
+//!     ]
+//! } ] }
+
+// SPDX-License-Identifier: MIT
+
+pragma solidity >=0.8.24;
+
+contract Test {
+    function main() public view returns(uint) {
+        uint _blobbasefee = block.blobbasefee;
+        return _blobbasefee;
+    }
+}
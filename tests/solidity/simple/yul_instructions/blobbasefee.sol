@@ -0,0 +1,25 @@
+//! { "cases": [ {
+//!     "name": "default",
+//!     "inputs": [
+//!         {
+//!             "method": "main",
+//!             "calldata": [
+//!             ]
+//!         }
+//!     ],
+//!     "expected": [
+//!         "$BLOB_BASE_FEE"
+//!     ]
+//! } ] }
+
+// SPDX-License-Identifier: MIT
+
+pragma solidity >=0.8.24;
+
+contract Test {
+    function main() external view returns(uint256 result) {
+        assembly {
+            result := blobbasefee()
+        }
+    }
+}